@@ -57,6 +57,37 @@ where
     Ok(())
 }
 
+/// Upserts a deletion of `catalog_name` into the draft.
+#[tracing::instrument(err, level = "debug", skip(txn))]
+pub async fn upsert_deletion(
+    draft_id: Id,
+    catalog_name: &str,
+    expect_pub_id: Option<Id>,
+    txn: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+) -> sqlx::Result<()> {
+    sqlx::query!(
+        r#"
+        insert into draft_specs(
+            draft_id,
+            catalog_name,
+            spec,
+            spec_type,
+            expect_pub_id
+        ) values ($1, $2, null, null, $3)
+        on conflict (draft_id, catalog_name) do update set
+            spec = null,
+            spec_type = null
+        returning 1 as "must_exist";
+        "#,
+        draft_id as Id,
+        catalog_name as &str,
+        expect_pub_id as Option<Id>,
+    )
+    .fetch_one(&mut *txn)
+    .await?;
+    Ok(())
+}
+
 pub async fn add_built_spec<S, V>(
     draft_spec_id: Id,
     built_spec: S,
@@ -113,10 +144,11 @@ pub async fn delete_errors(
     Ok(())
 }
 
-pub async fn insert_error(
+/// Inserts errors of the draft, as parallel arrays of their scopes and details.
+pub async fn insert_errors(
     draft_id: Id,
-    scope: String,
-    detail: String,
+    scopes: Vec<String>,
+    details: Vec<String>,
     txn: &mut sqlx::Transaction<'_, sqlx::Postgres>,
 ) -> sqlx::Result<()> {
     sqlx::query!(
@@ -124,11 +156,12 @@ pub async fn insert_error(
             draft_id,
             scope,
             detail
-        ) values ($1, $2, $3)
+        ) select $1, scope, detail
+        from unnest($2::text[], $3::text[]) as e(scope, detail)
         "#,
         draft_id as Id,
-        scope,
-        detail,
+        scopes as Vec<String>,
+        details as Vec<String>,
     )
     .execute(&mut *txn)
     .await?;
@@ -62,6 +62,8 @@ pub struct Row {
     pub user_id: Uuid,
     pub auto_evolve: bool,
     pub background: bool,
+    pub only_catalog_names: Option<Vec<String>>,
+    pub force: bool,
 }
 
 #[tracing::instrument(level = "debug", skip(txn))]
@@ -81,7 +83,9 @@ pub async fn dequeue(
             updated_at,
             user_id,
             auto_evolve,
-            background
+            background,
+            only_catalog_names,
+            force
         from publications
         where job_status->>'type' = 'queued' and (background = $1 or background = false)
         order by background asc, id asc
@@ -144,9 +148,26 @@ pub async fn rollback_noop(txn: &mut sqlx::Transaction<'_, sqlx::Postgres>) -> s
     Ok(())
 }
 
+pub async fn savepoint_preview(
+    txn: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+) -> sqlx::Result<()> {
+    sqlx::query!("savepoint preview;").execute(txn).await?;
+    Ok(())
+}
+
+pub async fn rollback_preview(txn: &mut sqlx::Transaction<'_, sqlx::Postgres>) -> sqlx::Result<()> {
+    sqlx::query!("rollback transaction to preview;")
+        .execute(txn)
+        .await?;
+    Ok(())
+}
+
+/// Insert `live_specs` for each spec of the draft which doesn't already exist.
+/// If `only` is Some, only specs having those catalog names are considered.
 pub async fn insert_new_live_specs(
     draft_id: Id,
     pub_id: Id,
+    only: Option<&[String]>,
     txn: &mut sqlx::Transaction<'_, sqlx::Postgres>,
 ) -> sqlx::Result<u64> {
     let rows = sqlx::query!(
@@ -155,11 +176,14 @@ pub async fn insert_new_live_specs(
             select catalog_name, $2, $2
             from draft_specs
             where draft_specs.draft_id = $1
+                and ($3::text[] is null or draft_specs.catalog_name = any($3))
+            order by draft_specs.catalog_name asc
             for update of draft_specs
         ) on conflict (catalog_name) do nothing
         "#,
         draft_id as Id,
         pub_id as Id,
+        only as Option<&[String]>,
     )
     .execute(&mut *txn)
     .await?;
@@ -242,15 +266,22 @@ pub struct SpecRow {
     pub live_spec_id: Id,
     // Spec type of the live specification.
     pub live_type: Option<CatalogType>,
+    // Content hash of the live specification, if known.
+    pub live_spec_hash: Option<String>,
+    // Prior catalog name of a collection which is renamed by this draft.
+    pub rename_from: Option<String>,
     // Capabilities of the specification with respect to other roles.
     pub spec_capabilities: Json<Vec<RoleGrant>>,
     // User's capability to the specification `catalog_name`.
     pub user_capability: Option<Capability>,
 }
 
+/// Resolve the joined draft and live specs of the draft, locking each.
+/// If `only` is Some, only specs having those catalog names are resolved.
 pub async fn resolve_spec_rows(
     draft_id: Id,
     user_id: Uuid,
+    only: Option<&[String]>,
     txn: &mut sqlx::Transaction<'_, sqlx::Postgres>,
 ) -> sqlx::Result<Vec<SpecRow>> {
     sqlx::query_as!(
@@ -267,6 +298,8 @@ pub async fn resolve_spec_rows(
             live_specs.spec as "live_spec: Json<Box<RawValue>>",
             live_specs.id as "live_spec_id: Id",
             live_specs.spec_type as "live_type: CatalogType",
+            live_specs.spec_hash as "live_spec_hash",
+            draft_specs.rename_from,
             coalesce(
                 (select json_agg(row_to_json(role_grants))
                 from role_grants
@@ -281,11 +314,13 @@ pub async fn resolve_spec_rows(
         join live_specs
             on draft_specs.catalog_name = live_specs.catalog_name
         where draft_specs.draft_id = $1
+            and ($3::text[] is null or draft_specs.catalog_name = any($3))
         order by draft_specs.catalog_name asc
         for update of draft_specs, live_specs nowait;
         "#,
         draft_id as Id,
         user_id,
+        only as Option<&[String]>,
     )
     .fetch_all(txn)
     .await
@@ -531,6 +566,158 @@ pub async fn insert_publication_spec(
     Ok(())
 }
 
+#[derive(Debug)]
+pub struct PublishedSpec {
+    // Publication which published the specification.
+    pub pub_id: Id,
+    // Specification which was published, or None if it was deleted.
+    pub spec: Option<Json<Box<RawValue>>>,
+    // Type of the published specification, or None if it was deleted.
+    pub spec_type: Option<CatalogType>,
+}
+
+/// Returns the specification of `catalog_name` as it was upon the completion
+/// of publication `pub_id`, which is that of the latest publication of the
+/// specification at or before `pub_id`. Each row of `publication_specs` holds
+/// the complete specification which was published, so no replay of patches
+/// is required. Returns None if `catalog_name` wasn't yet published.
+pub async fn reconstruct_spec_at(
+    catalog_name: &str,
+    pub_id: Id,
+    txn: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+) -> sqlx::Result<Option<PublishedSpec>> {
+    sqlx::query_as!(
+        PublishedSpec,
+        r#"
+        select
+            p.pub_id as "pub_id: Id",
+            p.spec as "spec: Json<Box<RawValue>>",
+            p.spec_type as "spec_type: CatalogType"
+        from live_specs l
+        join publication_specs p on p.live_spec_id = l.id
+        where l.catalog_name = $1 and p.pub_id <= $2
+        order by p.pub_id desc
+        limit 1;
+        "#,
+        catalog_name as &str,
+        pub_id as Id,
+    )
+    .fetch_optional(&mut *txn)
+    .await
+}
+
+#[derive(Debug)]
+pub struct RollbackRow {
+    // Name of the specification.
+    pub catalog_name: String,
+    // Last publication ID of the live spec.
+    pub last_pub_id: Id,
+    // Publication which preceded the rolled-back publication of the spec,
+    // or None if the rolled-back publication created it.
+    pub prior_pub_id: Option<Id>,
+    // Specification as of the prior publication, or None if it didn't exist.
+    pub prior_spec: Option<Json<Box<RawValue>>>,
+    // Type of the prior specification, or None if it didn't exist.
+    pub prior_spec_type: Option<CatalogType>,
+}
+
+/// Returns each specification which was published by `pub_id`,
+/// along with its specification as of its preceding publication.
+pub async fn resolve_rollback_rows(
+    pub_id: Id,
+    txn: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+) -> sqlx::Result<Vec<RollbackRow>> {
+    sqlx::query_as!(
+        RollbackRow,
+        r#"
+        select
+            l.catalog_name as "catalog_name!: String",
+            l.last_pub_id as "last_pub_id!: Id",
+            prior.pub_id as "prior_pub_id?: Id",
+            prior.spec as "prior_spec?: Json<Box<RawValue>>",
+            prior.spec_type as "prior_spec_type?: CatalogType"
+        from publication_specs p
+        join live_specs l on l.id = p.live_spec_id
+        left join lateral (
+            select pp.pub_id, pp.spec, pp.spec_type
+            from publication_specs pp
+            where pp.live_spec_id = p.live_spec_id and pp.pub_id < p.pub_id
+            order by pp.pub_id desc
+            limit 1
+        ) prior on true
+        where p.pub_id = $1
+        order by l.catalog_name;
+        "#,
+        pub_id as Id,
+    )
+    .fetch_all(&mut *txn)
+    .await
+}
+
+#[derive(Debug)]
+pub struct OrphanRow {
+    // Name of the orphaned collection.
+    pub catalog_name: String,
+    // Last publication ID of the collection.
+    pub last_pub_id: Id,
+}
+
+/// Returns live collections under `catalog_prefix` which aren't derivations,
+/// and which no live capture, derivation, or materialization reads or writes.
+/// Such collections have no data-flow edges at all within `live_spec_flows`.
+pub async fn resolve_orphaned_collections(
+    catalog_prefix: &str,
+    txn: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+) -> sqlx::Result<Vec<OrphanRow>> {
+    sqlx::query_as!(
+        OrphanRow,
+        r#"
+        select
+            l.catalog_name as "catalog_name!: String",
+            l.last_pub_id as "last_pub_id!: Id"
+        from live_specs l
+        where starts_with(l.catalog_name, $1)
+            and l.spec_type = 'collection'
+            and l.spec->'derive' is null
+            and not exists (
+                select 1 from live_spec_flows f
+                where f.source_id = l.id or f.target_id = l.id
+            )
+        order by l.catalog_name;
+        "#,
+        catalog_prefix,
+    )
+    .fetch_all(&mut *txn)
+    .await
+}
+
+/// Returns the names of live collections among `catalog_names` which are read
+/// by a live derivation, materialization, or test, as indicated by a data-flow
+/// edge within `live_spec_flows` having the collection as its source.
+pub async fn resolve_read_collections(
+    catalog_names: Vec<&str>,
+    txn: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+) -> sqlx::Result<Vec<String>> {
+    let rows = sqlx::query!(
+        r#"
+        select l.catalog_name as "catalog_name!: String"
+        from live_specs l
+        where l.catalog_name = any($1::text[])
+            and l.spec_type = 'collection'
+            and exists (
+                select 1 from live_spec_flows f
+                where f.source_id = l.id
+            )
+        order by l.catalog_name;
+        "#,
+        catalog_names as Vec<&str>,
+    )
+    .fetch_all(&mut *txn)
+    .await?;
+
+    Ok(rows.into_iter().map(|row| row.catalog_name).collect())
+}
+
 pub async fn update_published_live_spec(
     catalog_name: &str,
     connector_image_name: Option<&String>,
@@ -540,6 +727,7 @@ pub async fn update_published_live_spec(
     live_spec_id: Id,
     pub_id: Id,
     reads_from: &Option<Vec<&str>>,
+    spec_hash: Option<&str>,
     writes_to: &Option<Vec<&str>>,
     txn: &mut sqlx::Transaction<'_, sqlx::Postgres>,
 ) -> sqlx::Result<()> {
@@ -556,7 +744,8 @@ pub async fn update_published_live_spec(
             spec = $7,
             spec_type = $8,
             updated_at = clock_timestamp(),
-            writes_to = $9
+            writes_to = $9,
+            spec_hash = $10
         where id = $1
         returning 1 as "must_exist";
         "#,
@@ -569,6 +758,7 @@ pub async fn update_published_live_spec(
         draft_spec as &Option<Json<Box<RawValue>>>,
         draft_type as &Option<CatalogType>,
         writes_to as &Option<Vec<&str>>,
+        spec_hash,
     )
     .fetch_one(&mut *txn)
     .await?;
@@ -583,8 +773,15 @@ pub async fn update_expanded_live_specs(
 ) -> sqlx::Result<()> {
     sqlx::query!(
         r#"
+        with locked as (
+            select id from live_specs
+            where id in (select id from unnest($2::flowid[]) as id)
+            order by catalog_name asc
+            for update
+        )
         update live_specs set last_build_id = $1
-        where id in (select id from unnest($2::flowid[]) as id);
+        from locked
+        where live_specs.id = locked.id;
         "#,
         pub_id as Id,
         live_spec_ids as &[Id],
@@ -686,6 +883,95 @@ pub struct InferredSchemaRow {
     pub md5: String,
 }
 
+#[derive(Debug)]
+pub struct LiveReference {
+    // Name of the live specification.
+    pub catalog_name: String,
+    // Collection which the live specification reads from or writes to.
+    pub reference: String,
+}
+
+/// Returns live specifications which read from or write to any of `collections`.
+pub async fn find_live_references(
+    collections: Vec<String>,
+    txn: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+) -> sqlx::Result<Vec<LiveReference>> {
+    sqlx::query_as!(
+        LiveReference,
+        r#"
+        select
+            l.catalog_name as "catalog_name!",
+            r.reference as "reference!"
+        from live_specs l,
+            unnest(coalesce(l.reads_from, '{}') || coalesce(l.writes_to, '{}')) as r(reference)
+        where l.spec is not null and r.reference = any($1::text[])
+        order by l.catalog_name, r.reference;
+        "#,
+        collections as Vec<String>,
+    )
+    .fetch_all(&mut *txn)
+    .await
+}
+
+/// Returns the subset of `collections` which are live collections.
+pub async fn find_live_collections(
+    collections: Vec<String>,
+    txn: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+) -> sqlx::Result<Vec<String>> {
+    sqlx::query_scalar!(
+        r#"
+        select catalog_name as "catalog_name!"
+        from live_specs
+        where catalog_name = any($1::text[]) and spec_type = 'collection';
+        "#,
+        collections as Vec<String>,
+    )
+    .fetch_all(&mut *txn)
+    .await
+}
+
+/// Returns live collections having a name which matches one of `collections`
+/// when compared case-insensitively.
+pub async fn find_live_collections_ignoring_case(
+    collections: Vec<String>,
+    txn: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+) -> sqlx::Result<Vec<String>> {
+    sqlx::query_scalar!(
+        r#"
+        select catalog_name as "catalog_name!"
+        from live_specs
+        where lower(catalog_name) in (select lower(c) from unnest($1::text[]) c)
+            and spec_type = 'collection';
+        "#,
+        collections as Vec<String>,
+    )
+    .fetch_all(&mut *txn)
+    .await
+}
+
+/// Copies the inferred schema of collection `from`, if there is one,
+/// to collection `to` which is being renamed from it.
+pub async fn copy_inferred_schema(
+    from: &str,
+    to: &str,
+    txn: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+) -> sqlx::Result<()> {
+    sqlx::query!(
+        r#"
+        insert into inferred_schemas (collection_name, schema, flow_document)
+        select $2::text::catalog_name, schema, flow_document
+        from inferred_schemas where collection_name = $1
+        on conflict do nothing;
+        "#,
+        from,
+        to,
+    )
+    .execute(&mut *txn)
+    .await?;
+
+    Ok(())
+}
+
 pub async fn get_inferred_schemas(
     collections: Vec<String>,
     pool: sqlx::PgPool,
@@ -118,7 +118,7 @@ async fn test_publication_data_operations() {
     ]);
 
     // Create new `live_specs` where they don't already exist, with a NULL `spec_type`.
-    agent_sql::publications::insert_new_live_specs(draft_id, pub_id, &mut txn)
+    agent_sql::publications::insert_new_live_specs(draft_id, pub_id, None, &mut txn)
         .await
         .unwrap();
 
@@ -144,7 +144,7 @@ async fn test_publication_data_operations() {
     "###);
 
     // Expect we resolve the correct specifications, with corresponding grants.
-    let resolved = agent_sql::publications::resolve_spec_rows(draft_id, alice, &mut txn)
+    let resolved = agent_sql::publications::resolve_spec_rows(draft_id, alice, None, &mut txn)
         .await
         .unwrap();
 
@@ -207,6 +207,7 @@ async fn test_publication_data_operations() {
             row.live_spec_id,
             pub_id,
             &Some(vec!["reads/from"]),
+            None,
             &Some(vec!["writes/to"]),
             &mut txn,
         )
@@ -363,3 +364,171 @@ async fn test_text_json_round_trip() {
 
     assert_eq!(json_string, got.spec.unwrap().get().to_string());
 }
+
+#[tokio::test]
+async fn test_reconstruct_spec_at() {
+    let mut conn = sqlx::postgres::PgConnection::connect(&FIXED_DATABASE_URL)
+        .await
+        .expect("connect");
+
+    let mut txn = conn.begin().await.unwrap();
+
+    // Fixture: a collection which was created by one publication,
+    // and then updated by a second.
+    sqlx::query(
+        r#"
+        with p1 as (
+            insert into auth.users (id) values
+            ('11111111-1111-1111-1111-111111111111')
+        ),
+        p2 as (
+            insert into live_specs (id, catalog_name, spec, spec_type, last_build_id, last_pub_id) values
+            ('aa00000000000000', 'aliceCo/History/Thing', '{"v":2}', 'collection', 'cc00000000000000', 'cc00000000000000')
+        ),
+        p3 as (
+            insert into publication_specs (live_spec_id, pub_id, spec, spec_type, user_id) values
+            ('aa00000000000000', 'bb00000000000000', '{"v":1}', 'collection', '11111111-1111-1111-1111-111111111111'),
+            ('aa00000000000000', 'cc00000000000000', '{"v":2}', 'collection', '11111111-1111-1111-1111-111111111111')
+        )
+        select 1;
+        "#,
+    )
+    .execute(&mut txn)
+    .await
+    .unwrap();
+
+    let mut history = Vec::new();
+    for pub_id in [
+        "aa00000000000000", // Before the first publication.
+        "bb00000000000000", // At the first publication.
+        "bbff000000000000", // In between.
+        "cc00000000000000", // At the second publication.
+        "dd00000000000000", // After.
+    ] {
+        let spec = agent_sql::publications::reconstruct_spec_at(
+            "aliceCo/History/Thing",
+            Id::from_hex(pub_id).unwrap(),
+            &mut txn,
+        )
+        .await
+        .unwrap()
+        .map(|spec| {
+            (
+                spec.pub_id.to_string(),
+                spec.spec.unwrap().get().to_string(),
+            )
+        });
+
+        history.push(spec);
+    }
+
+    assert_eq!(
+        history,
+        vec![
+            None,
+            Some(("bb00000000000000".to_string(), r#"{"v":1}"#.to_string())),
+            Some(("bb00000000000000".to_string(), r#"{"v":1}"#.to_string())),
+            Some(("cc00000000000000".to_string(), r#"{"v":2}"#.to_string())),
+            Some(("cc00000000000000".to_string(), r#"{"v":2}"#.to_string())),
+        ]
+    );
+}
+
+#[tokio::test]
+async fn test_partial_publication() {
+    let mut conn = sqlx::postgres::PgConnection::connect(&FIXED_DATABASE_URL)
+        .await
+        .expect("connect");
+
+    let mut txn = conn.begin().await.unwrap();
+
+    // Fixture: a draft of five new collections.
+    sqlx::query(
+        r#"
+        with p1 as (
+            insert into auth.users (id) values
+            ('11111111-1111-1111-1111-111111111111')
+        ),
+        p2 as (
+            insert into drafts (id, user_id) values
+            ('dddddddddddddddd', '11111111-1111-1111-1111-111111111111')
+        ),
+        p3 as (
+            insert into draft_specs (draft_id, catalog_name, spec, spec_type) values
+            ('dddddddddddddddd', 'aliceCo/Partial/One', '{}', 'collection'),
+            ('dddddddddddddddd', 'aliceCo/Partial/Two', '{}', 'collection'),
+            ('dddddddddddddddd', 'aliceCo/Partial/Three', '{}', 'collection'),
+            ('dddddddddddddddd', 'aliceCo/Partial/Four', '{}', 'collection'),
+            ('dddddddddddddddd', 'aliceCo/Partial/Five', '{}', 'collection')
+        )
+        select 1;
+        "#,
+    )
+    .execute(&mut txn)
+    .await
+    .unwrap();
+
+    let draft_id = Id::from_hex("dddddddddddddddd").unwrap();
+    let pub_id = Id::from_hex("eeeeeeeeeeeeeeee").unwrap();
+    let alice = Uuid::from_bytes([0x11; 16]);
+    let only = vec![
+        "aliceCo/Partial/Two".to_string(),
+        "aliceCo/Partial/Four".to_string(),
+    ];
+
+    // Only the selected specs have live_specs created, and are resolved.
+    let inserted = agent_sql::publications::insert_new_live_specs(
+        draft_id,
+        pub_id,
+        Some(only.as_slice()),
+        &mut txn,
+    )
+    .await
+    .unwrap();
+    assert_eq!(inserted, 2);
+
+    let resolved = agent_sql::publications::resolve_spec_rows(
+        draft_id,
+        alice,
+        Some(only.as_slice()),
+        &mut txn,
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(
+        resolved
+            .iter()
+            .map(|row| row.catalog_name.as_str())
+            .collect::<Vec<_>>(),
+        vec!["aliceCo/Partial/Four", "aliceCo/Partial/Two"]
+    );
+
+    // Publishing removes the resolved specs from the draft,
+    // and the other three remain.
+    for row in resolved {
+        agent_sql::drafts::delete_spec(row.draft_spec_id, &mut txn)
+            .await
+            .unwrap();
+    }
+
+    let remaining = sqlx::query(
+        "select catalog_name from draft_specs where draft_id = $1 order by catalog_name",
+    )
+    .bind(draft_id)
+    .fetch_all(&mut txn)
+    .await
+    .unwrap();
+
+    assert_eq!(
+        remaining
+            .iter()
+            .map(|r| -> String { r.get(0) })
+            .collect::<Vec<_>>(),
+        vec![
+            "aliceCo/Partial/Five",
+            "aliceCo/Partial/One",
+            "aliceCo/Partial/Three",
+        ]
+    );
+}
@@ -172,6 +172,7 @@ impl DiscoverHandler {
             let error = draft::Error {
                 catalog_name: row.capture_name,
                 scope: None,
+                location: None,
                 detail,
             };
             draft::insert_errors(row.draft_id, vec![error], txn).await?;
@@ -288,6 +289,7 @@ impl DiscoverHandler {
             return Ok(Err(vec![draft::Error {
                 catalog_name: capture_name.to_string(),
                 scope: None,
+                location: None,
                 detail: "connector protocol error: a binding was missing 'recommended_name'. Please contact support for assistance".to_string(),
             }]));
         }
@@ -342,6 +344,7 @@ impl DiscoverHandler {
                 return Ok(Err(vec![draft::Error {
                     catalog_name: capture_name.to_string(),
                     scope: None,
+                    location: None,
                     detail: invalid_resource.to_string(),
                 }]))
             }
@@ -528,6 +531,9 @@ mod test {
             Error {
                 catalog_name: "aliceCo/bad",
                 scope: None,
+                location: Some(
+                    "/key",
+                ),
                 detail: "parsing collection aliceCo/bad: invalid type: string \"invalid\", expected a sequence at line 1 column 17",
             },
         ]
@@ -8,9 +8,25 @@ use anyhow::Context;
 pub struct Error {
     pub catalog_name: String,
     pub scope: Option<String>,
+    /// JSON pointer to the location of the error within the specification.
+    pub location: Option<String>,
     pub detail: String,
 }
 
+impl Error {
+    /// Scope of the Error, as persisted with draft errors. An explicit `scope`
+    /// is used as-is. Otherwise, if the Error has a location, its scope is the
+    /// location within the specification, as `flow://spec/{catalog_name}#{location}`.
+    /// Otherwise, its scope is the catalog name.
+    pub fn resolved_scope(&self) -> String {
+        match (&self.scope, &self.location) {
+            (Some(scope), _) => scope.clone(),
+            (None, Some(location)) => format!("flow://spec/{}#{location}", self.catalog_name),
+            (None, None) => self.catalog_name.clone(),
+        }
+    }
+}
+
 /// upsert_specs updates the given draft with specifications of the catalog.
 /// The `expect_pub_ids` parameter is used to lookup the `last_pub_id` by catalog name.
 /// For each item in the catalog, if an entry exists in `expect_pub_ids`, then it will
@@ -76,56 +92,133 @@ pub async fn upsert_specs(
     Ok(())
 }
 
+/// Outcome of extending a catalog with specifications. Errors are specifications
+/// which couldn't be parsed and weren't added to the catalog. Warnings are softer
+/// issues, such as use of a deprecated field, of specifications which were added.
+#[derive(Debug, Default)]
+pub struct Extended {
+    pub errors: Vec<Error>,
+    pub warnings: Vec<Error>,
+}
+
+/// extend_catalog extends the catalog with parsed specifications,
+/// returning an Error for each specification which couldn't be parsed.
 pub fn extend_catalog<'a>(
     catalog: &mut models::Catalog,
     it: impl Iterator<Item = (CatalogType, &'a str, &'a serde_json::value::RawValue)>,
 ) -> Vec<Error> {
+    extend_catalog_with_warnings(catalog, it).errors
+}
+
+/// extend_catalog_with_warnings extends the catalog with parsed specifications,
+/// as does `extend_catalog`, and additionally returns warnings of the
+/// specifications which were added.
+pub fn extend_catalog_with_warnings<'a>(
+    catalog: &mut models::Catalog,
+    it: impl Iterator<Item = (CatalogType, &'a str, &'a serde_json::value::RawValue)>,
+) -> Extended {
     let mut errors = Vec::new();
+    let mut warnings = Vec::new();
 
     for (catalog_type, catalog_name, spec) in it {
-        let mut on_err = |detail| {
+        let mut on_err = |kind: &str, (err, location): (serde_json::Error, Option<String>)| {
             errors.push(Error {
                 catalog_name: catalog_name.to_string(),
-                detail,
+                location,
+                detail: format!("parsing {kind} {catalog_name}: {err}"),
                 ..Error::default()
             });
         };
 
         match catalog_type {
-            CatalogType::Collection => match serde_json::from_str(spec.get()) {
+            CatalogType::Collection => match parse_spec(spec) {
                 Ok(spec) => {
                     catalog
                         .collections
                         .insert(models::Collection::new(catalog_name), spec);
                 }
-                Err(err) => on_err(format!("parsing collection {catalog_name}: {err}")),
+                Err(err) => on_err("collection", err),
             },
-            CatalogType::Capture => match serde_json::from_str(spec.get()) {
+            CatalogType::Capture => match parse_spec(spec) {
                 Ok(spec) => {
                     catalog
                         .captures
                         .insert(models::Capture::new(catalog_name), spec);
                 }
-                Err(err) => on_err(format!("parsing capture {catalog_name}: {err}")),
+                Err(err) => on_err("capture", err),
             },
-            CatalogType::Materialization => match serde_json::from_str(spec.get()) {
-                Ok(spec) => {
+            CatalogType::Materialization => match parse_spec(spec) {
+                Ok(parsed) => {
                     catalog
                         .materializations
-                        .insert(models::Materialization::new(catalog_name), spec);
+                        .insert(models::Materialization::new(catalog_name), parsed);
+
+                    if uses_flow_sink(spec) {
+                        warnings.push(Error {
+                            catalog_name: catalog_name.to_string(),
+                            detail: format!("materialization {catalog_name} uses the deprecated endpoint 'flowSink', which should be renamed to 'connector'"),
+                            ..Error::default()
+                        });
+                    }
                 }
-                Err(err) => on_err(format!("parsing materialization {catalog_name}: {err}")),
+                Err(err) => on_err("materialization", err),
             },
-            CatalogType::Test => match serde_json::from_str(spec.get()) {
+            CatalogType::Test => match parse_spec(spec) {
                 Ok(spec) => {
                     catalog.tests.insert(models::Test::new(catalog_name), spec);
                 }
-                Err(err) => on_err(format!("parsing test {catalog_name}: {err}")),
+                Err(err) => on_err("test", err),
             },
         }
     }
 
-    errors
+    Extended { errors, warnings }
+}
+
+// Parse a specification, returning a parse error along with the JSON pointer
+// location within `spec` at which it occurred, if known.
+fn parse_spec<T: serde::de::DeserializeOwned>(
+    spec: &serde_json::value::RawValue,
+) -> Result<T, (serde_json::Error, Option<String>)> {
+    let mut de = serde_json::Deserializer::from_str(spec.get());
+
+    serde_path_to_error::deserialize(&mut de).map_err(|err| {
+        use serde_path_to_error::Segment;
+
+        let mut location = String::new();
+        for segment in err.path().iter() {
+            match segment {
+                Segment::Seq { index } => location.push_str(&format!("/{index}")),
+                Segment::Map { key } | Segment::Enum { variant: key } => {
+                    location.push('/');
+                    location.push_str(&key.replace('~', "~0").replace('/', "~1"));
+                }
+                Segment::Unknown => break,
+            }
+        }
+        (err.into_inner(), (!location.is_empty()).then_some(location))
+    })
+}
+
+// Returns true if the materialization `spec` uses the deprecated `flowSink`
+// endpoint, which is accepted as an alias of `connector`.
+fn uses_flow_sink(spec: &serde_json::value::RawValue) -> bool {
+    #[derive(serde::Deserialize)]
+    struct Endpoint {
+        #[serde(rename = "flowSink")]
+        flow_sink: Option<serde::de::IgnoredAny>,
+    }
+    #[derive(serde::Deserialize)]
+    struct Materialization {
+        endpoint: Endpoint,
+    }
+
+    matches!(
+        serde_json::from_str::<Materialization>(spec.get()),
+        Ok(Materialization {
+            endpoint: Endpoint { flow_sink: Some(_) }
+        })
+    )
 }
 
 pub async fn insert_errors(
@@ -133,15 +226,195 @@ pub async fn insert_errors(
     errors: Vec<Error>,
     txn: &mut sqlx::Transaction<'_, sqlx::Postgres>,
 ) -> anyhow::Result<()> {
-    for err in errors {
-        drafts_sql::insert_error(
-            draft_id,
-            err.scope.unwrap_or(err.catalog_name),
-            err.detail,
-            txn,
+    if errors.is_empty() {
+        return Ok(());
+    }
+    let (scopes, details) = errors
+        .into_iter()
+        .map(|err| (err.resolved_scope(), err.detail))
+        .unzip();
+
+    drafts_sql::insert_errors(draft_id, scopes, details, txn)
+        .await
+        .context("inserting errors")?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::{extend_catalog, extend_catalog_with_warnings, insert_errors, Error};
+    use crate::FIXED_DATABASE_URL;
+    use agent_sql::{CatalogType, Id};
+    use sqlx::Connection;
+
+    #[test]
+    fn test_extend_catalog_warnings() {
+        let raw = |value: serde_json::Value| serde_json::value::to_raw_value(&value).unwrap();
+
+        let current = raw(serde_json::json!({
+            "endpoint": {"connector": {"image": "acmeCo/materialize:v1", "config": {}}},
+            "bindings": [],
+        }));
+        let deprecated = raw(serde_json::json!({
+            "endpoint": {"flowSink": {"image": "acmeCo/materialize:v1", "config": {}}},
+            "bindings": [],
+        }));
+        let malformed = raw(serde_json::json!({"endpoint": {"flowSink": 42}}));
+
+        let specs = [
+            (
+                CatalogType::Materialization,
+                "acmeCo/current",
+                current.as_ref(),
+            ),
+            (
+                CatalogType::Materialization,
+                "acmeCo/deprecated",
+                deprecated.as_ref(),
+            ),
+            (
+                CatalogType::Materialization,
+                "acmeCo/malformed",
+                malformed.as_ref(),
+            ),
+        ];
+
+        let mut catalog = models::Catalog::default();
+        let super::Extended { errors, warnings } =
+            extend_catalog_with_warnings(&mut catalog, specs.iter().copied());
+
+        // The deprecated specification is added to the catalog, with a warning.
+        assert_eq!(
+            catalog
+                .materializations
+                .keys()
+                .map(|name| name.as_str())
+                .collect::<Vec<_>>(),
+            vec!["acmeCo/current", "acmeCo/deprecated"]
+        );
+        assert_eq!(
+            warnings
+                .iter()
+                .map(|w| (w.catalog_name.as_str(), w.detail.as_str()))
+                .collect::<Vec<_>>(),
+            vec![(
+                "acmeCo/deprecated",
+                "materialization acmeCo/deprecated uses the deprecated endpoint 'flowSink', which should be renamed to 'connector'",
+            )]
+        );
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].catalog_name, "acmeCo/malformed");
+
+        // extend_catalog returns only errors.
+        let mut catalog = models::Catalog::default();
+        let errors = extend_catalog(&mut catalog, specs.iter().copied());
+        assert_eq!(errors.len(), 1);
+        assert_eq!(catalog.materializations.len(), 2);
+    }
+
+    #[test]
+    fn test_extend_catalog_error_locations() {
+        let raw = |value: serde_json::Value| serde_json::value::to_raw_value(&value).unwrap();
+
+        let bad_key = raw(serde_json::json!({"key": "invalid"}));
+        let bad_step = raw(serde_json::json!([
+            {"ingest": {"collection": "acmeCo/orders", "documents": []}},
+            {"verify": {"collection": "acmeCo/orders", "documents": [], "description": 42}},
+        ]));
+        let specs = [
+            (CatalogType::Collection, "acmeCo/orders", bad_key.as_ref()),
+            (CatalogType::Test, "acmeCo/tests/orders", bad_step.as_ref()),
+        ];
+
+        let errors = extend_catalog(&mut models::Catalog::default(), specs.iter().copied());
+
+        assert_eq!(
+            errors
+                .iter()
+                .map(|err| (err.location.as_deref(), err.resolved_scope()))
+                .collect::<Vec<_>>(),
+            vec![
+                (Some("/key"), "flow://spec/acmeCo/orders#/key".to_string()),
+                (
+                    Some("/1/verify/description"),
+                    "flow://spec/acmeCo/tests/orders#/1/verify/description".to_string()
+                ),
+            ]
+        );
+        assert_eq!(
+            errors[0].detail,
+            "parsing collection acmeCo/orders: invalid type: string \"invalid\", expected a sequence at line 1 column 17",
+        );
+
+        // An explicit scope takes precedence, and without a location
+        // the scope is the catalog name.
+        let err = Error {
+            catalog_name: "acmeCo/orders".to_string(),
+            scope: Some("flow://acmeCo/orders#/schema".to_string()),
+            location: Some("/key".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(err.resolved_scope(), "flow://acmeCo/orders#/schema");
+
+        let err = Error {
+            catalog_name: "acmeCo/orders".to_string(),
+            ..Default::default()
+        };
+        assert_eq!(err.resolved_scope(), "acmeCo/orders");
+    }
+
+    #[tokio::test]
+    #[serial_test::parallel]
+    async fn test_insert_many_errors() {
+        let mut conn = sqlx::postgres::PgConnection::connect(&FIXED_DATABASE_URL)
+            .await
+            .unwrap();
+        let mut txn = conn.begin().await.unwrap();
+
+        sqlx::query(
+            r#"
+            with p1 as (
+              insert into auth.users (id) values
+              ('43a18a3e-5a59-11ed-9b6a-0242ac120006')
+            )
+            insert into drafts (id, user_id) values
+            ('6660000000000000', '43a18a3e-5a59-11ed-9b6a-0242ac120006');
+            "#,
+        )
+        .execute(&mut txn)
+        .await
+        .unwrap();
+
+        // Errors alternately have an explicit scope, or fall back to their catalog name.
+        let errors = (0..500)
+            .map(|i| Error {
+                catalog_name: format!("acmeCo/spec-{i:03}"),
+                scope: (i % 2 == 0).then(|| format!("flow://acmeCo/spec-{i:03}#/key")),
+                location: None,
+                detail: format!("error {i}"),
+            })
+            .collect();
+
+        let draft_id = Id::from_hex("6660000000000000").unwrap();
+        insert_errors(draft_id, errors, &mut txn).await.unwrap();
+
+        let rows: Vec<(String, String)> = sqlx::query_as(
+            "select scope, detail from draft_errors where draft_id = '6660000000000000' order by detail;",
         )
+        .fetch_all(&mut txn)
         .await
-        .context("inserting error")?;
+        .unwrap();
+
+        assert_eq!(rows.len(), 500);
+        for (scope, detail) in rows {
+            let i: usize = detail.strip_prefix("error ").unwrap().parse().unwrap();
+            let expect = if i % 2 == 0 {
+                format!("flow://acmeCo/spec-{i:03}#/key")
+            } else {
+                format!("acmeCo/spec-{i:03}")
+            };
+            assert_eq!(scope, expect);
+        }
     }
-    Ok(())
 }
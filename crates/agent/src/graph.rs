@@ -0,0 +1,152 @@
+use agent_sql::CatalogType;
+use std::collections::BTreeSet;
+use std::fmt::Write;
+
+/// Node of a catalog dependency graph, such as a row of `live_specs`
+/// or a specification of a completed publication.
+#[derive(Debug, Clone)]
+pub struct Node {
+    pub catalog_name: String,
+    pub catalog_type: CatalogType,
+    /// Collections which this specification reads from.
+    pub reads_from: Vec<String>,
+    /// Collections which this specification writes to.
+    pub writes_to: Vec<String>,
+}
+
+/// Directed data-flow edges of the graph, as (source, target) pairs.
+/// A specification which reads from a collection has an edge from that
+/// collection, and one which writes to a collection has an edge to it.
+/// Edges are de-duplicated and returned in sorted order.
+pub fn edges(nodes: &[Node]) -> BTreeSet<(&str, &str)> {
+    let mut edges = BTreeSet::new();
+
+    for node in nodes {
+        for source in &node.reads_from {
+            edges.insert((source.as_str(), node.catalog_name.as_str()));
+        }
+        for target in &node.writes_to {
+            edges.insert((node.catalog_name.as_str(), target.as_str()));
+        }
+    }
+    edges
+}
+
+/// Export the graph of `nodes` in Graphviz DOT format. Nodes are shaped by
+/// their CatalogType. An edge may reference a collection which isn't itself
+/// among `nodes`, in which case Graphviz renders it with a default shape.
+pub fn to_dot(nodes: &[Node]) -> String {
+    let mut w = String::new();
+    writeln!(w, "digraph catalog {{").unwrap();
+    writeln!(w, "  rankdir=LR;").unwrap();
+
+    for node in nodes {
+        let shape = match node.catalog_type {
+            CatalogType::Capture => "invhouse",
+            CatalogType::Collection => "cylinder",
+            CatalogType::Materialization => "house",
+            CatalogType::Test => "note",
+        };
+        writeln!(
+            w,
+            "  {} [shape={shape}, type={}];",
+            quote(&node.catalog_name),
+            quote(&node.catalog_type.to_string()),
+        )
+        .unwrap();
+    }
+    for (source, target) in edges(nodes) {
+        writeln!(w, "  {} -> {};", quote(source), quote(target)).unwrap();
+    }
+
+    writeln!(w, "}}").unwrap();
+    w
+}
+
+/// Export the graph of `nodes` in a JSON node-link format:
+/// `{"nodes": [{"id", "type"}], "links": [{"source", "target"}]}`.
+pub fn to_node_link(nodes: &[Node]) -> serde_json::Value {
+    let links: Vec<_> = edges(nodes)
+        .into_iter()
+        .map(|(source, target)| serde_json::json!({"source": source, "target": target}))
+        .collect();
+
+    let nodes: Vec<_> = nodes
+        .iter()
+        .map(|node| {
+            serde_json::json!({
+                "id": node.catalog_name,
+                "type": node.catalog_type.to_string(),
+            })
+        })
+        .collect();
+
+    serde_json::json!({"nodes": nodes, "links": links})
+}
+
+// Quote a DOT identifier.
+fn quote(id: &str) -> String {
+    format!("\"{}\"", id.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn chain() -> Vec<Node> {
+        vec![
+            Node {
+                catalog_name: "acmeCo/capture".to_string(),
+                catalog_type: CatalogType::Capture,
+                reads_from: Vec::new(),
+                writes_to: vec!["acmeCo/orders".to_string()],
+            },
+            Node {
+                catalog_name: "acmeCo/orders".to_string(),
+                catalog_type: CatalogType::Collection,
+                reads_from: Vec::new(),
+                writes_to: Vec::new(),
+            },
+            Node {
+                catalog_name: "acmeCo/materialize".to_string(),
+                catalog_type: CatalogType::Materialization,
+                reads_from: vec!["acmeCo/orders".to_string(), "acmeCo/orders".to_string()],
+                writes_to: Vec::new(),
+            },
+        ]
+    }
+
+    #[test]
+    fn test_dot_export() {
+        assert_eq!(
+            to_dot(&chain()),
+            r#"digraph catalog {
+  rankdir=LR;
+  "acmeCo/capture" [shape=invhouse, type="capture"];
+  "acmeCo/orders" [shape=cylinder, type="collection"];
+  "acmeCo/materialize" [shape=house, type="materialization"];
+  "acmeCo/capture" -> "acmeCo/orders";
+  "acmeCo/orders" -> "acmeCo/materialize";
+}
+"#
+        );
+    }
+
+    #[test]
+    fn test_node_link_export() {
+        assert_eq!(
+            to_node_link(&chain()),
+            serde_json::json!({
+                "nodes": [
+                    {"id": "acmeCo/capture", "type": "capture"},
+                    {"id": "acmeCo/orders", "type": "collection"},
+                    {"id": "acmeCo/materialize", "type": "materialization"},
+                ],
+                "links": [
+                    {"source": "acmeCo/capture", "target": "acmeCo/orders"},
+                    {"source": "acmeCo/orders", "target": "acmeCo/materialize"},
+                ],
+            })
+        );
+    }
+}
@@ -3,6 +3,7 @@ mod directives;
 mod discovers;
 pub(crate) mod draft;
 pub(crate) mod evolution;
+pub mod graph;
 mod handlers;
 mod jobs;
 pub mod logs;
@@ -16,7 +17,10 @@ pub use discovers::DiscoverHandler;
 pub use evolution::EvolutionHandler;
 pub use handlers::{serve, HandleResult, Handler};
 use lazy_static::lazy_static;
-pub use publications::PublishHandler;
+pub use publications::{
+    orphans::prune_orphans, rollback::rollback_publication, specs::preview_specifications,
+    PublishHandler,
+};
 use regex::Regex;
 
 // Used during tests.
@@ -44,7 +44,8 @@ struct Args {
     /// Email address of user which provisions and maintains tenant accounts.
     #[clap(long = "accounts-email", default_value = "support@estuary.dev")]
     accounts_email: String,
-    /// Allow local connectors. True for local stacks, and false otherwise.
+    /// Allow local connectors and SQLite materializations.
+    /// True for local stacks, and false otherwise.
     #[clap(long = "allow-local")]
     allow_local: bool,
 }
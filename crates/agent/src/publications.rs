@@ -1,6 +1,7 @@
-use std::collections::HashSet;
+use std::collections::{BTreeSet, HashSet};
 
 use self::builds::IncompatibleCollection;
+use self::specs::PublicationSummary;
 use self::validation::ControlPlane;
 use super::{
     draft::{self, Error},
@@ -13,6 +14,8 @@ use tracing::info;
 
 pub mod builds;
 mod linked_materializations;
+pub mod orphans;
+pub mod rollback;
 pub mod specs;
 mod storage;
 mod validation;
@@ -57,6 +60,44 @@ impl JobStatus {
     }
 }
 
+/// ResolvedStatus is the `job_status` persisted for a handled publication:
+/// its JobStatus, extended with the publication report of its errors and
+/// summary (see `specs::publication_report`).
+#[derive(Debug, Serialize)]
+struct ResolvedStatus<'a> {
+    #[serde(flatten)]
+    status: &'a JobStatus,
+    report: &'a serde_json::Value,
+}
+
+/// Number of consecutive attempts to acquire the row locks of a publication,
+/// after which the handler gives up and fails the publication.
+const MAX_LOCK_ATTEMPTS: u32 = 10;
+
+/// Returns true if the given error is a failure to acquire a lock
+/// (see `is_acquire_lock_error`), or is a detected deadlock (40P01) or
+/// serialization failure (40001). Publications lock many rows and may contend
+/// with one another, and in each case the publication may be retried.
+fn is_lock_contention_error(err: &anyhow::Error) -> bool {
+    if crate::is_acquire_lock_error(err) {
+        return true;
+    }
+    let Some(sql_err) = err.downcast_ref::<sqlx::Error>() else {
+        return false;
+    };
+    sql_err
+        .as_database_error()
+        .and_then(|e| e.code())
+        .filter(|code| matches!(code.as_ref(), "40P01" | "40001"))
+        .is_some()
+}
+
+// Backoff before the next attempt to acquire row locks, which doubles with
+// each consecutive failure from 500ms, up to a maximum of 8s.
+fn lock_retry_backoff(lock_attempts: u32) -> std::time::Duration {
+    std::time::Duration::from_millis(500) * 2u32.pow(lock_attempts.clamp(1, 5) - 1)
+}
+
 /// A PublishHandler is a Handler which publishes catalog specifications.
 pub struct PublishHandler {
     agent_user_email: String,
@@ -67,7 +108,11 @@ pub struct PublishHandler {
     connector_network: String,
     consumer_address: url::Url,
     control_plane: ControlPlane,
+    draft_quotas: specs::DraftQuotas,
+    image_policy: specs::ConnectorImagePolicy,
     logs_tx: logs::Tx,
+    max_test_steps: Option<usize>,
+    reserved_prefixes: Vec<String>,
 }
 
 impl PublishHandler {
@@ -91,7 +136,52 @@ impl PublishHandler {
             connector_network: connector_network.to_string(),
             consumer_address: consumer_address.clone(),
             control_plane: ControlPlane::new(pool),
+            draft_quotas: specs::DraftQuotas::default(),
+            image_policy: specs::ConnectorImagePolicy::default(),
             logs_tx: logs_tx.clone(),
+            max_test_steps: Some(specs::DEFAULT_MAX_TEST_STEPS),
+            reserved_prefixes: specs::DEFAULT_RESERVED_PREFIXES
+                .iter()
+                .map(|prefix| prefix.to_string())
+                .collect(),
+        }
+    }
+
+    /// Returns a copy of this PublishHandler which rejects drafted catalog names
+    /// under any of the given `reserved_prefixes`, in place of the defaults.
+    pub fn with_reserved_prefixes(self, reserved_prefixes: Vec<String>) -> Self {
+        Self {
+            reserved_prefixes,
+            ..self
+        }
+    }
+
+    /// Returns a copy of this PublishHandler which enforces `draft_quotas`
+    /// on the specifications of each publication, in place of the defaults.
+    pub fn with_draft_quotas(self, draft_quotas: specs::DraftQuotas) -> Self {
+        Self {
+            draft_quotas,
+            ..self
+        }
+    }
+
+    /// Returns a copy of this PublishHandler which rejects drafted specifications
+    /// having connector images that aren't permitted by `image_policy`.
+    /// By default, images of any name and tag are permitted.
+    pub fn with_image_policy(self, image_policy: specs::ConnectorImagePolicy) -> Self {
+        Self {
+            image_policy,
+            ..self
+        }
+    }
+
+    /// Returns a copy of this PublishHandler which rejects drafted tests having
+    /// more than `max_test_steps` steps, in place of the default of
+    /// `specs::DEFAULT_MAX_TEST_STEPS`. If None, tests may have any number of steps.
+    pub fn with_max_test_steps(self, max_test_steps: Option<usize>) -> Self {
+        Self {
+            max_test_steps,
+            ..self
         }
     }
 }
@@ -103,6 +193,10 @@ impl Handler for PublishHandler {
         pg_pool: &sqlx::PgPool,
         allow_background: bool,
     ) -> anyhow::Result<HandleResult> {
+        // Consecutive attempts to acquire the row locks of `lock_pub_id`.
+        let mut lock_attempts = 0;
+        let mut lock_pub_id = None;
+
         loop {
             let mut txn = pg_pool.begin().await?;
 
@@ -121,12 +215,26 @@ impl Handler for PublishHandler {
 
             let time_queued = chrono::Utc::now().signed_duration_since(row.updated_at);
             let id = row.pub_id;
+            let draft_id = row.draft_id;
+
+            if lock_pub_id != Some(id) {
+                lock_pub_id = Some(id);
+                lock_attempts = 0;
+            }
             let process_result = self.process(row, &mut txn, false).await;
 
-            let status = match process_result {
-                Ok((_, status)) => status,
-                Err(err) if crate::is_acquire_lock_error(&err) => {
-                    tracing::info!(%id, %time_queued, "cannot acquire all row locks for publication (will retry)");
+            let (status, report) = match process_result {
+                Ok((_, status, report)) => (status, report),
+                Err(err)
+                    if is_lock_contention_error(&err) && lock_attempts + 1 == MAX_LOCK_ATTEMPTS =>
+                {
+                    lock_attempts += 1;
+                    tracing::warn!(%id, %time_queued, %lock_attempts, error = ?err, "cannot acquire all row locks for publication (giving up)");
+                    fail_lock_contention(draft_id, lock_attempts, err, &mut txn).await?
+                }
+                Err(err) if is_lock_contention_error(&err) => {
+                    lock_attempts += 1;
+                    tracing::info!(%id, %time_queued, %lock_attempts, "cannot acquire all row locks for publication (will retry)");
                     // Since we failed to acquire a necessary row lock, wait a short
                     // while and then try again.
                     txn.rollback().await?;
@@ -139,14 +247,20 @@ impl Handler for PublishHandler {
                     // see a need for jitter. All agents process the job queue in
                     // the same order, so the next time any agent polls the
                     // handler, it should get this same job, since we've released
-                    // the lock on the job row.
-                    tokio::time::sleep(std::time::Duration::from_millis(500)).await;
+                    // the lock on the job row. We back off further with each
+                    // consecutive failure, in case we're contending with a
+                    // long-running publication.
+                    tokio::time::sleep(lock_retry_backoff(lock_attempts)).await;
                     continue;
                 }
                 Err(other_err) => return Err(other_err),
             };
             info!(%id, %time_queued, ?status, %background, "finished");
-            agent_sql::publications::resolve(id, &status, &mut txn).await?;
+            let resolved = ResolvedStatus {
+                status: &status,
+                report: &report,
+            };
+            agent_sql::publications::resolve(id, &resolved, &mut txn).await?;
             txn.commit().await?;
 
             // As a separate transaction, delete the draft if it has no draft_specs.
@@ -170,7 +284,7 @@ impl PublishHandler {
         row: Row,
         txn: &mut sqlx::Transaction<'_, sqlx::Postgres>,
         test_run: bool,
-    ) -> anyhow::Result<(Id, JobStatus)> {
+    ) -> anyhow::Result<(Id, JobStatus, serde_json::Value)> {
         info!(
             %row.created_at,
             %row.draft_id,
@@ -192,8 +306,14 @@ impl PublishHandler {
             .await
             .context("creating savepoint")?;
 
-        let spec_rows =
-            specs::resolve_specifications(row.draft_id, row.pub_id, row.user_id, txn).await?;
+        let spec_rows = specs::resolve_specifications(
+            row.draft_id,
+            row.pub_id,
+            row.user_id,
+            row.only_catalog_names.as_deref(),
+            txn,
+        )
+        .await?;
         tracing::debug!(specs = %spec_rows.len(), "resolved specifications");
 
         // Keep track of which collections are being deleted so that we can account for them
@@ -227,7 +347,7 @@ impl PublishHandler {
             anyhow::bail!("unexpected errors from live specs: {errors:?}");
         }
 
-        let errors = draft::extend_catalog(
+        let draft::Extended { errors, warnings } = draft::extend_catalog_with_warnings(
             &mut draft_catalog,
             spec_rows.iter().filter_map(|r| {
                 r.draft_type.map(|t| {
@@ -239,20 +359,127 @@ impl PublishHandler {
                 })
             }),
         );
+        for warning in warnings {
+            tracing::warn!(
+                catalog_name = %warning.catalog_name,
+                detail = %warning.detail,
+                "drafted specification has a warning",
+            );
+        }
         if !errors.is_empty() {
             return stop_with_errors(errors, JobStatus::build_failed(Vec::new()), row, txn).await;
         }
 
-        if let Err((errors, incompatible_collections)) =
-            specs::validate_transition(&draft_catalog, &live_catalog, row.pub_id, &spec_rows)
-        {
-            return stop_with_errors(
-                errors,
-                JobStatus::build_failed(incompatible_collections),
-                row,
+        // Write schema Shapes of drafted collections, shared by the validators below.
+        let draft_shapes = specs::write_schema_shapes(&draft_catalog);
+
+        // Established collections having live readers, whose changes can't be forced.
+        let read_collections = if row.force {
+            agent_sql::publications::resolve_read_collections(
+                live_catalog
+                    .collections
+                    .keys()
+                    .map(|name| name.as_str())
+                    .collect(),
                 txn,
             )
-            .await;
+            .await
+            .context("resolving read collections")?
+            .into_iter()
+            .collect()
+        } else {
+            BTreeSet::new()
+        };
+
+        // Errors of forceable checks which were overridden by `force`.
+        let forced = match specs::validate_transition(
+            &draft_catalog,
+            &draft_shapes,
+            &live_catalog,
+            row.pub_id,
+            row.force,
+            &read_collections,
+            &spec_rows,
+        ) {
+            Ok(forced) => forced,
+            Err((errors, incompatible_collections)) => {
+                return stop_with_errors(
+                    errors,
+                    JobStatus::build_failed(incompatible_collections),
+                    row,
+                    txn,
+                )
+                .await;
+            }
+        };
+        for warning in &forced {
+            tracing::warn!(
+                catalog_name = %warning.catalog_name,
+                detail = %warning.detail,
+                "forced publication overrides a validation error",
+            );
+        }
+
+        let errors = specs::validate_test_steps(&draft_catalog, &live_catalog, txn).await?;
+        if !errors.is_empty() {
+            return stop_with_errors(errors, JobStatus::build_failed(Vec::new()), row, txn).await;
+        }
+
+        let errors = specs::validate_references(&draft_catalog, &spec_rows, txn).await?;
+        if !errors.is_empty() {
+            return stop_with_errors(errors, JobStatus::build_failed(Vec::new()), row, txn).await;
+        }
+
+        let errors = specs::validate_renamed_references(&spec_rows, txn).await?;
+        if !errors.is_empty() {
+            return stop_with_errors(errors, JobStatus::build_failed(Vec::new()), row, txn).await;
+        }
+
+        let errors = specs::validate_reserved_prefixes(&self.reserved_prefixes, &draft_catalog);
+        if !errors.is_empty() {
+            return stop_with_errors(errors, JobStatus::build_failed(Vec::new()), row, txn).await;
+        }
+
+        let errors = specs::validate_connector_images(&draft_catalog);
+        if !errors.is_empty() {
+            return stop_with_errors(errors, JobStatus::build_failed(Vec::new()), row, txn).await;
+        }
+
+        let errors = specs::validate_connector_image_policy(&self.image_policy, &draft_catalog);
+        if !errors.is_empty() {
+            return stop_with_errors(errors, JobStatus::build_failed(Vec::new()), row, txn).await;
+        }
+
+        let errors = specs::validate_collection_schemas(&draft_catalog);
+        if !errors.is_empty() {
+            return stop_with_errors(errors, JobStatus::build_failed(Vec::new()), row, txn).await;
+        }
+
+        let errors = specs::validate_required_keys(&draft_catalog, &draft_shapes);
+        if !errors.is_empty() {
+            return stop_with_errors(errors, JobStatus::build_failed(Vec::new()), row, txn).await;
+        }
+
+        // SQLite materializations are permitted only where local connectors are.
+        if !self.allow_local {
+            let errors = specs::validate_sqlite_materializations(&draft_catalog);
+            if !errors.is_empty() {
+                return stop_with_errors(errors, JobStatus::build_failed(Vec::new()), row, txn)
+                    .await;
+            }
+        }
+
+        if let Some(max_test_steps) = self.max_test_steps {
+            let errors = specs::validate_max_test_steps(max_test_steps, &draft_catalog);
+            if !errors.is_empty() {
+                return stop_with_errors(errors, JobStatus::build_failed(Vec::new()), row, txn)
+                    .await;
+            }
+        }
+
+        let errors = specs::validate_draft_quotas(&self.draft_quotas, &spec_rows);
+        if !errors.is_empty() {
+            return stop_with_errors(errors, JobStatus::build_failed(Vec::new()), row, txn).await;
         }
 
         let live_spec_ids: Vec<_> = spec_rows.iter().map(|row| row.live_spec_id).collect();
@@ -278,6 +505,8 @@ impl PublishHandler {
             tracing::info!(?pruned_collections, "pruned unbound collections");
         }
         let pruned_collections = pruned_collections.into_iter().collect::<HashSet<_>>();
+        let summary =
+            specs::summarize_publication(row.detail.as_ref(), &spec_rows, &pruned_collections);
 
         if spec_rows.len() - pruned_collections.len() == 0 {
             return stop_with_errors(Vec::new(), JobStatus::EmptyDraft, row, txn).await;
@@ -313,12 +542,9 @@ impl PublishHandler {
         tracing::debug!(specs = %expanded_rows.len(), "resolved expanded specifications");
 
         // Touch all expanded specifications to update their build ID.
-        // TODO(johnny): This can potentially deadlock. We may eventually want
-        // to catch this condition and gracefully roll-back the transaction to
-        // allow it to be re-attempted. BUT I'm avoiding this extra code path
-        // (and the potential for new bugs) until we actually see this in practice.
-        // Current behavior is that the agent will crash and restart, and the
-        // publication will then go on to retry as desired.
+        // Rows are locked in `catalog_name` order, as they are when resolving
+        // specifications, so that concurrent publications don't deadlock.
+        // Should a deadlock nonetheless occur, it's retried by `handle`.
         agent_sql::publications::update_expanded_live_specs(
             &expanded_rows
                 .iter()
@@ -361,7 +587,11 @@ impl PublishHandler {
         }
 
         if test_run {
-            return Ok((row.pub_id, JobStatus::success(Vec::new())));
+            return Ok((
+                row.pub_id,
+                JobStatus::success(Vec::new()),
+                specs::publication_report(&[], &forced, &summary),
+            ));
         }
 
         let tmpdir_handle = tempfile::TempDir::new().context("creating tempdir")?;
@@ -438,7 +668,11 @@ impl PublishHandler {
                 .await
                 .context("adding built specs to draft")?;
 
-            return Ok((row.pub_id, JobStatus::success(Vec::new())));
+            return Ok((
+                row.pub_id,
+                JobStatus::success(Vec::new()),
+                specs::publication_report(&[], &forced, &summary),
+            ));
         }
 
         // Add built specs to the live spec when publishing a build.
@@ -483,20 +717,56 @@ impl PublishHandler {
         .await
         .context("creating linked materialization publications")?;
 
-        Ok((row.pub_id, JobStatus::success(pub_ids)))
+        Ok((
+            row.pub_id,
+            JobStatus::success(pub_ids),
+            specs::publication_report(&[], &forced, &summary),
+        ))
     }
 }
 
+// Fails a publication which couldn't acquire its row locks after
+// `lock_attempts` attempts, rather than retrying it indefinitely.
+async fn fail_lock_contention(
+    draft_id: Id,
+    lock_attempts: u32,
+    err: anyhow::Error,
+    txn: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+) -> anyhow::Result<(JobStatus, serde_json::Value)> {
+    // Row locks are acquired after the "noop" savepoint, and rolling back to
+    // it also recovers a transaction which was aborted by a deadlock.
+    agent_sql::publications::rollback_noop(txn)
+        .await
+        .context("rolling back to savepoint")?;
+
+    let errors = vec![Error {
+        detail: format!(
+            "failed to acquire row locks after {lock_attempts} attempts, because they're held by other publications (try again later): {err:#}"
+        ),
+        ..Default::default()
+    }];
+    let report = specs::publication_report(&errors, &[], &PublicationSummary::default());
+    draft::insert_errors(draft_id, errors, txn).await?;
+
+    Ok((JobStatus::PublishFailed, report))
+}
+
 async fn stop_with_errors(
     errors: Vec<Error>,
     mut job_status: JobStatus,
     row: Row,
     txn: &mut sqlx::Transaction<'_, sqlx::Postgres>,
-) -> anyhow::Result<(Id, JobStatus)> {
+) -> anyhow::Result<(Id, JobStatus, serde_json::Value)> {
     agent_sql::publications::rollback_noop(txn)
         .await
         .context("rolling back to savepoint")?;
 
+    // Changes are rolled back, and nothing is published.
+    let summary = PublicationSummary {
+        detail: row.detail.clone(),
+        ..Default::default()
+    };
+    let report = specs::publication_report(&errors, &[], &summary);
     draft::insert_errors(row.draft_id, errors, txn).await?;
 
     // If this is a result of a build failure, then we may need to create an evolutions job in response.
@@ -526,7 +796,7 @@ async fn stop_with_errors(
         }
     }
 
-    Ok((row.pub_id, job_status))
+    Ok((row.pub_id, job_status, report))
 }
 
 fn create_evolutions_requests(
@@ -561,7 +831,8 @@ mod test {
         builds::{AffectedConsumer, ReCreateReason},
         *,
     };
-    use crate::evolution::EvolveRequest;
+    use crate::{evolution::EvolveRequest, FIXED_DATABASE_URL};
+    use sqlx::Connection;
 
     #[test]
     fn test_create_evolutions_requests() {
@@ -620,4 +891,88 @@ mod test {
         ];
         assert_eq!(expected, requests);
     }
+
+    #[test]
+    fn test_lock_retry_backoff() {
+        let backoffs = (1..=MAX_LOCK_ATTEMPTS)
+            .map(|attempt| lock_retry_backoff(attempt).as_millis())
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            backoffs,
+            vec![500, 1000, 2000, 4000, 8000, 8000, 8000, 8000, 8000, 8000]
+        );
+    }
+
+    #[test]
+    fn test_resolved_status() {
+        let report = serde_json::json!({"errors": [], "summary": {"added": 1}});
+        let status = JobStatus::success(vec![Id::new([1; 8])]);
+        let resolved = serde_json::to_value(ResolvedStatus {
+            status: &status,
+            report: &report,
+        })
+        .unwrap();
+
+        assert_eq!(
+            resolved,
+            serde_json::json!({
+                "type": "success",
+                "linked_materialization_publications": ["0101010101010101"],
+                "report": {"errors": [], "summary": {"added": 1}},
+            })
+        );
+        // The persisted status remains readable as a JobStatus.
+        assert_eq!(
+            serde_json::from_value::<JobStatus>(resolved).unwrap(),
+            status
+        );
+    }
+
+    #[tokio::test]
+    #[serial_test::parallel]
+    async fn test_deadlocks_are_lock_contention() {
+        // Takes advisory lock `first`, waits for the other transaction to take
+        // its own first lock, and then takes lock `second`.
+        async fn lock_both(
+            first: i64,
+            second: i64,
+            barrier: std::sync::Arc<tokio::sync::Barrier>,
+        ) -> anyhow::Result<()> {
+            let mut conn = sqlx::postgres::PgConnection::connect(&FIXED_DATABASE_URL).await?;
+            let mut txn = conn.begin().await?;
+
+            sqlx::query("select pg_advisory_xact_lock($1);")
+                .bind(first)
+                .execute(&mut txn)
+                .await?;
+            barrier.wait().await;
+            sqlx::query("select pg_advisory_xact_lock($1);")
+                .bind(second)
+                .execute(&mut txn)
+                .await?;
+
+            txn.rollback().await?;
+            Ok(())
+        }
+
+        // Two connections take the same locks in opposite orders. Postgres
+        // detects the deadlock and aborts one of them, which closes its
+        // connection and allows the other to complete.
+        let barrier = std::sync::Arc::new(tokio::sync::Barrier::new(2));
+        let (a, b) = tokio::join!(
+            tokio::spawn(lock_both(2901, 2902, barrier.clone())),
+            tokio::spawn(lock_both(2902, 2901, barrier)),
+        );
+        let results = [a.unwrap(), b.unwrap()];
+
+        let errors = results
+            .iter()
+            .filter_map(|r| r.as_ref().err())
+            .collect::<Vec<_>>();
+        assert_eq!(errors.len(), 1, "{results:?}");
+        assert!(is_lock_contention_error(errors[0]), "{errors:?}");
+        // Deadlocks are retried only by publications.
+        assert!(!crate::is_acquire_lock_error(errors[0]), "{errors:?}");
+    }
 }
@@ -53,6 +53,7 @@ pub fn draft_errors(output: &Output) -> Vec<Error> {
         .errors()
         .map(|e| Error {
             scope: Some(e.scope.to_string()),
+            location: None,
             // Use "alternate" form to include compact, chained error causes.
             // See: https://docs.rs/anyhow/latest/anyhow/struct.Error.html#display-representations
             detail: format!("{:#}", e.error),
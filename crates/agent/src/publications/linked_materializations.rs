@@ -153,6 +153,7 @@ pub async fn validate_source_captures(
         errors.push(Error {
             catalog_name: materialization_name,
             scope: None,
+            location: None,
             detail,
         });
     }
@@ -0,0 +1,134 @@
+use agent_sql::{drafts, publications::OrphanRow};
+use anyhow::Context;
+
+/// Returns the orphaned collections under `catalog_prefix`, which are live
+/// collections that no capture, derivation, or materialization reads or writes.
+/// Derivations are never orphans. Collections which a capture writes to are
+/// also never orphans, as the capture's writes are a data-flow of the collection.
+///
+/// This is a dry run unless `delete` is set, in which case a publication is
+/// enqueued which deletes each orphan. The deletion is an ordinary publication
+/// of a draft, and each drafted deletion expects the orphan's last publication,
+/// so that an orphan which is raced by another publication fails the pruning.
+pub async fn prune_orphans(
+    catalog_prefix: &str,
+    user_email: &str,
+    delete: bool,
+    txn: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+) -> anyhow::Result<Vec<String>> {
+    let rows = agent_sql::publications::resolve_orphaned_collections(catalog_prefix, txn)
+        .await
+        .context("resolving orphaned collections")?;
+
+    if !delete || rows.is_empty() {
+        return Ok(rows.into_iter().map(|row| row.catalog_name).collect());
+    }
+
+    let detail = format!(
+        "system created publication which prunes {} orphaned collections under '{catalog_prefix}'",
+        rows.len()
+    );
+    let draft_id = drafts::create(user_email, detail.clone(), txn).await?;
+
+    let mut pruned = Vec::with_capacity(rows.len());
+    for OrphanRow {
+        catalog_name,
+        last_pub_id,
+    } in rows
+    {
+        drafts::upsert_deletion(draft_id, &catalog_name, Some(last_pub_id), txn).await?;
+        pruned.push(catalog_name);
+    }
+
+    // Pruning doesn't auto-evolve, and runs as a background job.
+    agent_sql::publications::create_with_user_email(txn, user_email, draft_id, false, detail, true)
+        .await?;
+
+    Ok(pruned)
+}
+
+#[cfg(test)]
+mod test {
+    use crate::FIXED_DATABASE_URL;
+    use sqlx::Connection;
+
+    // Fixture of live specifications:
+    // * acmeCo/capture writes to acmeCo/captured.
+    // * acmeCo/derived reads from acmeCo/captured, and acmeCo/materialize reads acmeCo/derived.
+    // * acmeCo/orphan is neither read nor written.
+    // * otherCo/orphan is also an orphan, but isn't under the pruned prefix.
+    const FIXTURE: &str = r#"
+        with p1 as (
+            insert into auth.users (id, email, email_confirmed_at) values
+            ('11111111-1111-1111-1111-111111111111', 'alice@example.com', now())
+        ),
+        p2 as (
+            insert into live_specs (id, catalog_name, spec, spec_type, last_build_id, last_pub_id) values
+            ('bb00000000000001', 'acmeCo/capture', '{}', 'capture', '1100000000000000', '1100000000000000'),
+            ('bb00000000000002', 'acmeCo/captured', '{"key":["/id"]}', 'collection', '1100000000000000', '1100000000000000'),
+            ('bb00000000000003', 'acmeCo/derived', '{"key":["/id"],"derive":{}}', 'collection', '1100000000000000', '1100000000000000'),
+            ('bb00000000000004', 'acmeCo/materialize', '{}', 'materialization', '1100000000000000', '1100000000000000'),
+            ('bb00000000000005', 'acmeCo/orphan', '{"key":["/id"]}', 'collection', '1100000000000000', '2200000000000000'),
+            ('bb00000000000006', 'otherCo/orphan', '{"key":["/id"]}', 'collection', '1100000000000000', '1100000000000000')
+        ),
+        p3 as (
+            insert into live_spec_flows (source_id, target_id, flow_type) values
+            ('bb00000000000001', 'bb00000000000002', 'capture'),
+            ('bb00000000000002', 'bb00000000000003', 'collection'),
+            ('bb00000000000003', 'bb00000000000004', 'materialization')
+        )
+        select 1;
+    "#;
+
+    // Returns drafted (catalog_name, spec, expect_pub_id, background) of pruning publications.
+    async fn drafted(
+        txn: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    ) -> Vec<(String, Option<String>, String, bool)> {
+        sqlx::query_as(
+            r#"
+            select d.catalog_name, d.spec::text, d.expect_pub_id::text, p.background
+            from publications p
+            join draft_specs d on d.draft_id = p.draft_id
+            where p.detail like '%orphaned collections%'
+            order by d.catalog_name;
+            "#,
+        )
+        .fetch_all(txn)
+        .await
+        .unwrap()
+    }
+
+    #[tokio::test]
+    #[serial_test::parallel]
+    async fn test_prune_orphans() {
+        let mut conn = sqlx::postgres::PgConnection::connect(&FIXED_DATABASE_URL)
+            .await
+            .unwrap();
+        let mut txn = conn.begin().await.unwrap();
+
+        sqlx::query(FIXTURE).execute(&mut txn).await.unwrap();
+
+        // A dry run reports the orphan, while referenced collections and
+        // the derivation are preserved. Nothing is drafted.
+        let pruned = super::prune_orphans("acmeCo/", "alice@example.com", false, &mut txn)
+            .await
+            .unwrap();
+        assert_eq!(pruned, vec!["acmeCo/orphan".to_string()]);
+        assert!(drafted(&mut txn).await.is_empty());
+
+        // With `delete`, a publication is enqueued which deletes the orphan.
+        let pruned = super::prune_orphans("acmeCo/", "alice@example.com", true, &mut txn)
+            .await
+            .unwrap();
+        assert_eq!(pruned, vec!["acmeCo/orphan".to_string()]);
+        assert_eq!(
+            drafted(&mut txn).await,
+            vec![(
+                "acmeCo/orphan".to_string(),
+                None,
+                "22:00:00:00:00:00:00:00".to_string(),
+                true,
+            )],
+        );
+    }
+}
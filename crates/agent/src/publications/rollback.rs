@@ -0,0 +1,193 @@
+use agent_sql::{drafts, publications::RollbackRow, Id};
+use anyhow::Context;
+
+/// Enqueues a publication which rolls back the publication `pub_id`, restoring
+/// each specification it published to its state as of its prior publication.
+/// Specifications which `pub_id` updated are reverted, those it deleted are
+/// reinstated, and those it created are deleted. The rollback is itself an
+/// ordinary publication of a draft, which is built and tested as usual.
+///
+/// Rollback fails if any specification of `pub_id` has since been changed by a
+/// later publication. Each drafted specification also expects `pub_id` to be its
+/// last publication, so that a race with another publication fails the rollback.
+pub async fn rollback_publication(
+    user_email: &str,
+    pub_id: Id,
+    txn: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+) -> anyhow::Result<Id> {
+    let rows = agent_sql::publications::resolve_rollback_rows(pub_id, txn)
+        .await
+        .context("resolving specifications to roll back")?;
+
+    if rows.is_empty() {
+        anyhow::bail!("publication {pub_id} didn't publish any specifications");
+    }
+
+    let changed: Vec<String> = rows
+        .iter()
+        .filter(|row| row.last_pub_id != pub_id)
+        .map(|row| format!("{} (by publication {})", row.catalog_name, row.last_pub_id))
+        .collect();
+
+    if !changed.is_empty() {
+        anyhow::bail!(
+            "cannot roll back publication {pub_id} because specifications have since changed: {}",
+            changed.join(", ")
+        );
+    }
+
+    let detail = format!("system created publication which rolls back publication {pub_id}");
+    let draft_id = drafts::create(user_email, detail.clone(), txn).await?;
+
+    for RollbackRow {
+        catalog_name,
+        prior_spec,
+        prior_spec_type,
+        ..
+    } in rows
+    {
+        match (prior_spec, prior_spec_type) {
+            (Some(spec), Some(spec_type)) => {
+                drafts::upsert_spec(
+                    draft_id,
+                    &catalog_name,
+                    spec.0,
+                    spec_type,
+                    Some(pub_id),
+                    txn,
+                )
+                .await?
+            }
+            _ => drafts::upsert_deletion(draft_id, &catalog_name, Some(pub_id), txn).await?,
+        }
+    }
+
+    // Rollbacks don't auto-evolve, and run as a foreground job.
+    let rollback_pub_id = agent_sql::publications::create_with_user_email(
+        txn, user_email, draft_id, false, detail, false,
+    )
+    .await?;
+
+    Ok(rollback_pub_id)
+}
+
+#[cfg(test)]
+mod test {
+    use crate::FIXED_DATABASE_URL;
+    use agent_sql::Id;
+    use sqlx::Connection;
+
+    // Fixture of two publications:
+    // * 1100 creates acmeCo/updated and acmeCo/deleted.
+    // * 2200 updates acmeCo/updated, deletes acmeCo/deleted, and creates acmeCo/created.
+    const FIXTURE: &str = r#"
+        with p1 as (
+            insert into auth.users (id, email, email_confirmed_at) values
+            ('11111111-1111-1111-1111-111111111111', 'alice@example.com', now())
+        ),
+        p2 as (
+            insert into live_specs (id, catalog_name, spec, spec_type, last_build_id, last_pub_id) values
+            ('aa00000000000001', 'acmeCo/updated', '{"v":2}', 'collection', '2200000000000000', '2200000000000000'),
+            ('aa00000000000002', 'acmeCo/deleted', null, null, '2200000000000000', '2200000000000000'),
+            ('aa00000000000003', 'acmeCo/created', '{"v":1}', 'collection', '2200000000000000', '2200000000000000')
+        ),
+        p3 as (
+            insert into publication_specs (live_spec_id, pub_id, spec, spec_type, user_id) values
+            ('aa00000000000001', '1100000000000000', '{"v":1}', 'collection', '11111111-1111-1111-1111-111111111111'),
+            ('aa00000000000001', '2200000000000000', '{"v":2}', 'collection', '11111111-1111-1111-1111-111111111111'),
+            ('aa00000000000002', '1100000000000000', '{"v":1}', 'collection', '11111111-1111-1111-1111-111111111111'),
+            ('aa00000000000002', '2200000000000000', null, null, '11111111-1111-1111-1111-111111111111'),
+            ('aa00000000000003', '2200000000000000', '{"v":1}', 'collection', '11111111-1111-1111-1111-111111111111')
+        )
+        select 1;
+    "#;
+
+    #[tokio::test]
+    #[serial_test::parallel]
+    async fn test_rollback_update_and_deletion() {
+        let mut conn = sqlx::postgres::PgConnection::connect(&FIXED_DATABASE_URL)
+            .await
+            .unwrap();
+        let mut txn = conn.begin().await.unwrap();
+
+        sqlx::query(FIXTURE).execute(&mut txn).await.unwrap();
+
+        // Publication 1100 cannot be rolled back, as its specs were changed by 2200.
+        let err = super::rollback_publication(
+            "alice@example.com",
+            Id::from_hex("1100000000000000").unwrap(),
+            &mut txn,
+        )
+        .await
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "cannot roll back publication 1100000000000000 because specifications have since changed: acmeCo/deleted (by publication 2200000000000000), acmeCo/updated (by publication 2200000000000000)"
+        );
+
+        // Publication 2200 is rolled back by drafting and enqueuing a publication
+        // which reverts the update, reinstates the deletion, and removes the creation.
+        let rollback_pub_id = super::rollback_publication(
+            "alice@example.com",
+            Id::from_hex("2200000000000000").unwrap(),
+            &mut txn,
+        )
+        .await
+        .unwrap();
+
+        let drafted: Vec<(String, Option<String>, Option<String>, String, String)> =
+            sqlx::query_as(
+                r#"
+            select
+                d.catalog_name,
+                d.spec::text,
+                d.spec_type::text,
+                d.expect_pub_id::text,
+                p.detail
+            from publications p
+            join draft_specs d on d.draft_id = p.draft_id
+            where p.id = $1
+            order by d.catalog_name;
+            "#,
+            )
+            .bind(rollback_pub_id)
+            .fetch_all(&mut txn)
+            .await
+            .unwrap();
+
+        insta::assert_debug_snapshot!(drafted, @r###"
+        [
+            (
+                "acmeCo/created",
+                None,
+                None,
+                "22:00:00:00:00:00:00:00",
+                "system created publication which rolls back publication 2200000000000000",
+            ),
+            (
+                "acmeCo/deleted",
+                Some(
+                    "{\"v\":1}",
+                ),
+                Some(
+                    "collection",
+                ),
+                "22:00:00:00:00:00:00:00",
+                "system created publication which rolls back publication 2200000000000000",
+            ),
+            (
+                "acmeCo/updated",
+                Some(
+                    "{\"v\":1}",
+                ),
+                Some(
+                    "collection",
+                ),
+                "22:00:00:00:00:00:00:00",
+                "system created publication which rolls back publication 2200000000000000",
+            ),
+        ]
+        "###);
+    }
+}
@@ -4,17 +4,20 @@ use agent_sql::publications::{ExpandedRow, SpecRow, Tenant};
 use agent_sql::{Capability, CatalogType, Id};
 use anyhow::Context;
 use itertools::Itertools;
+use serde::Serialize;
 use sqlx::types::Uuid;
-use std::collections::{BTreeMap, HashMap, HashSet};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
 
 // resolve_specifications returns the definitive set of specifications which
 // are changing in this publication. It obtains sufficient locks to ensure
 // that raced publications to returned specifications are serialized with
-// this publication.
+// this publication. If `only` is Some, then only the draft specifications
+// having those catalog names are resolved, and others remain in the draft.
 pub async fn resolve_specifications(
     draft_id: Id,
     pub_id: Id,
     user_id: Uuid,
+    only: Option<&[String]>,
     txn: &mut sqlx::Transaction<'_, sqlx::Postgres>,
 ) -> anyhow::Result<Vec<SpecRow>> {
     // Attempt to create a row in live_specs for each of our draft_specs.
@@ -28,7 +31,7 @@ pub async fn resolve_specifications(
     // "on conflict .. do nothing" semantics, and we'll lock the new row next.
     //
     // See: https://www.postgresql.org/docs/14/transaction-iso.html#XACT-READ-COMMITTED
-    let rows = agent_sql::publications::insert_new_live_specs(draft_id, pub_id, txn)
+    let rows = agent_sql::publications::insert_new_live_specs(draft_id, pub_id, only, txn)
         .await
         .context("inserting new live_specs")?;
 
@@ -47,7 +50,7 @@ pub async fn resolve_specifications(
     // of what's "in" this publication, and what's not. Anything we don't pick up here will
     // be left behind as a draft_spec, and this is the reason we don't delete the draft
     // itself within this transaction.
-    let mut spec_rows = agent_sql::publications::resolve_spec_rows(draft_id, user_id, txn)
+    let mut spec_rows = agent_sql::publications::resolve_spec_rows(draft_id, user_id, only, txn)
         .await
         .context("selecting joined draft & live specs")?;
 
@@ -68,6 +71,31 @@ pub async fn resolve_specifications(
     Ok(spec_rows)
 }
 
+// preview_specifications returns the specifications which would change in a
+// publication of the draft, exactly as `resolve_specifications` would, but
+// without any lasting side-effects: the `live_specs` rows which are inserted
+// to take locks are rolled back before returning. Each returned row carries
+// both its `draft_spec` and current `live_spec`, from which a caller may
+// present the change that a publication would make.
+pub async fn preview_specifications(
+    draft_id: Id,
+    pub_id: Id,
+    user_id: Uuid,
+    txn: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+) -> anyhow::Result<Vec<SpecRow>> {
+    agent_sql::publications::savepoint_preview(txn)
+        .await
+        .context("creating preview savepoint")?;
+
+    let result = resolve_specifications(draft_id, pub_id, user_id, None, txn).await;
+
+    agent_sql::publications::rollback_preview(txn)
+        .await
+        .context("rolling back preview savepoint")?;
+
+    result
+}
+
 // expanded_specifications returns additional specifications which should be
 // included in this publication's build. Attempts to acquire a lock on each expanded `live_specs`
 // row, with the assumption that we will be updating the `built_spec` and `last_build_id`.
@@ -95,13 +123,30 @@ pub async fn expanded_specifications(
     Ok(expanded_rows)
 }
 
+/// Validates the transition of live specifications to their drafted counterparts.
+/// `draft_shapes` are the write schema Shapes of the drafted collections, and
+/// `read_collections` are the established collections which have live readers.
+///
+/// Some checks are conservative, and are forceable: changes to the partitions
+/// of an established collection, and narrowings of its key and partition
+/// locations. If `force` is set and the collection has no live readers, then
+/// their errors are instead returned as warnings, and don't fail the publication.
+/// Structural checks, such as authorization, agreement of catalog types, and
+/// collection key changes, are never forceable.
 pub fn validate_transition(
     draft: &models::Catalog,
+    draft_shapes: &BTreeMap<&models::Collection, doc::Shape>,
     live: &models::Catalog,
     pub_id: Id,
+    force: bool,
+    read_collections: &BTreeSet<String>,
     spec_rows: &[SpecRow],
-) -> Result<(), (Vec<Error>, Vec<IncompatibleCollection>)> {
+) -> Result<Vec<Error>, (Vec<Error>, Vec<IncompatibleCollection>)> {
     let mut errors = Vec::new();
+    // Errors of forceable checks, which are overridden by a forced publication.
+    let mut warnings = Vec::new();
+    // Narrowings of collection locations which are neither keys nor partitions.
+    let mut narrowings = Vec::new();
 
     // If collection changes are deemed to be incompatible here, then it
     // could potentially be for several reasons. Accumulate those reasons per
@@ -110,6 +155,8 @@ pub fn validate_transition(
     // require re-creating the collection.
     let mut incompatible_collections: BTreeMap<String, Vec<ReCreateReason>> = BTreeMap::new();
 
+    let renames = renamed_collections(spec_rows);
+
     for spec_row @ SpecRow {
         catalog_name,
         draft_spec: _,
@@ -119,8 +166,10 @@ pub fn validate_transition(
         last_build_id: _,
         last_pub_id,
         live_spec: _,
+        live_spec_hash: _,
         live_spec_id: _,
         live_type,
+        rename_from,
         spec_capabilities,
         user_capability,
     } in spec_rows
@@ -173,6 +222,38 @@ pub fn validate_transition(
             }
         }
 
+        // Specifications may not continue to reference a collection which is renamed.
+        for reference in reads_from.iter().chain(writes_to.iter()).flatten() {
+            if let Some(renamed_to) = renames.get(reference) {
+                errors.push(renamed_reference_error(catalog_name, reference, renamed_to));
+            }
+        }
+
+        // A rename must create a new collection, while deleting its prior live collection.
+        if let Some(rename_from) = rename_from {
+            if draft_type != &Some(CatalogType::Collection) || live_type.is_some() {
+                errors.push(Error {
+                    catalog_name: catalog_name.clone(),
+                    detail: format!(
+                        "Only the creation of a new collection may be renamed from '{rename_from}'"
+                    ),
+                    ..Default::default()
+                });
+            } else if !spec_rows.iter().any(|r| {
+                &r.catalog_name == rename_from
+                    && r.draft_type.is_none()
+                    && r.live_type == Some(CatalogType::Collection)
+            }) {
+                errors.push(Error {
+                    catalog_name: catalog_name.clone(),
+                    detail: format!(
+                        "Renaming from '{rename_from}' requires that this publication also deletes the live collection '{rename_from}'"
+                    ),
+                    ..Default::default()
+                });
+            }
+        }
+
         // If neither `live_type` nor `draft_type` is deleted, then they must agree.
         if matches!((live_type, draft_type), (Some(live_type), Some(draft_type)) if live_type != draft_type)
         {
@@ -247,6 +328,10 @@ pub fn validate_transition(
             None => continue,
         };
 
+        // Forcing a change of a collection having live readers could corrupt
+        // their view of it, and isn't permitted.
+        let forceable = force && !read_collections.contains(catalog_name.as_str());
+
         if !draft.key.iter().eq(live.key.iter()) {
             errors.push(Error {
                 catalog_name: catalog_name.to_string(),
@@ -285,22 +370,70 @@ pub fn validate_transition(
         let live_partitions = partitions(&live.projections);
 
         if draft_partitions != live_partitions {
-            errors.push(Error {
+            let error = Error {
                 catalog_name: catalog_name.to_string(),
                 detail: format!(
                     "Cannot change partitions of an established collection (from {live_partitions:?} to {draft_partitions:?})",
                 ),
                 ..Default::default()
-            });
-            let reasons = incompatible_collections
-                .entry(catalog_name.to_string())
-                .or_insert(Vec::new());
-            reasons.push(ReCreateReason::PartitionChange);
+            };
+            if forceable {
+                warnings.push(error);
+            } else {
+                errors.push(error);
+                let reasons = incompatible_collections
+                    .entry(catalog_name.to_string())
+                    .or_insert(Vec::new());
+                reasons.push(ReCreateReason::PartitionChange);
+            }
+        }
+
+        // Narrowing the write schema of an established collection may break its
+        // readers. Only narrowings of key and partition locations are errors:
+        // discovered and auto-evolved schemas routinely narrow other locations,
+        // and those narrowings are logged without failing the publication.
+        if let (Some(live_shape), Some(draft_shape)) =
+            (write_schema_shape(live), draft_shapes.get(catalog_name))
+        {
+            let extracted = draft
+                .key
+                .iter()
+                .map(|ptr| ptr.as_str())
+                .chain(draft.projections.values().filter_map(|projection| {
+                    match projection.as_parts() {
+                        (location, true) => Some(location.as_str()),
+                        (_, false) => None,
+                    }
+                }))
+                .collect::<BTreeSet<_>>();
+
+            for (ptr, detail) in schema_narrowings(&live_shape, draft_shape) {
+                let error = Error {
+                    catalog_name: catalog_name.to_string(),
+                    detail,
+                    ..Default::default()
+                };
+                if !extracted.contains(ptr.as_str()) {
+                    narrowings.push(error);
+                } else if forceable {
+                    warnings.push(error);
+                } else {
+                    errors.push(error);
+                }
+            }
         }
     }
 
+    for narrowing in narrowings {
+        tracing::warn!(
+            catalog_name = %narrowing.catalog_name,
+            detail = %narrowing.detail,
+            "publication narrows the write schema of an established collection",
+        );
+    }
+
     if errors.is_empty() {
-        Ok(())
+        Ok(warnings)
     } else {
         let ics = incompatible_collections
             .into_iter()
@@ -315,6 +448,564 @@ pub fn validate_transition(
     }
 }
 
+/// Verifies that each step of a drafted test references a collection of the
+/// draft or live catalog, and that each document ingested by a step is valid
+/// against its collection's write schema and has the collection's key.
+/// Errors are scoped to the location of the failing step or document.
+pub async fn validate_test_steps(
+    draft: &models::Catalog,
+    live: &models::Catalog,
+    txn: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+) -> anyhow::Result<Vec<Error>> {
+    let referenced: BTreeSet<String> = draft
+        .tests
+        .values()
+        .flatten()
+        .map(test_step_collection)
+        .filter(|name| {
+            !draft.collections.contains_key(*name) && !live.collections.contains_key(*name)
+        })
+        .map(|name| name.to_string())
+        .collect();
+
+    let live_collections = if referenced.is_empty() {
+        BTreeSet::new()
+    } else {
+        agent_sql::publications::find_live_collections(referenced.into_iter().collect(), txn)
+            .await
+            .context("finding live collections of test steps")?
+            .into_iter()
+            .collect()
+    };
+
+    Ok(test_step_errors(draft, live, &live_collections))
+}
+
+fn test_step_collection(step: &models::TestStep) -> &models::Collection {
+    match step {
+        models::TestStep::Ingest(ingest) => &ingest.collection,
+        models::TestStep::Verify(verify) => verify.collection.collection(),
+    }
+}
+
+fn test_step_errors(
+    draft: &models::Catalog,
+    live: &models::Catalog,
+    live_collections: &BTreeSet<String>,
+) -> Vec<Error> {
+    let mut errors = Vec::new();
+
+    for (catalog_name, steps) in &draft.tests {
+        for (step_index, step) in steps.iter().enumerate() {
+            let step_type = match step {
+                models::TestStep::Ingest(_) => "ingest",
+                models::TestStep::Verify(_) => "verify",
+            };
+            let location = format!("/{step_index}/{step_type}");
+            let collection = test_step_collection(step);
+
+            // The drafted collection takes precedence over its live specification.
+            let spec = draft
+                .collections
+                .get(collection)
+                .or_else(|| live.collections.get(collection));
+
+            if spec.is_none() && !live_collections.contains(collection.as_str()) {
+                errors.push(Error {
+                    catalog_name: catalog_name.to_string(),
+                    location: Some(format!("{location}/collection")),
+                    detail: format!(
+                        "Test step references collection '{collection}', which does not exist in the draft or live catalog"
+                    ),
+                    ..Default::default()
+                });
+                continue;
+            }
+
+            // Documents of live collections which aren't part of this publication
+            // are validated by the catalog build.
+            let (models::TestStep::Ingest(ingest), Some(spec)) = (step, spec) else {
+                continue;
+            };
+            let Some(mut validator) = write_schema_validator(spec) else {
+                continue;
+            };
+            // Fixtures which aren't an array of documents are reported by the catalog build.
+            let Ok(documents) =
+                serde_json::from_str::<Vec<serde_json::Value>>(ingest.documents.get())
+            else {
+                continue;
+            };
+
+            for (doc_index, doc) in documents.iter().enumerate() {
+                let detail = match validator.validate(None, doc).map(|v| v.ok()) {
+                    Ok(Err(failed)) => format!(
+                        "Ingested document is invalid against the write schema of collection '{collection}': {}",
+                        failed.basic_output,
+                    ),
+                    Ok(Ok(_)) => match spec
+                        .key
+                        .iter()
+                        .find(|ptr| doc::Pointer::from_str(ptr).query(doc).is_none())
+                    {
+                        Some(ptr) => format!(
+                            "Ingested document is missing collection '{collection}' key location '{ptr}'"
+                        ),
+                        None => continue,
+                    },
+                    Err(_) => continue,
+                };
+
+                errors.push(Error {
+                    catalog_name: catalog_name.to_string(),
+                    location: Some(format!("{location}/documents/{doc_index}")),
+                    detail,
+                    ..Default::default()
+                });
+            }
+        }
+    }
+    errors
+}
+
+/// Catalog name prefixes which are reserved for Flow's internal use by default.
+/// `ops/` holds the logs and stats collections of tasks, as well as other
+/// operational catalog specifications, and `recovery/` holds the recovery
+/// logs of task shards. A user specification under either prefix could
+/// collide with these system catalogs.
+pub const DEFAULT_RESERVED_PREFIXES: &[&str] = &["ops/", "recovery/"];
+
+/// Returns an Error for each drafted specification of the catalog which is
+/// named under one of the `reserved_prefixes`. Deletions aren't drafted, and
+/// are permitted.
+pub fn validate_reserved_prefixes(
+    reserved_prefixes: &[String],
+    draft: &models::Catalog,
+) -> Vec<Error> {
+    let names = draft
+        .captures
+        .keys()
+        .map(|n| n.as_str())
+        .chain(draft.collections.keys().map(|n| n.as_str()))
+        .chain(draft.materializations.keys().map(|n| n.as_str()))
+        .chain(draft.tests.keys().map(|n| n.as_str()));
+
+    names
+        .filter_map(|catalog_name| {
+            let prefix = reserved_prefixes
+                .iter()
+                .find(|prefix| catalog_name.starts_with(prefix.as_str()))?;
+
+            Some(Error {
+                catalog_name: catalog_name.to_string(),
+                detail: format!(
+                    "Catalog name '{catalog_name}' is under the prefix '{prefix}', which is reserved for internal use by Flow"
+                ),
+                ..Default::default()
+            })
+        })
+        .collect()
+}
+
+/// Maximum number of steps of a test specification, by default. Each step is
+/// stored within the test's published specification, and run serially by
+/// the publication's test data-plane, so very long tests bloat the catalog
+/// and slow publication.
+pub const DEFAULT_MAX_TEST_STEPS: usize = 1000;
+
+/// Quotas on the drafted specifications of a single publication.
+/// Both are in addition to the tenant's quotas of tasks and collections,
+/// and bound the work of a single draft rather than a tenant's total usage.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DraftQuotas {
+    /// Maximum number of drafted specifications under each tenant.
+    pub max_specs_per_tenant: usize,
+    /// Maximum size of a drafted specification, in serialized bytes.
+    pub max_spec_bytes: usize,
+}
+
+impl Default for DraftQuotas {
+    fn default() -> Self {
+        Self {
+            max_specs_per_tenant: 5000,
+            max_spec_bytes: 1 << 22, // 4MB.
+        }
+    }
+}
+
+/// Returns an Error for each tenant having more drafted specifications than
+/// permitted by `quotas`, and for each drafted specification which is larger
+/// than permitted. The tenant of a specification is the first component of its
+/// catalog name, including its trailing slash. Deletions aren't counted.
+pub fn validate_draft_quotas(quotas: &DraftQuotas, spec_rows: &[SpecRow]) -> Vec<Error> {
+    let mut errors = Vec::new();
+    let mut tenant_specs: BTreeMap<&str, usize> = BTreeMap::new();
+
+    for row in spec_rows {
+        let Some(draft_spec) = &row.draft_spec else {
+            continue;
+        };
+        let tenant = match row.catalog_name.split_once('/') {
+            Some((tenant, _)) => &row.catalog_name[..tenant.len() + 1],
+            None => row.catalog_name.as_str(),
+        };
+        *tenant_specs.entry(tenant).or_default() += 1;
+
+        let spec_bytes = draft_spec.0.get().len();
+        if spec_bytes > quotas.max_spec_bytes {
+            errors.push(Error {
+                catalog_name: row.catalog_name.clone(),
+                detail: format!(
+                    "Specification '{}' is {spec_bytes} bytes, which exceeds the maximum of {} bytes",
+                    row.catalog_name, quotas.max_spec_bytes,
+                ),
+                ..Default::default()
+            });
+        }
+    }
+
+    for (tenant, count) in tenant_specs {
+        if count > quotas.max_specs_per_tenant {
+            errors.push(Error {
+                catalog_name: tenant.to_string(),
+                detail: format!(
+                    "Draft has {count} specifications under tenant '{tenant}', which exceeds the maximum of {} per publication. Publish them in smaller drafts",
+                    quotas.max_specs_per_tenant,
+                ),
+                ..Default::default()
+            });
+        }
+    }
+    errors
+}
+
+/// Returns an Error for each drafted test having more than `max_test_steps` steps.
+pub fn validate_max_test_steps(max_test_steps: usize, draft: &models::Catalog) -> Vec<Error> {
+    draft
+        .tests
+        .iter()
+        .filter(|(_, steps)| steps.len() > max_test_steps)
+        .map(|(catalog_name, steps)| Error {
+            catalog_name: catalog_name.to_string(),
+            detail: format!(
+                "Test '{catalog_name}' has {} steps, which exceeds the maximum of {max_test_steps} steps. Split it into multiple smaller tests",
+                steps.len()
+            ),
+            ..Default::default()
+        })
+        .collect()
+}
+
+/// Returns an Error for each drafted specification having a malformed
+/// connector image reference.
+pub fn validate_connector_images(draft: &models::Catalog) -> Vec<Error> {
+    connector_images(draft)
+        .filter_map(|(catalog_name, image)| {
+            let detail = split_tag(image).err()?;
+
+            Some(Error {
+                catalog_name: catalog_name.to_string(),
+                detail,
+                ..Default::default()
+            })
+        })
+        .collect()
+}
+
+/// Policy of the connector images which drafted captures, derivations,
+/// and materializations may use.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ConnectorImagePolicy {
+    /// Prefixes of allowed image names, such as a registry `ghcr.io/estuary/`
+    /// or a specific image. If empty, images of any name are allowed.
+    pub allowed_prefixes: Vec<String>,
+    /// Prefixes of image names which are denied, even if they're also allowed.
+    pub denied_prefixes: Vec<String>,
+    /// Image tags which are denied, such as `:dev`.
+    pub denied_tags: Vec<String>,
+}
+
+/// Returns an Error for each drafted specification having a connector image
+/// which isn't permitted by `policy`. Malformed images are instead reported
+/// by `validate_connector_images`.
+pub fn validate_connector_image_policy(
+    policy: &ConnectorImagePolicy,
+    draft: &models::Catalog,
+) -> Vec<Error> {
+    connector_images(draft)
+        .filter_map(|(catalog_name, image)| {
+            let (image_name, tag) = split_tag(image).ok()?;
+
+            let detail = if !policy.allowed_prefixes.is_empty()
+                && !policy
+                    .allowed_prefixes
+                    .iter()
+                    .any(|prefix| image_name.starts_with(prefix.as_str()))
+            {
+                format!(
+                    "Connector image '{image}' is not an allowed image. Images must be under one of: {}",
+                    policy.allowed_prefixes.join(", ")
+                )
+            } else if let Some(prefix) = policy
+                .denied_prefixes
+                .iter()
+                .find(|prefix| image_name.starts_with(prefix.as_str()))
+            {
+                format!("Connector image '{image}' is under '{prefix}', which is not allowed")
+            } else if policy.denied_tags.contains(&tag) {
+                format!("Connector image '{image}' has the tag '{tag}', which is not allowed")
+            } else {
+                return None;
+            };
+
+            Some(Error {
+                catalog_name: catalog_name.to_string(),
+                detail,
+                ..Default::default()
+            })
+        })
+        .collect()
+}
+
+// Iterate over the (catalog name, connector image) of each drafted capture,
+// derivation, and materialization which uses a connector.
+fn connector_images(draft: &models::Catalog) -> impl Iterator<Item = (&str, &str)> {
+    let captures = draft.captures.iter().filter_map(|(name, capture)| {
+        let models::CaptureEndpoint::Connector(config) = &capture.endpoint else {
+            return None;
+        };
+        Some((name.as_str(), config.image.as_str()))
+    });
+    let derivations = draft.collections.iter().filter_map(|(name, collection)| {
+        let models::DeriveUsing::Connector(config) = &collection.derive.as_ref()?.using else {
+            return None;
+        };
+        Some((name.as_str(), config.image.as_str()))
+    });
+    let materializations = draft
+        .materializations
+        .iter()
+        .filter_map(|(name, materialization)| {
+            let models::MaterializationEndpoint::Connector(config) = &materialization.endpoint
+            else {
+                return None;
+            };
+            Some((name.as_str(), config.image.as_str()))
+        });
+
+    captures.chain(derivations).chain(materializations)
+}
+
+/// Image of the SQLite materialization connector, which materializes into a
+/// database file that's local to the task shard. It's useful in development,
+/// but isn't a durable endpoint and may not be used in a hosted data-plane.
+pub const SQLITE_MATERIALIZATION_IMAGE: &str = "ghcr.io/estuary/materialize-sqlite";
+
+/// Returns an Error for each drafted materialization which uses the SQLite
+/// materialization connector.
+pub fn validate_sqlite_materializations(draft: &models::Catalog) -> Vec<Error> {
+    draft
+        .materializations
+        .iter()
+        .filter_map(|(catalog_name, materialization)| {
+            let models::MaterializationEndpoint::Connector(config) = &materialization.endpoint
+            else {
+                return None;
+            };
+            let Ok((image_name, _)) = split_tag(&config.image) else {
+                return None; // Reported by `validate_connector_images`.
+            };
+
+            (image_name == SQLITE_MATERIALIZATION_IMAGE).then(|| Error {
+                catalog_name: catalog_name.to_string(),
+                detail: format!(
+                    "Materialization uses the SQLite connector '{}', which is supported only for local development. Use a connector for a hosted database or warehouse instead",
+                    config.image
+                ),
+                ..Default::default()
+            })
+        })
+        .collect()
+}
+
+/// Returns an Error for each key pointer of a drafted collection which the
+/// collection's schema permits to be undefined, and which has no default,
+/// as such a collection admits documents having no valid key. A collection
+/// having a read schema is checked against it, as the build does, and
+/// otherwise against its write schema. Key pointers which the schema doesn't
+/// explicitly declare, or which cannot exist, are instead reported by the
+/// catalog build.
+pub fn validate_required_keys(
+    draft: &models::Catalog,
+    draft_shapes: &BTreeMap<&models::Collection, doc::Shape>,
+) -> Vec<Error> {
+    let mut errors = Vec::new();
+
+    for (catalog_name, collection) in &draft.collections {
+        let read_shape;
+        let shape = match &collection.read_schema {
+            Some(read_schema) => {
+                let write_schema = collection.write_schema.as_ref().unwrap_or(read_schema);
+                let bundle = models::Schema::extend_read_bundle(read_schema, write_schema, None);
+
+                let Some(shape) = schema_shape(&bundle) else {
+                    continue;
+                };
+                read_shape = shape;
+                &read_shape
+            }
+            None => match draft_shapes.get(catalog_name) {
+                Some(shape) => shape,
+                None => continue,
+            },
+        };
+
+        for ptr in collection.key.iter() {
+            let (key_shape, exists) = shape.locate(&doc::Pointer::from_str(ptr));
+
+            // An undefined location having a default is extracted as its default.
+            if exists == doc::shape::location::Exists::May && key_shape.default.is_none() {
+                errors.push(Error {
+                    catalog_name: catalog_name.to_string(),
+                    detail: format!(
+                        "Collection key pointer '{ptr}' is not required to exist by the collection schema. Add it to the `required` properties of the schema, so that every document has a valid key"
+                    ),
+                    ..Default::default()
+                });
+            }
+        }
+    }
+    errors
+}
+
+/// Returns an Error for each schema of a drafted collection which fails to
+/// compile, such as because of an invalid `pattern` or a `$ref` which doesn't
+/// resolve. Read schemas are compiled with their references to the collection's
+/// write and inferred schemas resolved, as they are when the collection is built.
+pub fn validate_collection_schemas(draft: &models::Catalog) -> Vec<Error> {
+    let mut errors = Vec::new();
+
+    for (catalog_name, collection) in &draft.collections {
+        let mut schemas = Vec::new();
+
+        if let Some(schema) = &collection.schema {
+            schemas.push(("/schema", schema.clone()));
+        }
+        if let Some(write_schema) = &collection.write_schema {
+            schemas.push(("/writeSchema", write_schema.clone()));
+        }
+        if let Some(read_schema) = &collection.read_schema {
+            let write_schema = collection.write_schema.as_ref().unwrap_or(read_schema);
+            schemas.push((
+                "/readSchema",
+                models::Schema::extend_read_bundle(read_schema, write_schema, None),
+            ));
+        }
+
+        for (location, schema) in schemas {
+            if let Err(detail) = compile_schema(schema.get()) {
+                errors.push(Error {
+                    catalog_name: catalog_name.to_string(),
+                    location: Some(location.to_string()),
+                    detail: format!("Collection schema failed to compile: {detail}"),
+                    ..Default::default()
+                });
+            }
+        }
+    }
+    errors
+}
+
+// Build and index a bundled schema, verifying that its references resolve.
+fn compile_schema(bundle: &str) -> Result<(), String> {
+    let schema = doc::validation::build_bundle(bundle).map_err(|err| err.to_string())?;
+
+    let mut builder = doc::SchemaIndexBuilder::new();
+    builder.add(&schema).map_err(|err| err.to_string())?;
+    builder.verify_references().map_err(|err| err.to_string())
+}
+
+// Build a Validator of the collection's write schema, or None if the schema
+// cannot be built. Build errors are reported by `validate_collection_schemas`.
+fn write_schema_validator(collection: &models::CollectionDef) -> Option<doc::Validator> {
+    let schema = collection
+        .write_schema
+        .as_ref()
+        .or(collection.schema.as_ref())?;
+    let schema = doc::validation::build_bundle(schema.get()).ok()?;
+
+    doc::Validator::new(schema).ok()
+}
+
+/// Infers the Shape of the write schema of each collection of the catalog,
+/// so that validators which inspect drafted collections share one inference.
+/// Collections whose schema cannot be built are omitted, as build errors
+/// are reported by `validate_collection_schemas`.
+pub fn write_schema_shapes(catalog: &models::Catalog) -> BTreeMap<&models::Collection, doc::Shape> {
+    catalog
+        .collections
+        .iter()
+        .filter_map(|(name, collection)| Some((name, write_schema_shape(collection)?)))
+        .collect()
+}
+
+// Infer the Shape of the collection's write schema, or None if the schema
+// cannot be built. Build errors are reported by `validate_collection_schemas`.
+fn write_schema_shape(collection: &models::CollectionDef) -> Option<doc::Shape> {
+    schema_shape(
+        collection
+            .write_schema
+            .as_ref()
+            .or(collection.schema.as_ref())?,
+    )
+}
+
+// Infer the Shape of a bundled schema, or None if it cannot be built.
+fn schema_shape(schema: &models::Schema) -> Option<doc::Shape> {
+    let schema = doc::validation::build_bundle(schema.get()).ok()?;
+
+    let mut builder = doc::SchemaIndexBuilder::new();
+    builder.add(&schema).ok()?;
+    let index = builder.into_index();
+
+    Some(doc::Shape::infer(&schema, &index))
+}
+
+// Compare the write schema Shapes of a live and drafted collection, and describe
+// each location which the draft narrows: either because it no longer permits a
+// type of the live location, or because it no longer requires a live location
+// which was required. Established readers of the collection, such as
+// materializations, may depend on either property. Returns each narrowed
+// location with its description.
+fn schema_narrowings(live: &doc::Shape, draft: &doc::Shape) -> Vec<(String, String)> {
+    use doc::shape::location::Exists;
+    use json::schema::types;
+
+    let mut out = Vec::new();
+
+    for (ptr, is_pattern, live_shape, live_exists) in live.locations() {
+        if is_pattern {
+            continue;
+        }
+        let (draft_shape, draft_exists) = draft.locate(&ptr);
+
+        if live_shape.type_ - draft_shape.type_ != types::INVALID {
+            let detail = format!(
+                "Cannot narrow the types of location '{ptr}' from {} to {}, as this may break established readers of the collection",
+                live_shape.type_.to_json_array(),
+                draft_shape.type_.to_json_array(),
+            );
+            out.push((ptr.to_string(), detail));
+        } else if live_exists == Exists::Must && draft_exists != Exists::Must {
+            let detail = format!(
+                "Cannot remove the requirement that location '{ptr}' exists, as this may break established readers of the collection",
+            );
+            out.push((ptr.to_string(), detail));
+        }
+    }
+    out
+}
+
 /// Note that `spec_rows` may contain `live_spec_id`s that have already been deleted
 /// due to being unbound collections, which have been pruned.
 pub async fn enforce_resource_quotas(
@@ -381,12 +1072,167 @@ pub async fn enforce_resource_quotas(
     Ok(errors)
 }
 
-pub async fn apply_updates_for_row(
-    catalog: &models::Catalog,
-    detail: Option<&String>,
-    pub_id: Id,
-    spec_row: &SpecRow,
-    user_id: Uuid,
+// Collections renamed by a publication, as prior name => new name.
+fn renamed_collections(spec_rows: &[SpecRow]) -> BTreeMap<&str, &str> {
+    spec_rows
+        .iter()
+        .filter_map(|r| {
+            r.rename_from
+                .as_ref()
+                .map(|from| (from.as_str(), r.catalog_name.as_str()))
+        })
+        .collect()
+}
+
+fn renamed_reference_error(catalog_name: &str, reference: &str, renamed_to: &str) -> Error {
+    Error {
+        catalog_name: catalog_name.to_string(),
+        detail: format!(
+            "Specification references collection '{reference}', which is renamed to '{renamed_to}' by this publication. Update it to reference '{renamed_to}' instead"
+        ),
+        ..Default::default()
+    }
+}
+
+/// Verifies that live specifications which aren't part of this publication
+/// don't continue to read from or write to a collection which it renames.
+/// References of drafted specifications are verified by `validate_transition`.
+pub async fn validate_renamed_references(
+    spec_rows: &[SpecRow],
+    txn: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+) -> anyhow::Result<Vec<Error>> {
+    let renames = renamed_collections(spec_rows);
+    if renames.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let references = agent_sql::publications::find_live_references(
+        renames.keys().map(|name| name.to_string()).collect(),
+        txn,
+    )
+    .await
+    .context("finding live references of renamed collections")?;
+
+    Ok(references
+        .into_iter()
+        .filter(|r| !spec_rows.iter().any(|s| s.catalog_name == r.catalog_name))
+        .map(|r| {
+            renamed_reference_error(&r.catalog_name, &r.reference, renames[r.reference.as_str()])
+        })
+        .collect())
+}
+
+/// Verifies that every collection which a drafted specification reads from
+/// or writes to is either drafted, or is a live collection which isn't
+/// deleted by this publication. A reference which differs only in case from
+/// a drafted or live collection is likely a typo, and its error suggests
+/// the collection's actual name.
+pub async fn validate_references(
+    draft: &models::Catalog,
+    spec_rows: &[SpecRow],
+    txn: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+) -> anyhow::Result<Vec<Error>> {
+    let mut referenced = BTreeSet::new();
+    for spec_row in spec_rows {
+        let (reads_from, writes_to, _) = extract_spec_metadata(draft, spec_row);
+        for reference in reads_from.into_iter().chain(writes_to).flatten() {
+            referenced.insert(reference.to_string());
+        }
+    }
+    if referenced.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let live_collections: BTreeSet<String> =
+        agent_sql::publications::find_live_collections(referenced.iter().cloned().collect(), txn)
+            .await
+            .context("finding live collections of references")?
+            .into_iter()
+            .collect();
+
+    // Look for live collections whose names differ only by case
+    // from references which don't otherwise exist.
+    let missing: Vec<String> = referenced
+        .into_iter()
+        .filter(|r| {
+            !live_collections.contains(r)
+                && !draft.collections.contains_key(&models::Collection::new(r))
+        })
+        .collect();
+
+    let case_mismatches = if missing.is_empty() {
+        BTreeSet::new()
+    } else {
+        agent_sql::publications::find_live_collections_ignoring_case(missing, txn)
+            .await
+            .context("finding live collections of missing references")?
+            .into_iter()
+            .collect()
+    };
+
+    Ok(unresolved_references(
+        draft,
+        spec_rows,
+        &live_collections,
+        &case_mismatches,
+    ))
+}
+
+fn unresolved_references(
+    draft: &models::Catalog,
+    spec_rows: &[SpecRow],
+    live_collections: &BTreeSet<String>,
+    case_mismatches: &BTreeSet<String>,
+) -> Vec<Error> {
+    let deleted: BTreeSet<&str> = spec_rows
+        .iter()
+        .filter(|r| r.draft_type.is_none())
+        .map(|r| r.catalog_name.as_str())
+        .collect();
+
+    let mut errors = Vec::new();
+
+    for spec_row in spec_rows {
+        let (reads_from, writes_to, _) = extract_spec_metadata(draft, spec_row);
+
+        for reference in reads_from.into_iter().chain(writes_to).flatten() {
+            let detail = if deleted.contains(reference) {
+                format!("Specification references collection '{reference}', which is deleted by this publication")
+            } else if draft
+                .collections
+                .contains_key(&models::Collection::new(reference))
+                || live_collections.contains(reference)
+            {
+                continue;
+            } else if let Some(suggestion) = draft
+                .collections
+                .keys()
+                .map(|name| name.as_str())
+                .chain(case_mismatches.iter().map(String::as_str))
+                .filter(|name| !deleted.contains(name))
+                .find(|name| name.to_lowercase() == reference.to_lowercase())
+            {
+                format!("Specification references collection '{reference}', which does not exist. Catalog names are case-sensitive: did you mean '{suggestion}'?")
+            } else {
+                format!("Specification references collection '{reference}', which does not exist in the draft or live catalog")
+            };
+
+            errors.push(Error {
+                catalog_name: spec_row.catalog_name.clone(),
+                detail,
+                ..Default::default()
+            });
+        }
+    }
+    errors
+}
+
+pub async fn apply_updates_for_row(
+    catalog: &models::Catalog,
+    detail: Option<&String>,
+    pub_id: Id,
+    spec_row: &SpecRow,
+    user_id: Uuid,
     txn: &mut sqlx::Transaction<'_, sqlx::Postgres>,
 ) -> anyhow::Result<()> {
     let SpecRow {
@@ -398,32 +1244,56 @@ pub async fn apply_updates_for_row(
         last_build_id: _,
         last_pub_id: _,
         live_spec: _,
+        live_spec_hash: _,
         live_spec_id,
         live_type,
+        rename_from,
         spec_capabilities: _,
         user_capability,
     } = spec_row;
 
     assert!(matches!(user_capability, Some(Capability::Admin)));
 
+    // Record a rename within the publication detail of the renamed collection,
+    // and carry forward the inferred schema of its prior name.
+    let detail = match (detail, rename_from) {
+        (Some(detail), Some(from)) => Some(format!("{detail} (renamed from '{from}')")),
+        (None, Some(from)) => Some(format!("renamed from '{from}'")),
+        (detail, None) => detail.cloned(),
+    };
+    if let Some(from) = rename_from {
+        agent_sql::publications::copy_inferred_schema(from, catalog_name, txn)
+            .await
+            .context("copy inferred schema of renamed collection")?;
+    }
+
     agent_sql::drafts::delete_spec(*draft_spec_id, txn)
         .await
         .context("delete from draft_specs")?;
 
-    // Clear out data-flow edges that we'll replace.
-    match live_type {
-        Some(live_type) => {
-            agent_sql::publications::delete_stale_flow(*live_spec_id, *live_type, txn)
-                .await
-                .with_context(|| format!("delete stale {live_type:?} edges"))?;
-        }
-        None => {} // No-op.
+    let spec_hash = draft_spec
+        .as_ref()
+        .map(|spec| spec_hash(&spec.0))
+        .transpose()
+        .with_context(|| format!("hashing specification of '{catalog_name}'"))?;
+
+    // If the drafted specification is unchanged from the live specification,
+    // then the publication is a no-op for it and we leave it unchanged.
+    // Its build is still tracked as the last build of the specification,
+    // but the publication isn't added to its history: `publication_specs`
+    // records only the publications which changed a specification, as does
+    // its `last_pub_id`, and rollbacks rely on the two agreeing.
+    if is_unchanged(spec_hash.as_deref(), spec_row) {
+        agent_sql::publications::update_expanded_live_specs(&[*live_spec_id], pub_id, txn)
+            .await
+            .context("update last_build_id of unchanged live_specs")?;
+        return Ok(());
     }
 
     agent_sql::publications::insert_publication_spec(
         *live_spec_id,
         pub_id,
-        detail,
+        detail.as_ref(),
         draft_spec,
         draft_type,
         user_id,
@@ -432,12 +1302,27 @@ pub async fn apply_updates_for_row(
     .await
     .context("insert into publication_specs")?;
 
+    // Clear out data-flow edges that we'll replace.
+    match live_type {
+        Some(live_type) => {
+            agent_sql::publications::delete_stale_flow(*live_spec_id, *live_type, txn)
+                .await
+                .with_context(|| format!("delete stale {live_type:?} edges"))?;
+        }
+        None => {} // No-op.
+    }
+
     // Draft is an update of a live spec. The semantic insertion and deletion
     // cases are also an update: we previously created a `live_specs` rows for
     // the draft `catalog_name` in order to lock it. If the draft is a deletion,
     // that's marked as a DB NULL of `spec` and `spec_type`.
 
-    let (reads_from, writes_to, image_parts) = extract_spec_metadata(catalog, spec_row);
+    let (reads_from, writes_to, image) = extract_spec_metadata(catalog, spec_row);
+    let image_parts = image
+        .map(split_tag)
+        .transpose()
+        .map_err(anyhow::Error::msg)
+        .context("splitting connector image tag")?;
 
     agent_sql::publications::update_published_live_spec(
         catalog_name,
@@ -448,6 +1333,7 @@ pub async fn apply_updates_for_row(
         *live_spec_id,
         pub_id,
         &reads_from,
+        spec_hash.as_deref(),
         &writes_to,
         txn,
     )
@@ -473,6 +1359,134 @@ pub async fn apply_updates_for_row(
     Ok(())
 }
 
+/// SpecChange is the effect of a publication upon one of its specifications.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum SpecChange {
+    /// The specification didn't previously exist, and was created.
+    Added,
+    /// The live specification was updated with differing content.
+    Modified,
+    /// The live specification was deleted.
+    Deleted,
+    /// The drafted specification is identical to the live specification.
+    Unchanged,
+}
+
+/// PublicationSummary is an aggregate view of the changes applied by a publication.
+#[derive(Debug, Default, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PublicationSummary {
+    /// Message of the publication, such as the reason for its changes.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub detail: Option<String>,
+    pub added: usize,
+    pub modified: usize,
+    pub deleted: usize,
+    pub unchanged: usize,
+    /// Change of each published specification, keyed on its catalog name.
+    pub specs: BTreeMap<String, SpecChange>,
+}
+
+impl PublicationSummary {
+    fn insert(&mut self, catalog_name: &str, change: SpecChange) {
+        match change {
+            SpecChange::Added => self.added += 1,
+            SpecChange::Modified => self.modified += 1,
+            SpecChange::Deleted => self.deleted += 1,
+            SpecChange::Unchanged => self.unchanged += 1,
+        }
+        self.specs.insert(catalog_name.to_string(), change);
+    }
+}
+
+/// Summarize the changes of `spec_rows`, which are being applied by a publication
+/// having message `detail`. Collections which were pruned from the publication
+/// are not included.
+pub fn summarize_publication(
+    detail: Option<&String>,
+    spec_rows: &[SpecRow],
+    pruned_collections: &HashSet<String>,
+) -> PublicationSummary {
+    let mut summary = PublicationSummary {
+        detail: detail.cloned(),
+        ..Default::default()
+    };
+
+    for row in spec_rows {
+        if pruned_collections.contains(&row.catalog_name) {
+            continue;
+        }
+        let change = match (&row.live_spec, &row.draft_spec) {
+            (_, None) => SpecChange::Deleted,
+            (None, Some(_)) => SpecChange::Added,
+            (Some(_), Some(draft_spec)) => {
+                // A specification which fails to hash is applied as a change.
+                let spec_hash = spec_hash(&draft_spec.0).ok();
+
+                if is_unchanged(spec_hash.as_deref(), row) {
+                    SpecChange::Unchanged
+                } else {
+                    SpecChange::Modified
+                }
+            }
+        };
+        summary.insert(&row.catalog_name, change);
+    }
+    summary
+}
+
+/// Build a machine-readable report of a publication's outcome, having
+/// each of its `errors` and forced `warnings` with their resolved scope,
+/// and its `summary`.
+pub fn publication_report(
+    errors: &[Error],
+    warnings: &[Error],
+    summary: &PublicationSummary,
+) -> serde_json::Value {
+    let to_json = |errors: &[Error]| -> Vec<serde_json::Value> {
+        errors
+            .iter()
+            .map(|err| {
+                serde_json::json!({
+                    "catalogName": err.catalog_name,
+                    "scope": err.resolved_scope(),
+                    "detail": err.detail,
+                })
+            })
+            .collect()
+    };
+
+    serde_json::json!({
+        "errors": to_json(errors),
+        "summary": summary,
+        "warnings": to_json(warnings),
+    })
+}
+
+/// Returns true if the drafted specification of `row`, having hash `spec_hash`,
+/// is unchanged from its live specification. Publishing an unchanged
+/// specification doesn't update its live specification.
+fn is_unchanged(spec_hash: Option<&str>, row: &SpecRow) -> bool {
+    spec_hash.is_some()
+        && spec_hash == row.live_spec_hash.as_deref()
+        && row.live_type == row.draft_type
+}
+
+/// Returns the hex-encoded SHA-256 hash of the canonical JSON of `spec`,
+/// as produced by `json::schema::canonical::canonicalize_schema`. Specifications
+/// which differ only in formatting, property order, or the representation of
+/// integral numbers have equal hashes.
+pub fn spec_hash(spec: &serde_json::value::RawValue) -> anyhow::Result<String> {
+    use sha2::Digest;
+
+    let spec: serde_json::Value =
+        serde_json::from_str(spec.get()).context("parsing specification to hash")?;
+    let canonical = json::schema::canonical::canonicalize_schema(&spec);
+
+    Ok(format!("{:x}", sha2::Sha256::digest(canonical.as_bytes())))
+}
+
 /// adds the built spec to the live_specs row for all tasks included
 /// in build_output if they are in the list of specifications which are
 /// changing in this publication per the list of spec_rows. Also sets the
@@ -620,15 +1634,11 @@ pub async fn add_built_specs_to_draft_specs(
 /// Returns a tuple containing:
 /// - catalog names that this spec reads from
 /// - catalog names that this spec writes to
-/// - connector image parts, if applicable
+/// - connector image, if applicable
 fn extract_spec_metadata<'a>(
     catalog: &'a models::Catalog,
     spec_row: &'a SpecRow,
-) -> (
-    Option<Vec<&'a str>>,
-    Option<Vec<&'a str>>,
-    Option<(String, String)>,
-) {
+) -> (Option<Vec<&'a str>>, Option<Vec<&'a str>>, Option<&'a str>) {
     let SpecRow {
         user_capability: _,
         spec_capabilities: _,
@@ -640,13 +1650,15 @@ fn extract_spec_metadata<'a>(
         last_build_id: _,
         last_pub_id: _,
         live_spec: _,
+        live_spec_hash: _,
         live_spec_id: _,
         live_type: _,
+        rename_from: _,
     } = spec_row;
 
     let mut reads_from = Vec::new();
     let mut writes_to = Vec::new();
-    let mut image_parts = None;
+    let mut image = None;
 
     match *draft_type {
         Some(CatalogType::Capture) => {
@@ -654,7 +1666,7 @@ fn extract_spec_metadata<'a>(
             let capture = catalog.captures.get(&key).unwrap();
 
             if let models::CaptureEndpoint::Connector(config) = &capture.endpoint {
-                image_parts = Some(split_tag(&config.image));
+                image = Some(config.image.as_str());
             }
             for binding in &capture.bindings {
                 if !binding.disable {
@@ -669,7 +1681,7 @@ fn extract_spec_metadata<'a>(
 
             if let Some(derive) = &collection.derive {
                 if let models::DeriveUsing::Connector(config) = &derive.using {
-                    image_parts = Some(split_tag(&config.image));
+                    image = Some(config.image.as_str());
                 }
                 for transform in &derive.transforms {
                     if !transform.disable {
@@ -684,7 +1696,7 @@ fn extract_spec_metadata<'a>(
             let materialization = catalog.materializations.get(&key).unwrap();
 
             if let models::MaterializationEndpoint::Connector(config) = &materialization.endpoint {
-                image_parts = Some(split_tag(&config.image));
+                image = Some(config.image.as_str());
             }
             for binding in &materialization.bindings {
                 if !binding.disable {
@@ -727,18 +1739,39 @@ fn extract_spec_metadata<'a>(
         } else {
             None
         },
-        image_parts,
+        image,
     )
 }
 
-fn split_tag(image_full: &str) -> (String, String) {
-    let mut image = image_full.to_string();
+// Split a connector image into its name and tag, where the tag is either a
+// `:tag` or an `@sha256:digest` suffix. A `:` which isn't within the final
+// path segment of the image separates a registry host from its port, and
+// isn't a tag.
+fn split_tag(image_full: &str) -> Result<(String, String), String> {
+    if let Some(pivot) = image_full.find("@sha256:") {
+        let digest = &image_full[pivot + "@sha256:".len()..];
 
-    if let Some(pivot) = image.find("@sha256:").or_else(|| image.find(":")) {
-        let tag = image.split_off(pivot);
-        (image, tag)
-    } else {
-        (image, String::new())
+        if digest.len() != 64
+            || !digest
+                .bytes()
+                .all(|b| matches!(b, b'0'..=b'9' | b'a'..=b'f'))
+        {
+            return Err(format!(
+                "Connector image '{image_full}' has a malformed digest: a sha256 digest must be 64 lowercase hexadecimal characters"
+            ));
+        }
+        let (image, tag) = image_full.split_at(pivot);
+        return Ok((image.to_string(), tag.to_string()));
+    }
+
+    let name_begin = image_full.rfind('/').map(|i| i + 1).unwrap_or_default();
+
+    match image_full[name_begin..].find(':') {
+        Some(pivot) => {
+            let (image, tag) = image_full.split_at(name_begin + pivot);
+            Ok((image.to_string(), tag.to_string()))
+        }
+        None => Ok((image_full.to_string(), String::new())),
     }
 }
 
@@ -802,7 +1835,7 @@ mod test {
             .unwrap()
         {
             let row_draft_id = row.draft_id.clone();
-            let (pub_id, status) = handler.process(row, &mut *txn, true).await.unwrap();
+            let (pub_id, status, _summary) = handler.process(row, &mut *txn, true).await.unwrap();
 
             agent_sql::publications::resolve(pub_id, &status, &mut *txn)
                 .await
@@ -866,6 +1899,27 @@ mod test {
         results
     }
 
+    // Returns a SpecRow fixture of `catalog_name` having no live or drafted
+    // specification, which tests customize using struct update syntax.
+    fn spec_row(catalog_name: &str) -> agent_sql::publications::SpecRow {
+        agent_sql::publications::SpecRow {
+            catalog_name: catalog_name.to_string(),
+            draft_spec: None,
+            draft_spec_id: Id::new([1; 8]),
+            draft_type: None,
+            expect_pub_id: None,
+            last_build_id: Id::new([1; 8]),
+            last_pub_id: Id::new([1; 8]),
+            live_spec: None,
+            live_spec_hash: None,
+            live_spec_id: Id::new([1; 8]),
+            live_type: None,
+            rename_from: None,
+            spec_capabilities: sqlx::types::Json(Vec::new()),
+            user_capability: Some(agent_sql::Capability::Admin),
+        }
+    }
+
     #[tokio::test]
     #[serial_test::parallel]
     async fn test_happy_path() {
@@ -1110,10 +2164,14 @@ mod test {
             p3 as (
               insert into draft_specs (id, draft_id, catalog_name, spec, spec_type) values
               ('1111000000000000', '1110000000000000', 'usageB/CaptureC', '{
-                  "bindings": [{"target": "usageB/CaptureC", "resource": {"binding": "foo", "syncMode": "incremental"}}],
+                  "bindings": [{"target": "usageB/CollectionA", "resource": {"binding": "foo", "syncMode": "incremental"}}],
                   "endpoint": {"connector": {"image": "forbidden_connector", "config": {}}}
               }'::json, 'capture')
             ),
+            p3a as (
+              insert into live_specs (id, catalog_name, spec, spec_type, last_build_id, last_pub_id) values
+              ('1000000000000000', 'usageB/CollectionA', '{"schema": {},"key": ["/foo"]}'::json, 'collection', 'bbbbbbbbbbbbbbbb', 'bbbbbbbbbbbbbbbb')
+            ),
             p4 as (
               insert into publications (id, job_status, user_id, draft_id) values
               ('1111100000000000', '{"type": "queued"}'::json, '43a18a3e-5a59-11ed-9b6a-0242ac120002', '1110000000000000')
@@ -1148,6 +2206,68 @@ mod test {
         "###);
     }
 
+    #[tokio::test]
+    #[serial_test::parallel]
+    async fn test_sqlite_materialization() {
+        let mut conn = sqlx::postgres::PgConnection::connect(&FIXED_DATABASE_URL)
+            .await
+            .unwrap();
+        let mut txn = conn.begin().await.unwrap();
+
+        sqlx::query(r#"
+            with p1 as (
+              insert into auth.users (id) values
+              ('43a18a3e-5a59-11ed-9b6a-0242ac120002')
+            ),
+            p2 as (
+              insert into drafts (id, user_id) values
+              ('1110000000000000', '43a18a3e-5a59-11ed-9b6a-0242ac120002')
+            ),
+            p3 as (
+              insert into draft_specs (id, draft_id, catalog_name, spec, spec_type) values
+              ('1111000000000000', '1110000000000000', 'usageB/MaterializationA', '{
+                  "bindings": [{"source": "usageB/CollectionA", "resource": {"table": "a"}}],
+                  "endpoint": {"connector": {"image": "ghcr.io/estuary/materialize-sqlite:dev", "config": {}}}
+              }'::json, 'materialization')
+            ),
+            p3a as (
+              insert into live_specs (id, catalog_name, spec, spec_type, last_build_id, last_pub_id) values
+              ('1000000000000000', 'usageB/CollectionA', '{"schema": {},"key": ["/foo"]}'::json, 'collection', 'bbbbbbbbbbbbbbbb', 'bbbbbbbbbbbbbbbb')
+            ),
+            p4 as (
+              insert into publications (id, job_status, user_id, draft_id) values
+              ('1111100000000000', '{"type": "queued"}'::json, '43a18a3e-5a59-11ed-9b6a-0242ac120002', '1110000000000000')
+            ),
+            p5 as (
+              insert into role_grants (subject_role, object_role, capability) values
+              ('usageB/', 'usageB/', 'admin')
+            ),
+            p6 as (
+              insert into user_grants (user_id, object_role, capability) values
+              ('43a18a3e-5a59-11ed-9b6a-0242ac120002', 'usageB/', 'admin')
+            )
+            select 1;
+        "#).execute(&mut txn).await.unwrap();
+
+        let results = execute_publications(&mut txn).await;
+
+        insta::assert_debug_snapshot!(results, @r###"
+        [
+            ScenarioResult {
+                draft_id: 1110000000000000,
+                status: BuildFailed {
+                    incompatible_collections: [],
+                    evolution_id: None,
+                },
+                errors: [
+                    "Materialization uses the SQLite connector 'ghcr.io/estuary/materialize-sqlite:dev', which is supported only for local development. Use a connector for a hosted database or warehouse instead",
+                ],
+                live_specs: [],
+            },
+        ]
+        "###);
+    }
+
     #[tokio::test]
     #[serial_test::parallel]
     async fn test_allowed_connector() {
@@ -1168,10 +2288,14 @@ mod test {
             p3 as (
               insert into draft_specs (id, draft_id, catalog_name, spec, spec_type) values
               ('1111000000000000', '1110000000000000', 'usageB/CaptureC', '{
-                  "bindings": [{"target": "usageB/CaptureC", "resource": {"binding": "foo", "syncMode": "incremental"}}],
+                  "bindings": [{"target": "usageB/CollectionA", "resource": {"binding": "foo", "syncMode": "incremental"}}],
                   "endpoint": {"connector": {"image": "allowed_connector", "config": {}}}
               }'::json, 'capture')
             ),
+            p3a as (
+              insert into live_specs (id, catalog_name, spec, spec_type, last_build_id, last_pub_id) values
+              ('1000000000000000', 'usageB/CollectionA', '{"schema": {},"key": ["/foo"]}'::json, 'collection', 'bbbbbbbbbbbbbbbb', 'bbbbbbbbbbbbbbbb')
+            ),
             p4 as (
               insert into publications (id, job_status, user_id, draft_id) values
               ('1111100000000000', '{"type": "queued"}'::json, '43a18a3e-5a59-11ed-9b6a-0242ac120002', '1110000000000000')
@@ -1213,7 +2337,7 @@ mod test {
                         reads_from: None,
                         writes_to: Some(
                             [
-                                "usageB/CaptureC",
+                                "usageB/CollectionA",
                             ],
                         ),
                         spec: Some(
@@ -1224,7 +2348,7 @@ mod test {
                                             "binding": String("foo"),
                                             "syncMode": String("incremental"),
                                         },
-                                        "target": String("usageB/CaptureC"),
+                                        "target": String("usageB/CollectionA"),
                                     },
                                 ],
                                 "endpoint": Object {
@@ -1277,7 +2401,7 @@ mod test {
               p5 as (
                 insert into draft_specs (id, draft_id, catalog_name, spec, spec_type) values
                 ('1111000000000000', '1110000000000000', 'usageB/CaptureC', '{
-                    "bindings": [{"target": "usageB/CaptureC", "resource": {"binding": "foo", "syncMode": "incremental"}}],
+                    "bindings": [{"target": "usageB/CollectionA", "resource": {"binding": "foo", "syncMode": "incremental"}}],
                     "endpoint": {"connector": {"image": "foo", "config": {}}}
                 }'::json, 'capture'),
                 -- This collection should be pruned, and thus _not_ count against the quota of 2 collections.
@@ -1584,4 +2708,1430 @@ mod test {
         ]
         "###);
     }
+
+    #[test]
+    fn test_max_test_steps() {
+        let step = serde_json::json!({
+            "ingest": {"collection": "acmeCo/collection", "documents": [{"id": 1}]},
+        });
+        let draft: models::Catalog = serde_json::from_value(serde_json::json!({
+            "tests": {
+                "acmeCo/at-limit": vec![step.clone(); 3],
+                "acmeCo/over-limit": vec![step.clone(); 4],
+            },
+        }))
+        .unwrap();
+
+        let errors = super::validate_max_test_steps(3, &draft)
+            .into_iter()
+            .map(|err| (err.catalog_name, err.detail))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            errors,
+            vec![(
+                "acmeCo/over-limit".to_string(),
+                "Test 'acmeCo/over-limit' has 4 steps, which exceeds the maximum of 3 steps. Split it into multiple smaller tests".to_string(),
+            )]
+        );
+
+        assert!(super::validate_max_test_steps(super::DEFAULT_MAX_TEST_STEPS, &draft).is_empty());
+    }
+
+    #[test]
+    fn test_draft_quotas() {
+        let spec_row =
+            |catalog_name: &str, draft_spec: Option<&str>| agent_sql::publications::SpecRow {
+                draft_spec: draft_spec.map(|spec| {
+                    sqlx::types::Json(
+                        serde_json::value::RawValue::from_string(spec.to_string()).unwrap(),
+                    )
+                }),
+                ..spec_row(catalog_name)
+            };
+        let collection = r#"{"schema":{"type":"object"},"key":["/id"]}"#; // 42 bytes.
+        let large = r#"{"schema":{"type":"object","title":"large"},"key":["/id"]}"#; // 58 bytes.
+
+        let spec_rows = vec![
+            spec_row("acmeCo/one", Some(collection)),
+            spec_row("acmeCo/nested/two", Some(collection)),
+            spec_row("acmeCo/three", Some(collection)),
+            // Deletions aren't counted.
+            spec_row("acmeCo/deleted", None),
+            spec_row("otherCo/one", Some(collection)),
+            spec_row("otherCo/large", Some(large)),
+        ];
+        let quotas = super::DraftQuotas {
+            max_specs_per_tenant: 2,
+            max_spec_bytes: 50,
+        };
+
+        let errors = super::validate_draft_quotas(&quotas, &spec_rows)
+            .into_iter()
+            .map(|err| (err.catalog_name, err.detail))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            errors,
+            vec![
+                (
+                    "otherCo/large".to_string(),
+                    "Specification 'otherCo/large' is 58 bytes, which exceeds the maximum of 50 bytes".to_string(),
+                ),
+                (
+                    "acmeCo/".to_string(),
+                    "Draft has 3 specifications under tenant 'acmeCo/', which exceeds the maximum of 2 per publication. Publish them in smaller drafts".to_string(),
+                ),
+            ]
+        );
+
+        assert!(super::validate_draft_quotas(&Default::default(), &spec_rows).is_empty());
+    }
+
+    #[test]
+    fn test_connector_image_policy() {
+        let draft: models::Catalog = serde_json::from_value(serde_json::json!({
+            "captures": {
+                "acmeCo/allowed": {
+                    "endpoint": {"connector": {"image": "ghcr.io/estuary/source-hello-world:v1", "config": {}}},
+                    "bindings": [],
+                },
+                "acmeCo/other-registry": {
+                    "endpoint": {"connector": {"image": "docker.io/someone/source-hello-world:v1", "config": {}}},
+                    "bindings": [],
+                },
+            },
+            "materializations": {
+                "acmeCo/dev-tag": {
+                    "endpoint": {"connector": {"image": "ghcr.io/estuary/materialize-postgres:dev", "config": {}}},
+                    "bindings": [],
+                },
+            },
+        }))
+        .unwrap();
+
+        let policy = super::ConnectorImagePolicy {
+            allowed_prefixes: vec!["ghcr.io/estuary/".to_string()],
+            denied_prefixes: Vec::new(),
+            denied_tags: vec![":dev".to_string()],
+        };
+
+        let errors = super::validate_connector_image_policy(&policy, &draft)
+            .into_iter()
+            .map(|err| (err.catalog_name, err.detail))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            errors,
+            vec![
+                (
+                    "acmeCo/other-registry".to_string(),
+                    "Connector image 'docker.io/someone/source-hello-world:v1' is not an allowed image. Images must be under one of: ghcr.io/estuary/".to_string(),
+                ),
+                (
+                    "acmeCo/dev-tag".to_string(),
+                    "Connector image 'ghcr.io/estuary/materialize-postgres:dev' has the tag ':dev', which is not allowed".to_string(),
+                ),
+            ]
+        );
+
+        // Denied prefixes take precedence over allowed ones.
+        let policy = super::ConnectorImagePolicy {
+            denied_prefixes: vec!["ghcr.io/estuary/source-".to_string()],
+            ..policy
+        };
+        let errors = super::validate_connector_image_policy(&policy, &draft);
+        assert_eq!(
+            errors[0].detail,
+            "Connector image 'ghcr.io/estuary/source-hello-world:v1' is under 'ghcr.io/estuary/source-', which is not allowed"
+        );
+
+        // The default policy permits all images.
+        assert!(super::validate_connector_image_policy(&Default::default(), &draft).is_empty());
+    }
+
+    #[test]
+    fn test_reserved_prefixes() {
+        let draft: models::Catalog = serde_json::from_value(serde_json::json!({
+            "collections": {
+                "acmeCo/collection": {"schema": {"type": "object"}, "key": ["/id"]},
+                "ops/collection": {"schema": {"type": "object"}, "key": ["/id"]},
+            },
+            "tests": {
+                "acmeCo/ops/test": [],
+                "recovery/test": [],
+            },
+        }))
+        .unwrap();
+
+        let reserved: Vec<String> = super::DEFAULT_RESERVED_PREFIXES
+            .iter()
+            .map(|prefix| prefix.to_string())
+            .collect();
+
+        let errors = super::validate_reserved_prefixes(&reserved, &draft)
+            .into_iter()
+            .map(|err| (err.catalog_name, err.detail))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            errors,
+            vec![
+                (
+                    "ops/collection".to_string(),
+                    "Catalog name 'ops/collection' is under the prefix 'ops/', which is reserved for internal use by Flow".to_string(),
+                ),
+                (
+                    "recovery/test".to_string(),
+                    "Catalog name 'recovery/test' is under the prefix 'recovery/', which is reserved for internal use by Flow".to_string(),
+                ),
+            ]
+        );
+
+        // Without reserved prefixes, all names are allowed.
+        assert!(super::validate_reserved_prefixes(&[], &draft).is_empty());
+    }
+
+    #[test]
+    fn test_required_keys() {
+        let draft: models::Catalog = serde_json::from_value(serde_json::json!({
+            "collections": {
+                "acmeCo/required": {
+                    "schema": {
+                        "type": "object",
+                        "properties": {"id": {"type": "string"}, "part": {"type": "integer"}},
+                        "required": ["id", "part"],
+                    },
+                    "key": ["/id", "/part"],
+                },
+                "acmeCo/optional": {
+                    "schema": {
+                        "type": "object",
+                        "properties": {"id": {"type": "string"}, "part": {"type": "integer"}},
+                        "required": ["id"],
+                    },
+                    "key": ["/id", "/part"],
+                },
+                "acmeCo/defaulted": {
+                    "schema": {
+                        "type": "object",
+                        "properties": {
+                            "id": {"type": "string"},
+                            "part": {"type": "integer", "default": 0},
+                        },
+                        "required": ["id"],
+                    },
+                    "key": ["/id", "/part"],
+                },
+                "acmeCo/read-required": {
+                    "writeSchema": {
+                        "type": "object",
+                        "properties": {"id": {"type": "string"}, "part": {"type": "integer"}},
+                        "required": ["id"],
+                    },
+                    "readSchema": {
+                        "allOf": [
+                            {"$ref": "flow://write-schema"},
+                            {"required": ["part"]},
+                        ],
+                    },
+                    "key": ["/id", "/part"],
+                },
+                "acmeCo/read-optional": {
+                    "writeSchema": {
+                        "type": "object",
+                        "properties": {"id": {"type": "string"}, "part": {"type": "integer"}},
+                        "required": ["id", "part"],
+                    },
+                    "readSchema": {
+                        "type": "object",
+                        "properties": {"id": {"type": "string"}, "part": {"type": "integer"}},
+                        "required": ["id"],
+                    },
+                    "key": ["/id", "/part"],
+                },
+            },
+        }))
+        .unwrap();
+
+        let errors = super::validate_required_keys(&draft, &super::write_schema_shapes(&draft))
+            .into_iter()
+            .map(|err| (err.catalog_name, err.detail))
+            .collect::<Vec<_>>();
+
+        // The defaulted key is permitted to be undefined, and the read schema
+        // of a collection takes precedence over its write schema.
+        let detail = "Collection key pointer '/part' is not required to exist by the collection schema. Add it to the `required` properties of the schema, so that every document has a valid key";
+        assert_eq!(
+            errors,
+            vec![
+                ("acmeCo/optional".to_string(), detail.to_string()),
+                ("acmeCo/read-optional".to_string(), detail.to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_collection_schemas() {
+        let draft: models::Catalog = serde_json::from_value(serde_json::json!({
+            "collections": {
+                "acmeCo/valid": {
+                    "writeSchema": {
+                        "type": "object",
+                        "properties": {"id": {"type": "string", "pattern": "^[a-z]+$"}},
+                        "required": ["id"],
+                    },
+                    "readSchema": {
+                        "allOf": [
+                            {"$ref": "flow://write-schema"},
+                            {"$ref": "flow://inferred-schema"},
+                        ],
+                    },
+                    "key": ["/id"],
+                },
+                "acmeCo/broken-ref": {
+                    "schema": {
+                        "type": "object",
+                        "properties": {"id": {"$ref": "#/$defs/missing"}},
+                        "required": ["id"],
+                    },
+                    "key": ["/id"],
+                },
+            },
+        }))
+        .unwrap();
+
+        let errors = super::validate_collection_schemas(&draft)
+            .into_iter()
+            .map(|err| (err.catalog_name, err.location, err.detail))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            errors,
+            vec![(
+                "acmeCo/broken-ref".to_string(),
+                Some("/schema".to_string()),
+                "Collection schema failed to compile: schema $ref 'schema://bundle#/$defs/missing', referenced by 'schema://bundle#/properties/id', was not found".to_string(),
+            )]
+        );
+    }
+
+    #[test]
+    fn test_schema_narrowing() {
+        let catalog = |schema: serde_json::Value| -> models::Catalog {
+            serde_json::from_value(serde_json::json!({
+                "collections": {
+                    "acmeCo/collection": {
+                        "schema": schema,
+                        "key": ["/id"],
+                        "projections": {
+                            "count": {"location": "/count", "partition": true},
+                        },
+                    },
+                },
+            }))
+            .unwrap()
+        };
+        let transition = |draft: &models::Catalog, live: &models::Catalog| {
+            super::validate_transition(
+                draft,
+                &super::write_schema_shapes(draft),
+                live,
+                Id::new([1; 8]),
+                false,
+                &Default::default(),
+                &[],
+            )
+        };
+        let live = catalog(serde_json::json!({
+            "type": "object",
+            "properties": {
+                "id": {"type": ["string", "null"]},
+                "name": {"type": ["string", "null"]},
+                "count": {"type": "integer"},
+            },
+            "required": ["id", "count"],
+        }));
+
+        // A compatible widening: `name` may also be an integer, and `tags` is added.
+        let widened = catalog(serde_json::json!({
+            "type": "object",
+            "properties": {
+                "id": {"type": ["string", "null"]},
+                "name": {"type": ["string", "integer", "null"]},
+                "count": {"type": "integer"},
+                "tags": {"type": "array"},
+            },
+            "required": ["id", "count"],
+        }));
+        assert!(transition(&widened, &live).is_ok());
+
+        // Narrowing a location which is neither a key nor a partition,
+        // as discovered schemas often do, is logged but permitted.
+        let narrowed_name = catalog(serde_json::json!({
+            "type": "object",
+            "properties": {
+                "id": {"type": ["string", "null"]},
+                "name": {"type": "string"},
+                "count": {"type": "integer"},
+            },
+            "required": ["id", "count"],
+        }));
+        assert!(transition(&narrowed_name, &live).is_ok());
+
+        // Narrowings of the key `/id`, which is no longer nullable, and the
+        // partition `/count`, which is no longer required, are errors.
+        let narrowed = catalog(serde_json::json!({
+            "type": "object",
+            "properties": {
+                "id": {"type": "string"},
+                "name": {"type": "string"},
+                "count": {"type": "integer"},
+            },
+            "required": ["id"],
+        }));
+        let (errors, incompatible) = transition(&narrowed, &live).unwrap_err();
+
+        assert!(incompatible.is_empty());
+        assert_eq!(
+            errors
+                .into_iter()
+                .map(|err| (err.catalog_name, err.detail))
+                .collect::<Vec<_>>(),
+            vec![
+                (
+                    "acmeCo/collection".to_string(),
+                    "Cannot remove the requirement that location '/count' exists, as this may break established readers of the collection".to_string(),
+                ),
+                (
+                    "acmeCo/collection".to_string(),
+                    r#"Cannot narrow the types of location '/id' from ["null", "string"] to ["string"], as this may break established readers of the collection"#.to_string(),
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_forced_transition() {
+        let catalog = |partitioned: bool| -> models::Catalog {
+            let mut collection = serde_json::json!({
+                "schema": {
+                    "type": "object",
+                    "properties": {"id": {"type": "string"}, "region": {"type": "string"}},
+                    "required": ["id", "region"],
+                },
+                "key": ["/id"],
+            });
+            if partitioned {
+                collection["projections"] = serde_json::json!({
+                    "region": {"location": "/region", "partition": true},
+                });
+            }
+            serde_json::from_value(serde_json::json!({
+                "collections": {"acmeCo/unreferenced": collection},
+            }))
+            .unwrap()
+        };
+        let (live, mut draft) = (catalog(false), catalog(true));
+
+        // A live collection which is drafted as a capture.
+        draft.captures.insert(
+            models::Capture::new("acmeCo/mismatch"),
+            serde_json::from_value(serde_json::json!({
+                "endpoint": {"connector": {"image": "an/image", "config": {}}},
+                "bindings": [],
+            }))
+            .unwrap(),
+        );
+        let mismatch = agent_sql::publications::SpecRow {
+            draft_type: Some(agent_sql::CatalogType::Capture),
+            live_type: Some(agent_sql::CatalogType::Collection),
+            ..spec_row("acmeCo/mismatch")
+        };
+
+        let draft_shapes = super::write_schema_shapes(&draft);
+        let transition =
+            |force: bool, read: &[&str], spec_rows: &[agent_sql::publications::SpecRow]| {
+                super::validate_transition(
+                    &draft,
+                    &draft_shapes,
+                    &live,
+                    Id::new([2; 8]),
+                    force,
+                    &read.iter().map(|name| name.to_string()).collect(),
+                    spec_rows,
+                )
+            };
+        let partition_change =
+            r#"Cannot change partitions of an established collection (from [] to ["region"])"#;
+
+        // Without `force`, the partition change is an error which requires re-creation.
+        let (errors, incompatible) = transition(false, &[], &[]).unwrap_err();
+        assert_eq!(
+            errors.into_iter().map(|err| err.detail).collect::<Vec<_>>(),
+            vec![partition_change],
+        );
+        assert_eq!(incompatible.len(), 1);
+
+        // With `force`, the partition change is permitted with a warning.
+        let warnings = transition(true, &[], &[]).unwrap();
+        assert_eq!(
+            warnings
+                .into_iter()
+                .map(|err| (err.catalog_name, err.detail))
+                .collect::<Vec<_>>(),
+            vec![(
+                "acmeCo/unreferenced".to_string(),
+                partition_change.to_string()
+            )],
+        );
+
+        // But not if the collection has live readers.
+        let (errors, incompatible) = transition(true, &["acmeCo/unreferenced"], &[]).unwrap_err();
+        assert_eq!(
+            errors.into_iter().map(|err| err.detail).collect::<Vec<_>>(),
+            vec![partition_change],
+        );
+        assert_eq!(incompatible.len(), 1);
+
+        // And a catalog type mismatch is not forceable.
+        let (errors, incompatible) = transition(true, &[], &[mismatch]).unwrap_err();
+        assert_eq!(
+            errors
+                .into_iter()
+                .map(|err| (err.catalog_name, err.detail))
+                .collect::<Vec<_>>(),
+            vec![(
+                "acmeCo/mismatch".to_string(),
+                "Draft has an incompatible type Capture vs current type Collection. This may be caused by an attempt to create a Capture while an existing Collection with this name exists.".to_string(),
+            )],
+        );
+        assert!(incompatible.is_empty());
+    }
+
+    #[tokio::test]
+    #[serial_test::parallel]
+    async fn test_preview_specifications() {
+        let mut conn = sqlx::postgres::PgConnection::connect(&FIXED_DATABASE_URL)
+            .await
+            .unwrap();
+        let mut txn = conn.begin().await.unwrap();
+
+        sqlx::query(include_str!("test_resources/happy_path.sql"))
+            .execute(&mut txn)
+            .await
+            .unwrap();
+
+        const COUNT_LIVE_SPECS: &str = "select count(*) from live_specs;";
+        let live_specs_before: i64 = sqlx::query_scalar(COUNT_LIVE_SPECS)
+            .fetch_one(&mut txn)
+            .await
+            .unwrap();
+
+        let rows = super::preview_specifications(
+            Id::from_hex("1110000000000000").unwrap(),
+            Id::from_hex("1111100000000000").unwrap(),
+            "43a18a3e-5a59-11ed-9b6a-0242ac120002".parse().unwrap(),
+            &mut txn,
+        )
+        .await
+        .unwrap();
+
+        // The preview resolves the drafted derivation as a new specification.
+        assert_eq!(
+            rows.iter()
+                .map(|row| (row.catalog_name.as_str(), row.live_type, row.draft_type))
+                .collect::<Vec<_>>(),
+            vec![(
+                "usageB/DerivationA",
+                None,
+                Some(agent_sql::CatalogType::Collection)
+            )],
+        );
+
+        // But leaves no `live_specs` rows behind.
+        let live_specs_after: i64 = sqlx::query_scalar(COUNT_LIVE_SPECS)
+            .fetch_one(&mut txn)
+            .await
+            .unwrap();
+        assert_eq!(live_specs_after, live_specs_before);
+    }
+
+    // Sets up a live capture into collection `renameCo/orders`, and a draft
+    // which renames `renameCo/orders` to `renameCo/sales-orders`. If
+    // `update_capture`, the draft also updates the capture to the new name.
+    async fn rename_fixture(update_capture: bool, txn: &mut Transaction<'_, Postgres>) {
+        sqlx::query(r#"
+            with p1 as (
+              insert into auth.users (id) values
+              ('43a18a3e-5a59-11ed-9b6a-0242ac120004')
+            ),
+            p2 as (
+              insert into live_specs (id, catalog_name, spec, spec_type, reads_from, writes_to, last_build_id, last_pub_id) values
+              ('8000000000000000', 'renameCo/orders', '{
+                  "schema": {"type": "object", "properties": {"id": {"type": "string"}}, "required": ["id"]},
+                  "key": ["/id"]
+              }'::json, 'collection', null, null, 'bbbbbbbbbbbbbbbb', 'bbbbbbbbbbbbbbbb'),
+              ('9000000000000000', 'renameCo/capture', '{
+                  "bindings": [{"target": "renameCo/orders", "resource": {"table": "orders"}}],
+                  "endpoint": {"connector": {"image": "allowed_connector", "config": {}}}
+              }'::json, 'capture', null, '{renameCo/orders}', 'bbbbbbbbbbbbbbbb', 'bbbbbbbbbbbbbbbb')
+            ),
+            p3 as (
+              insert into inferred_schemas (collection_name, schema, flow_document) values
+              ('renameCo/orders', '{"type": "object", "x-inferred": true}'::json, '{}'::json)
+            ),
+            p4 as (
+              insert into drafts (id, user_id) values
+              ('4440000000000000', '43a18a3e-5a59-11ed-9b6a-0242ac120004')
+            ),
+            p5 as (
+              insert into draft_specs (id, draft_id, catalog_name, spec, spec_type, rename_from) values
+              ('4441000000000000', '4440000000000000', 'renameCo/orders', null, null, null),
+              ('4442000000000000', '4440000000000000', 'renameCo/sales-orders', '{
+                  "schema": {"type": "object", "properties": {"id": {"type": "string"}}, "required": ["id"]},
+                  "key": ["/id"]
+              }'::json, 'collection', 'renameCo/orders')
+            ),
+            p6 as (
+              insert into publications (id, job_status, user_id, draft_id) values
+              ('4444400000000000', '{"type": "queued"}'::json, '43a18a3e-5a59-11ed-9b6a-0242ac120004', '4440000000000000')
+            ),
+            p7 as (
+              insert into role_grants (subject_role, object_role, capability) values
+              ('renameCo/', 'renameCo/', 'admin')
+            ),
+            p8 as (
+              insert into user_grants (user_id, object_role, capability) values
+              ('43a18a3e-5a59-11ed-9b6a-0242ac120004', 'renameCo/', 'admin')
+            ),
+            p9 as (
+                insert into connectors (external_url, image_name, title, short_description, logo_url) values
+                    ('http://example.com', 'allowed_connector', '{"en-US": "foo"}'::json, '{"en-US": "foo"}'::json, '{"en-US": "foo"}'::json)
+            )
+            select 1;"#,
+        )
+        .execute(&mut *txn)
+        .await
+        .unwrap();
+
+        if update_capture {
+            sqlx::query(r#"
+                insert into draft_specs (id, draft_id, catalog_name, spec, spec_type) values
+                ('4443000000000000', '4440000000000000', 'renameCo/capture', '{
+                    "bindings": [{"target": "renameCo/sales-orders", "resource": {"table": "orders"}}],
+                    "endpoint": {"connector": {"image": "allowed_connector", "config": {}}}
+                }'::json, 'capture');"#,
+            )
+            .execute(&mut *txn)
+            .await
+            .unwrap();
+        }
+    }
+
+    #[tokio::test]
+    #[serial_test::parallel]
+    async fn test_rename_collection() {
+        let mut conn = sqlx::postgres::PgConnection::connect(&FIXED_DATABASE_URL)
+            .await
+            .unwrap();
+        let mut txn = conn.begin().await.unwrap();
+
+        rename_fixture(true, &mut txn).await;
+        let results = execute_publications(&mut txn).await;
+
+        assert!(
+            matches!(results[0].status, JobStatus::Success { .. }),
+            "{results:?}"
+        );
+        assert_eq!(
+            results[0]
+                .live_specs
+                .iter()
+                .map(|s| (s.catalog_name.as_str(), s.spec_type.as_deref()))
+                .collect::<Vec<_>>(),
+            vec![
+                ("renameCo/capture", Some("capture")),
+                ("renameCo/orders", None),
+                ("renameCo/sales-orders", Some("collection")),
+            ],
+        );
+
+        // The inferred schema of the prior name is carried over.
+        let inferred: Value = sqlx::query_scalar(
+            "select schema from inferred_schemas where collection_name = 'renameCo/sales-orders';",
+        )
+        .fetch_one(&mut txn)
+        .await
+        .unwrap();
+        assert_eq!(
+            inferred,
+            serde_json::json!({"type": "object", "x-inferred": true})
+        );
+
+        // And the rename is recorded in its publication history.
+        let detail: Option<String> = sqlx::query_scalar(
+            r#"select ps.detail from publication_specs ps
+            join live_specs ls on ls.id = ps.live_spec_id
+            where ls.catalog_name = 'renameCo/sales-orders';"#,
+        )
+        .fetch_one(&mut txn)
+        .await
+        .unwrap();
+        assert_eq!(detail.as_deref(), Some("renamed from 'renameCo/orders'"));
+    }
+
+    #[tokio::test]
+    #[serial_test::parallel]
+    async fn test_rename_collection_with_dangling_reference() {
+        let mut conn = sqlx::postgres::PgConnection::connect(&FIXED_DATABASE_URL)
+            .await
+            .unwrap();
+        let mut txn = conn.begin().await.unwrap();
+
+        // The live capture isn't updated, and still writes to the prior name.
+        rename_fixture(false, &mut txn).await;
+        let results = execute_publications(&mut txn).await;
+
+        assert!(
+            matches!(results[0].status, JobStatus::BuildFailed { .. }),
+            "{results:?}"
+        );
+        assert_eq!(
+            results[0].errors,
+            vec!["Specification references collection 'renameCo/orders', which is renamed to 'renameCo/sales-orders' by this publication. Update it to reference 'renameCo/sales-orders' instead".to_string()],
+        );
+    }
+
+    #[tokio::test]
+    #[serial_test::parallel]
+    async fn test_publications_of_overlapping_specs() {
+        let mut conn = sqlx::postgres::PgConnection::connect(&FIXED_DATABASE_URL)
+            .await
+            .unwrap();
+        let mut txn = conn.begin().await.unwrap();
+
+        // Two drafts touch the same specifications, which were drafted in
+        // differing orders. Both publications succeed.
+        sqlx::query(r#"
+            with p1 as (
+              insert into auth.users (id) values
+              ('43a18a3e-5a59-11ed-9b6a-0242ac120005')
+            ),
+            p2 as (
+              insert into drafts (id, user_id) values
+              ('5550000000000000', '43a18a3e-5a59-11ed-9b6a-0242ac120005'),
+              ('5560000000000000', '43a18a3e-5a59-11ed-9b6a-0242ac120005')
+            ),
+            p3 as (
+              insert into draft_specs (id, draft_id, catalog_name, spec, spec_type) values
+              ('5551000000000000', '5550000000000000', 'lockCo/capture', '{
+                  "bindings": [
+                      {"target": "lockCo/b", "resource": {"table": "b"}},
+                      {"target": "lockCo/a", "resource": {"table": "a"}}
+                  ],
+                  "endpoint": {"connector": {"image": "allowed_connector", "config": {}}}
+              }'::json, 'capture'),
+              ('5552000000000000', '5550000000000000', 'lockCo/b', '{
+                  "schema": {"type": "object", "properties": {"id": {"type": "string"}}, "required": ["id"]},
+                  "key": ["/id"]
+              }'::json, 'collection'),
+              ('5553000000000000', '5550000000000000', 'lockCo/a', '{
+                  "schema": {"type": "object", "properties": {"id": {"type": "string"}}, "required": ["id"]},
+                  "key": ["/id"]
+              }'::json, 'collection'),
+              ('5561000000000000', '5560000000000000', 'lockCo/a', '{
+                  "schema": {"type": "object", "properties": {"id": {"type": "string"}}, "required": ["id"]},
+                  "key": ["/id"]
+              }'::json, 'collection'),
+              ('5562000000000000', '5560000000000000', 'lockCo/b', '{
+                  "schema": {"type": "object", "properties": {"id": {"type": "string"}}, "required": ["id"]},
+                  "key": ["/id"]
+              }'::json, 'collection'),
+              ('5563000000000000', '5560000000000000', 'lockCo/capture', '{
+                  "bindings": [
+                      {"target": "lockCo/a", "resource": {"table": "a"}},
+                      {"target": "lockCo/b", "resource": {"table": "b"}}
+                  ],
+                  "endpoint": {"connector": {"image": "allowed_connector", "config": {}}}
+              }'::json, 'capture')
+            ),
+            p4 as (
+              insert into publications (id, job_status, user_id, draft_id) values
+              ('5555500000000000', '{"type": "queued"}'::json, '43a18a3e-5a59-11ed-9b6a-0242ac120005', '5550000000000000'),
+              ('5566600000000000', '{"type": "queued"}'::json, '43a18a3e-5a59-11ed-9b6a-0242ac120005', '5560000000000000')
+            ),
+            p5 as (
+              insert into role_grants (subject_role, object_role, capability) values
+              ('lockCo/', 'lockCo/', 'admin')
+            ),
+            p6 as (
+              insert into user_grants (user_id, object_role, capability) values
+              ('43a18a3e-5a59-11ed-9b6a-0242ac120005', 'lockCo/', 'admin')
+            ),
+            p7 as (
+                insert into connectors (external_url, image_name, title, short_description, logo_url) values
+                    ('http://example.com', 'allowed_connector', '{"en-US": "foo"}'::json, '{"en-US": "foo"}'::json, '{"en-US": "foo"}'::json)
+            )
+            select 1;
+        "#).execute(&mut txn).await.unwrap();
+
+        let results = execute_publications(&mut txn).await;
+
+        assert_eq!(results.len(), 2, "{results:?}");
+        for result in &results {
+            assert!(
+                matches!(result.status, JobStatus::Success { .. }),
+                "{results:?}"
+            );
+            assert_eq!(
+                result
+                    .live_specs
+                    .iter()
+                    .map(|s| s.catalog_name.as_str())
+                    .collect::<Vec<_>>(),
+                vec!["lockCo/a", "lockCo/b", "lockCo/capture"],
+            );
+        }
+    }
+
+    #[tokio::test]
+    #[serial_test::parallel]
+    async fn test_publication_detail() {
+        let mut conn = sqlx::postgres::PgConnection::connect(&FIXED_DATABASE_URL)
+            .await
+            .unwrap();
+        let mut txn = conn.begin().await.unwrap();
+
+        sqlx::query(include_str!("test_resources/happy_path.sql"))
+            .execute(&mut txn)
+            .await
+            .unwrap();
+        sqlx::query(
+            "update publications set detail = 'add a derivation of CollectionA' where id = '1111100000000000';",
+        )
+        .execute(&mut txn)
+        .await
+        .unwrap();
+
+        let bs_url: Url = "http://example.com".parse().unwrap();
+        let (logs_tx, mut logs_rx) = tokio::sync::mpsc::channel(8192);
+        logs_rx.close();
+
+        let mut handler = PublishHandler::new(
+            "support@estuary.dev",
+            false,
+            "",
+            &bs_url,
+            &bs_url,
+            "",
+            &bs_url,
+            &logs_tx,
+            None,
+        );
+        let row = agent_sql::publications::dequeue(&mut txn, true)
+            .await
+            .unwrap()
+            .unwrap();
+        let (_pub_id, status, report) = handler.process(row, &mut txn, true).await.unwrap();
+
+        assert!(matches!(status, JobStatus::Success { .. }), "{status:?}");
+        // The message of the publication is echoed in its summary.
+        assert_eq!(
+            report["summary"]["detail"],
+            serde_json::json!("add a derivation of CollectionA")
+        );
+        // And it's persisted in the publication history of each of its specs.
+        let detail: Option<String> = sqlx::query_scalar(
+            "select detail from publication_specs where pub_id = '1111100000000000';",
+        )
+        .fetch_one(&mut txn)
+        .await
+        .unwrap();
+        assert_eq!(detail.as_deref(), Some("add a derivation of CollectionA"));
+    }
+
+    #[tokio::test]
+    #[serial_test::parallel]
+    async fn test_republish_of_unchanged_specs() {
+        let mut conn = sqlx::postgres::PgConnection::connect(&FIXED_DATABASE_URL)
+            .await
+            .unwrap();
+        let mut txn = conn.begin().await.unwrap();
+
+        // The first draft creates two collections. The second republishes
+        // hashCo/same with re-ordered and re-formatted content, and widens
+        // the schema of hashCo/changed with an additional property.
+        sqlx::query(r#"
+            with p1 as (
+              insert into auth.users (id, email, email_confirmed_at) values
+              ('43a18a3e-5a59-11ed-9b6a-0242ac120006', 'hash@example.com', now())
+            ),
+            p2 as (
+              insert into drafts (id, user_id) values
+              ('7770000000000000', '43a18a3e-5a59-11ed-9b6a-0242ac120006'),
+              ('7780000000000000', '43a18a3e-5a59-11ed-9b6a-0242ac120006')
+            ),
+            p3 as (
+              insert into draft_specs (id, draft_id, catalog_name, spec, spec_type) values
+              ('7771000000000000', '7770000000000000', 'hashCo/same', '{
+                  "schema": {"type": "object", "properties": {"id": {"type": "string"}}, "required": ["id"]},
+                  "key": ["/id"]
+              }'::json, 'collection'),
+              ('7772000000000000', '7770000000000000', 'hashCo/changed', '{
+                  "schema": {"type": "object", "properties": {"id": {"type": "string"}}, "required": ["id"]},
+                  "key": ["/id"]
+              }'::json, 'collection'),
+              ('7781000000000000', '7780000000000000', 'hashCo/same', '{"key":["/id"],"schema":{"required":["id"],"properties":{"id":{"type":"string"}},"type":"object"}}'::json, 'collection'),
+              ('7782000000000000', '7780000000000000', 'hashCo/changed', '{
+                  "schema": {"type": "object", "properties": {"id": {"type": "string"}, "name": {"type": "string"}}, "required": ["id"]},
+                  "key": ["/id"]
+              }'::json, 'collection')
+            ),
+            p4 as (
+              insert into publications (id, job_status, user_id, draft_id) values
+              ('7777700000000000', '{"type": "queued"}'::json, '43a18a3e-5a59-11ed-9b6a-0242ac120006', '7770000000000000'),
+              ('7788800000000000', '{"type": "queued"}'::json, '43a18a3e-5a59-11ed-9b6a-0242ac120006', '7780000000000000')
+            ),
+            p5 as (
+              insert into role_grants (subject_role, object_role, capability) values
+              ('hashCo/', 'hashCo/', 'admin')
+            ),
+            p6 as (
+              insert into user_grants (user_id, object_role, capability) values
+              ('43a18a3e-5a59-11ed-9b6a-0242ac120006', 'hashCo/', 'admin')
+            )
+            select 1;
+        "#).execute(&mut txn).await.unwrap();
+
+        let results = execute_publications(&mut txn).await;
+
+        assert_eq!(results.len(), 2, "{results:?}");
+        for result in &results {
+            assert!(
+                matches!(result.status, JobStatus::Success { .. }),
+                "{results:?}"
+            );
+        }
+        let published = |index: usize| {
+            results[index]
+                .live_specs
+                .iter()
+                .map(|s| s.catalog_name.as_str())
+                .collect::<Vec<_>>()
+        };
+        // Only the changed specification was updated by the second publication.
+        assert_eq!(published(0), vec!["hashCo/changed", "hashCo/same"]);
+        assert_eq!(published(1), vec!["hashCo/changed"]);
+
+        let live: Vec<(String, String, String, Option<String>)> = sqlx::query_as(
+            r#"select catalog_name::text, last_pub_id::text, last_build_id::text, spec_hash
+            from live_specs where catalog_name like 'hashCo/%'
+            order by catalog_name;"#,
+        )
+        .fetch_all(&mut txn)
+        .await
+        .unwrap();
+
+        let hash = |spec: serde_json::Value| {
+            super::spec_hash(&models::RawValue::from_value(&spec)).unwrap()
+        };
+        assert_eq!(
+            live,
+            vec![
+                (
+                    "hashCo/changed".to_string(),
+                    "77:88:80:00:00:00:00:00".to_string(),
+                    "77:88:80:00:00:00:00:00".to_string(),
+                    Some(hash(serde_json::json!({
+                        "schema": {"type": "object", "properties": {"id": {"type": "string"}, "name": {"type": "string"}}, "required": ["id"]},
+                        "key": ["/id"]
+                    }))),
+                ),
+                (
+                    "hashCo/same".to_string(),
+                    "77:77:70:00:00:00:00:00".to_string(),
+                    "77:88:80:00:00:00:00:00".to_string(),
+                    Some(hash(serde_json::json!({
+                        "schema": {"type": "object", "properties": {"id": {"type": "string"}}, "required": ["id"]},
+                        "key": ["/id"]
+                    }))),
+                ),
+            ],
+        );
+
+        // Only the changed specification is recorded in the history of the
+        // second publication.
+        let history: Vec<String> = sqlx::query_scalar(
+            r#"select l.catalog_name::text from publication_specs p
+            join live_specs l on l.id = p.live_spec_id
+            where p.pub_id = '7788800000000000';"#,
+        )
+        .fetch_all(&mut txn)
+        .await
+        .unwrap();
+        assert_eq!(history, vec!["hashCo/changed"]);
+
+        // The second publication may be rolled back, which reverts only the
+        // specification it changed.
+        let rollback_pub_id = crate::publications::rollback::rollback_publication(
+            "hash@example.com",
+            Id::from_hex("7788800000000000").unwrap(),
+            &mut txn,
+        )
+        .await
+        .unwrap();
+
+        let drafted: Vec<(String, String)> = sqlx::query_as(
+            r#"select d.catalog_name, d.expect_pub_id::text
+            from publications p join draft_specs d on d.draft_id = p.draft_id
+            where p.id = $1;"#,
+        )
+        .bind(rollback_pub_id)
+        .fetch_all(&mut txn)
+        .await
+        .unwrap();
+        assert_eq!(
+            drafted,
+            vec![(
+                "hashCo/changed".to_string(),
+                "77:88:80:00:00:00:00:00".to_string()
+            )]
+        );
+    }
+
+    #[test]
+    fn test_unresolved_references() {
+        let draft: models::Catalog = serde_json::from_value(serde_json::json!({
+            "collections": {
+                "acmeCo/orders": {"schema": {"type": "object"}, "key": ["/id"]},
+                "acmeCo/derived": {
+                    "schema": {"type": "object"},
+                    "key": ["/id"],
+                    "derive": {
+                        "using": {"sqlite": {}},
+                        "transforms": [
+                            // Drafted collection.
+                            {"name": "fromOrders", "source": "acmeCo/orders", "shuffle": "any"},
+                            // Live collection.
+                            {"name": "fromUsers", "source": "acmeCo/users", "shuffle": "any"},
+                        ],
+                    },
+                },
+            },
+            "materializations": {
+                "acmeCo/materialize": {
+                    "endpoint": {"connector": {"image": "an/image", "config": {}}},
+                    "bindings": [
+                        {"source": "acmeCo/derived", "resource": {"table": "derived"}},
+                        {"source": "acmeCo/odrers", "resource": {"table": "typo"}},
+                        {"source": "acmeCo/deleted", "resource": {"table": "deleted"}},
+                    ],
+                },
+            },
+        }))
+        .unwrap();
+
+        let spec_row = |catalog_name: &str, draft_type| agent_sql::publications::SpecRow {
+            draft_type,
+            ..spec_row(catalog_name)
+        };
+        let spec_rows = vec![
+            spec_row("acmeCo/deleted", None),
+            spec_row("acmeCo/derived", Some(agent_sql::CatalogType::Collection)),
+            spec_row(
+                "acmeCo/materialize",
+                Some(agent_sql::CatalogType::Materialization),
+            ),
+            spec_row("acmeCo/orders", Some(agent_sql::CatalogType::Collection)),
+        ];
+        let live_collections = ["acmeCo/deleted", "acmeCo/users"]
+            .into_iter()
+            .map(str::to_string)
+            .collect();
+
+        let errors = super::unresolved_references(
+            &draft,
+            &spec_rows,
+            &live_collections,
+            &Default::default(),
+        )
+        .into_iter()
+        .map(|err| (err.catalog_name, err.detail))
+        .collect::<Vec<_>>();
+
+        assert_eq!(
+            errors,
+            vec![
+                (
+                    "acmeCo/materialize".to_string(),
+                    "Specification references collection 'acmeCo/deleted', which is deleted by this publication".to_string(),
+                ),
+                (
+                    "acmeCo/materialize".to_string(),
+                    "Specification references collection 'acmeCo/odrers', which does not exist in the draft or live catalog".to_string(),
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_test_step_missing_collection() {
+        let draft: models::Catalog = serde_json::from_value(serde_json::json!({
+            "tests": {
+                "acmeCo/test": [
+                    // Live collection which isn't part of this publication.
+                    {"ingest": {"collection": "acmeCo/users", "documents": [{"id": "a"}]}},
+                    {"verify": {"collection": "acmeCo/missing", "documents": []}},
+                ],
+            },
+        }))
+        .unwrap();
+
+        let live_collections = ["acmeCo/users"].into_iter().map(str::to_string).collect();
+
+        let errors =
+            super::test_step_errors(&draft, &models::Catalog::default(), &live_collections)
+                .into_iter()
+                .map(|err| (err.catalog_name, err.location, err.detail))
+                .collect::<Vec<_>>();
+
+        assert_eq!(
+            errors,
+            vec![(
+                "acmeCo/test".to_string(),
+                Some("/1/verify/collection".to_string()),
+                "Test step references collection 'acmeCo/missing', which does not exist in the draft or live catalog".to_string(),
+            )]
+        );
+    }
+
+    #[test]
+    fn test_test_step_invalid_ingest() {
+        let draft: models::Catalog = serde_json::from_value(serde_json::json!({
+            "collections": {
+                "acmeCo/orders": {
+                    "schema": {
+                        "type": "object",
+                        "properties": {"id": {"type": "string"}, "qty": {"type": "integer"}},
+                    },
+                    "key": ["/id"],
+                },
+            },
+            "tests": {
+                "acmeCo/test": [
+                    {"ingest": {"collection": "acmeCo/orders", "documents": [
+                        {"id": "a", "qty": 1},
+                        {"id": "b", "qty": "many"},
+                        {"qty": 2},
+                    ]}},
+                    {"verify": {"collection": "acmeCo/orders", "documents": [{"id": "a"}]}},
+                ],
+            },
+        }))
+        .unwrap();
+
+        let errors =
+            super::test_step_errors(&draft, &models::Catalog::default(), &Default::default());
+        assert_eq!(errors.len(), 2);
+
+        assert_eq!(errors[0].catalog_name, "acmeCo/test");
+        assert_eq!(errors[0].location.as_deref(), Some("/0/ingest/documents/1"));
+        assert!(errors[0].detail.starts_with(
+            "Ingested document is invalid against the write schema of collection 'acmeCo/orders': "
+        ));
+        assert!(errors[0].detail.contains("#/properties/qty"));
+
+        assert_eq!(errors[1].location.as_deref(), Some("/0/ingest/documents/2"));
+        assert_eq!(
+            errors[1].detail,
+            "Ingested document is missing collection 'acmeCo/orders' key location '/id'"
+        );
+    }
+
+    #[test]
+    fn test_case_mismatched_references() {
+        let draft: models::Catalog = serde_json::from_value(serde_json::json!({
+            "collections": {
+                "acmeCo/orders": {"schema": {"type": "object"}, "key": ["/id"]},
+            },
+            "materializations": {
+                "acmeCo/materialize": {
+                    "endpoint": {"connector": {"image": "an/image", "config": {}}},
+                    "bindings": [
+                        // Exact match of a drafted collection.
+                        {"source": "acmeCo/orders", "resource": {"table": "orders"}},
+                        // Case mismatch of a drafted collection.
+                        {"source": "acmeCo/Orders", "resource": {"table": "orders2"}},
+                        // Case mismatch of a live collection.
+                        {"source": "acmeCo/users", "resource": {"table": "users"}},
+                        // Genuinely missing.
+                        {"source": "acmeCo/missing", "resource": {"table": "missing"}},
+                    ],
+                },
+            },
+        }))
+        .unwrap();
+
+        let spec_row = |catalog_name: &str, draft_type| agent_sql::publications::SpecRow {
+            draft_type,
+            ..spec_row(catalog_name)
+        };
+        let spec_rows = vec![
+            spec_row(
+                "acmeCo/materialize",
+                Some(agent_sql::CatalogType::Materialization),
+            ),
+            spec_row("acmeCo/orders", Some(agent_sql::CatalogType::Collection)),
+        ];
+        let case_mismatches = ["acmeCo/Users".to_string()].into_iter().collect();
+
+        let errors =
+            super::unresolved_references(&draft, &spec_rows, &Default::default(), &case_mismatches)
+                .into_iter()
+                .map(|err| err.detail)
+                .collect::<Vec<_>>();
+
+        assert_eq!(
+            errors,
+            vec![
+                "Specification references collection 'acmeCo/Orders', which does not exist. Catalog names are case-sensitive: did you mean 'acmeCo/orders'?",
+                "Specification references collection 'acmeCo/missing', which does not exist in the draft or live catalog",
+                "Specification references collection 'acmeCo/users', which does not exist. Catalog names are case-sensitive: did you mean 'acmeCo/Users'?",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_split_tag() {
+        let digest = "c9d2b2c1a96f2f29ac5dd6e91b3a1ef0cc95fe3b0c8c1a4b7a5a5ed7a0e3f7b1";
+
+        let cases = [
+            (
+                "ghcr.io/estuary/source-hello-world:v1",
+                Ok(("ghcr.io/estuary/source-hello-world", ":v1")),
+            ),
+            ("source-hello-world", Ok(("source-hello-world", ""))),
+            // A registry port isn't a tag.
+            (
+                "registry:5000/acme/connector:v1",
+                Ok(("registry:5000/acme/connector", ":v1")),
+            ),
+            (
+                "registry:5000/acme/connector",
+                Ok(("registry:5000/acme/connector", "")),
+            ),
+        ];
+        for (image, expect) in cases {
+            let expect: Result<(String, String), String> =
+                expect.map(|(name, tag)| (name.to_string(), tag.to_string()));
+            assert_eq!(super::split_tag(image), expect, "{image}");
+        }
+
+        // A digest reference.
+        let image = format!("registry:5000/acme/connector@sha256:{digest}");
+        assert_eq!(
+            super::split_tag(&image),
+            Ok((
+                "registry:5000/acme/connector".to_string(),
+                format!("@sha256:{digest}")
+            ))
+        );
+
+        // Malformed digests, which are too short or aren't lowercase hex.
+        for image in [
+            "acme/connector@sha256:c9d2b2".to_string(),
+            format!("acme/connector@sha256:{}", digest.to_uppercase()),
+        ] {
+            assert_eq!(
+                super::split_tag(&image),
+                Err(format!("Connector image '{image}' has a malformed digest: a sha256 digest must be 64 lowercase hexadecimal characters")),
+            );
+        }
+    }
+
+    #[test]
+    fn test_summarize_publication() {
+        use super::{PublicationSummary, SpecChange};
+
+        let spec = |value: serde_json::Value| {
+            Some(sqlx::types::Json(
+                serde_json::value::to_raw_value(&value).unwrap(),
+            ))
+        };
+        // Specifications are collections, and live specifications have
+        // their hash as of their last publication.
+        type Spec = Option<sqlx::types::Json<Box<serde_json::value::RawValue>>>;
+        let spec_row = |catalog_name: &str, live_spec: Spec, draft_spec: Spec| {
+            agent_sql::publications::SpecRow {
+                live_spec_hash: live_spec
+                    .as_ref()
+                    .map(|spec| super::spec_hash(&spec.0).unwrap()),
+                live_type: live_spec
+                    .as_ref()
+                    .map(|_| agent_sql::CatalogType::Collection),
+                draft_type: draft_spec
+                    .as_ref()
+                    .map(|_| agent_sql::CatalogType::Collection),
+                live_spec,
+                draft_spec,
+                ..spec_row(catalog_name)
+            }
+        };
+        let collection = serde_json::json!({"schema": {"type": "object"}, "key": ["/id"]});
+
+        let spec_rows = vec![
+            spec_row("acmeCo/added", None, spec(collection.clone())),
+            spec_row("acmeCo/deleted", spec(collection.clone()), None),
+            spec_row(
+                "acmeCo/modified",
+                spec(collection.clone()),
+                spec(serde_json::json!({"schema": {"type": "object"}, "key": ["/other"]})),
+            ),
+            spec_row(
+                "acmeCo/unchanged",
+                spec(collection.clone()),
+                // Property order and whitespace are insignificant.
+                Some(sqlx::types::Json(
+                    serde_json::value::RawValue::from_string(
+                        r#"{ "key": ["/id"], "schema": {"type": "object"} }"#.to_string(),
+                    )
+                    .unwrap(),
+                )),
+            ),
+            // Integral numbers are equal to their float representation.
+            spec_row(
+                "acmeCo/integral",
+                spec(
+                    serde_json::json!({"schema": {"type": "object", "maxProperties": 1}, "key": ["/id"]}),
+                ),
+                spec(
+                    serde_json::json!({"schema": {"type": "object", "maxProperties": 1.0}, "key": ["/id"]}),
+                ),
+            ),
+            spec_row("acmeCo/pruned", None, spec(collection.clone())),
+        ];
+        let pruned_collections = ["acmeCo/pruned".to_string()].into_iter().collect();
+
+        assert_eq!(
+            super::summarize_publication(
+                Some(&"tighten the key".to_string()),
+                &spec_rows,
+                &pruned_collections
+            ),
+            PublicationSummary {
+                detail: Some("tighten the key".to_string()),
+                added: 1,
+                modified: 1,
+                deleted: 1,
+                unchanged: 2,
+                specs: [
+                    ("acmeCo/added", SpecChange::Added),
+                    ("acmeCo/deleted", SpecChange::Deleted),
+                    ("acmeCo/integral", SpecChange::Unchanged),
+                    ("acmeCo/modified", SpecChange::Modified),
+                    ("acmeCo/unchanged", SpecChange::Unchanged),
+                ]
+                .into_iter()
+                .map(|(name, change)| (name.to_string(), change))
+                .collect(),
+            }
+        );
+    }
+
+    #[test]
+    fn test_publication_report() {
+        use super::{Error, PublicationSummary, SpecChange};
+
+        let mut summary = PublicationSummary {
+            detail: Some("add and remove collections".to_string()),
+            ..Default::default()
+        };
+        summary.insert("acmeCo/added", SpecChange::Added);
+        summary.insert("acmeCo/deleted", SpecChange::Deleted);
+
+        let errors = vec![
+            Error {
+                catalog_name: "acmeCo/broken".to_string(),
+                location: Some("/schema".to_string()),
+                detail: "Collection schema failed to compile".to_string(),
+                ..Default::default()
+            },
+            Error {
+                catalog_name: "acmeCo/other".to_string(),
+                detail: "something went wrong".to_string(),
+                ..Default::default()
+            },
+        ];
+        let warnings = vec![Error {
+            catalog_name: "acmeCo/forced".to_string(),
+            detail: "Cannot change partitions of an established collection".to_string(),
+            ..Default::default()
+        }];
+
+        insta::assert_json_snapshot!(super::publication_report(&errors, &warnings, &summary), @r###"
+        {
+          "errors": [
+            {
+              "catalogName": "acmeCo/broken",
+              "detail": "Collection schema failed to compile",
+              "scope": "flow://spec/acmeCo/broken#/schema"
+            },
+            {
+              "catalogName": "acmeCo/other",
+              "detail": "something went wrong",
+              "scope": "acmeCo/other"
+            }
+          ],
+          "summary": {
+            "added": 1,
+            "deleted": 1,
+            "detail": "add and remove collections",
+            "modified": 0,
+            "specs": {
+              "acmeCo/added": "added",
+              "acmeCo/deleted": "deleted"
+            },
+            "unchanged": 0
+          },
+          "warnings": [
+            {
+              "catalogName": "acmeCo/forced",
+              "detail": "Cannot change partitions of an established collection",
+              "scope": "acmeCo/forced"
+            }
+          ]
+        }
+        "###);
+    }
+
+    #[test]
+    fn test_spec_hash() {
+        let hash = |spec: &str| {
+            let spec: Box<serde_json::value::RawValue> = serde_json::from_str(spec).unwrap();
+            super::spec_hash(&spec).unwrap()
+        };
+        let fixture = hash(r#"{"key": ["/id"], "schema": {"type": "object", "required": ["id"]}}"#);
+
+        // Property order and whitespace don't change the hash.
+        assert_eq!(
+            fixture,
+            hash("{\n  \"schema\":{\"required\":[\"id\"],\"type\":\"object\"},\n  \"key\":[\"/id\"]\n}"),
+        );
+        // Nor does the representation of integral numbers.
+        assert_eq!(
+            hash(r#"{"schema": {"maximum": 10}}"#),
+            hash(r#"{"schema": {"maximum": 10.0}}"#),
+        );
+        // But content does, including the order of array items.
+        assert_ne!(
+            fixture,
+            hash(r#"{"key": ["/id"], "schema": {"type": "object", "required": ["ID"]}}"#),
+        );
+        assert_ne!(fixture, hash(r#"{"key": ["/id", "/v"], "schema": {}}"#));
+        assert_ne!(hash(r#"[1, 2]"#), hash(r#"[2, 1]"#));
+
+        assert_eq!(fixture.len(), 64);
+        assert!(fixture.chars().all(|c| matches!(c, '0'..='9' | 'a'..='f')));
+    }
 }
@@ -225,6 +225,25 @@ impl MemTable {
         HeapNode::from_serde(&mut de, self.alloc())
     }
 
+    /// Validate a document of the binding against its schema, without adding it.
+    /// Added documents are otherwise validated only as they're reduced or drained,
+    /// which may be well after they're added.
+    pub fn validate<'s>(&'s self, binding: u32, root: &HeapNode<'s>) -> Result<(), Error> {
+        // Safety: mutable borrow does not escape this function.
+        let entries = unsafe { &mut *self.entries.get() };
+        let (validator, ref schema) = &mut entries.spec.validators[binding as usize];
+
+        validator
+            .validate(schema.as_ref(), root)
+            .map_err(Error::SchemaError)?
+            .ok()
+            .map_err(|err| {
+                Error::FailedValidation(entries.spec.names[binding as usize].clone(), err)
+            })?;
+
+        Ok(())
+    }
+
     /// Add the document to the MemTable.
     pub fn add<'s>(&'s self, binding: u32, root: HeapNode<'s>, front: bool) -> Result<(), Error> {
         // Safety: mutable borrow does not escape this function.
@@ -1018,8 +1037,17 @@ mod test {
                                                                00000074
         "###);
 
-        // New MemTable. This time we attempt to spill an invalid, non-reduced document.
+        // New MemTable. Documents may be validated without being added.
         let memtable = MemTable::new(spec);
+        let good = HeapNode::from_node(&json!({"key": "ddd", "v": "good"}), memtable.alloc());
+        let bad = HeapNode::from_node(&json!({"key": "ddd", "v": "bad"}), memtable.alloc());
+        assert!(memtable.validate(0, &good).is_ok());
+        assert!(matches!(
+            memtable.validate(0, &bad),
+            Err(Error::FailedValidation(n, _)) if n == "source-name"
+        ));
+
+        // This time we attempt to spill an invalid, non-reduced document.
         add(&memtable, false, json!({"key": "ddd", "v": "bad"}));
 
         let mut spill = SpillWriter::new(io::Cursor::new(Vec::new())).unwrap();
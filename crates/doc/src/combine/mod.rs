@@ -103,16 +103,40 @@ pub use spill::{SpillDrainer, SpillWriter};
 pub struct Accumulator {
     memtable: Option<MemTable>,
     spill: SpillWriter<std::fs::File>,
+    spill_threshold: usize,
 }
 
 impl Accumulator {
     pub fn new(spec: Spec, spill: std::fs::File) -> Result<Self, Error> {
+        Self::with_spill_threshold(spec, spill, BUMP_THRESHOLD)
+    }
+
+    /// Build an Accumulator which spills its MemTable once the MemTable uses
+    /// more than `spill_threshold` bytes of memory. The threshold is retained
+    /// by Drainers of the Accumulator, and their new Accumulators.
+    pub fn with_spill_threshold(
+        spec: Spec,
+        spill: std::fs::File,
+        spill_threshold: usize,
+    ) -> Result<Self, Error> {
         Ok(Self {
             memtable: Some(MemTable::new(spec)),
             spill: SpillWriter::new(spill)?,
+            spill_threshold,
         })
     }
 
+    /// Total bytes of MemTable segments spilled by this Accumulator.
+    /// This doesn't include the final segment which is spilled upon
+    /// `into_drainer()`, which happens only if prior segments were spilled.
+    pub fn spilled_bytes(&self) -> u64 {
+        self.spill
+            .segment_ranges()
+            .iter()
+            .map(|range| range.end - range.begin)
+            .sum()
+    }
+
     /// Obtain an MemTable with available capacity.
     /// If the held MemTable is already over-capacity, it is first spilled and
     /// then replaced with a new instance, which is then returned.
@@ -120,12 +144,13 @@ impl Accumulator {
         let Self {
             memtable: Some(memtable),
             spill,
+            spill_threshold,
         } = self
         else {
             unreachable!("memtable is always Some");
         };
 
-        if bump_mem_used(memtable.alloc()) > BUMP_THRESHOLD {
+        if bump_mem_used(memtable.alloc()) > *spill_threshold {
             let spec = self
                 .memtable
                 .take()
@@ -143,6 +168,7 @@ impl Accumulator {
         let Self {
             memtable: Some(memtable),
             mut spill,
+            spill_threshold,
         } = self
         else {
             unreachable!("memtable must be Some");
@@ -154,6 +180,7 @@ impl Accumulator {
             Ok(Drainer::Mem {
                 spill,
                 drainer: memtable.try_into_drainer()?,
+                spill_threshold,
             })
         } else {
             // Spill the final MemTable segment.
@@ -162,6 +189,7 @@ impl Accumulator {
 
             Ok(Drainer::Spill {
                 drainer: SpillDrainer::new(spec, spill, &ranges)?,
+                spill_threshold,
             })
         }
     }
@@ -172,9 +200,11 @@ pub enum Drainer {
     Mem {
         spill: std::fs::File,
         drainer: MemDrainer,
+        spill_threshold: usize,
     },
     Spill {
         drainer: SpillDrainer<std::fs::File>,
+        spill_threshold: usize,
     },
 }
 
@@ -190,7 +220,7 @@ impl Iterator for Drainer {
     fn next(&mut self) -> Option<Self::Item> {
         match self {
             Self::Mem { drainer, .. } => drainer.next(),
-            Self::Spill { drainer } => drainer.next(),
+            Self::Spill { drainer, .. } => drainer.next(),
         }
     }
 }
@@ -200,7 +230,7 @@ impl Drainer {
     pub fn drain_next(&mut self) -> Result<Option<DrainedDoc>, Error> {
         match self {
             Self::Mem { drainer, .. } => drainer.drain_next(),
-            Self::Spill { drainer } => drainer.drain_next(),
+            Self::Spill { drainer, .. } => drainer.drain_next(),
         }
     }
 
@@ -208,17 +238,32 @@ impl Drainer {
     /// Any un-drained documents are dropped.
     pub fn into_new_accumulator(self) -> Result<Accumulator, Error> {
         match self {
-            Drainer::Mem { spill, drainer } => {
+            Drainer::Mem {
+                spill,
+                drainer,
+                spill_threshold,
+            } => {
                 let spec = drainer.into_spec();
-                Ok(Accumulator::new(spec, spill)?)
+                Ok(Accumulator::with_spill_threshold(
+                    spec,
+                    spill,
+                    spill_threshold,
+                )?)
             }
-            Drainer::Spill { drainer } => {
+            Drainer::Spill {
+                drainer,
+                spill_threshold,
+            } => {
                 let (spec, mut spill) = drainer.into_parts();
 
                 spill.seek(io::SeekFrom::Start(0))?; // Reset to start.
                 spill.set_len(0)?; // Release allocated size to OS.
 
-                Ok(Accumulator::new(spec, spill)?)
+                Ok(Accumulator::with_spill_threshold(
+                    spec,
+                    spill,
+                    spill_threshold,
+                )?)
             }
         }
     }
@@ -1,7 +1,7 @@
 // This module defines limits which are used to simplify complex,
 // typically inferred schema Shapes.
 use super::*;
-use crate::ptr::Token;
+use crate::{ptr::Token, Pointer};
 use itertools::Itertools;
 use std::cmp::Ordering;
 
@@ -122,25 +122,15 @@ fn squash_location(shape: &mut Shape, location: &[Token]) {
 }
 
 /// Reduce the size/complexity of a shape while making sure that all
-/// objects that used to pass validation still do.
-pub fn enforce_shape_complexity_limit(shape: &mut Shape, limit: usize) {
-    let mut pointers = shape
-        .locations()
-        .into_iter()
-        .filter_map(|(ptr, _, _, _)| match ptr.0.as_slice() {
-            // We need to include `/*/foo` in order to squash inside `additional*` subschemas,
-            // but we don't want to include those locations that are leaf nodes, since
-            // leaf node recursion is squashed every time we squash a concrete property.
-            [.., Token::NextIndex] => None,
-            [.., Token::NextProperty] => None,
-            [] => None,
-            _ => Some(ptr),
-        })
-        .collect_vec();
+/// objects that used to pass validation still do. If the shape was reduced,
+/// its prior and reduced complexity (as counts of locations) are returned.
+pub fn enforce_shape_complexity_limit(shape: &mut Shape, limit: usize) -> Option<(usize, usize)> {
+    let mut pointers = complexity_pointers(shape);
 
-    if pointers.len() < limit {
-        return;
+    if pointers.len() <= limit {
+        return None;
     }
+    let prior = pointers.len();
 
     pointers.sort_by(|a_ptr, b_ptr| {
         // order by depth, then by pointer location
@@ -158,6 +148,25 @@ pub fn enforce_shape_complexity_limit(shape: &mut Shape, limit: usize) {
 
         squash_location(shape, location_ptr.0.as_slice());
     }
+
+    Some((prior, complexity_pointers(shape).len()))
+}
+
+// Locations of the shape which count towards its complexity.
+fn complexity_pointers(shape: &Shape) -> Vec<Pointer> {
+    shape
+        .locations()
+        .into_iter()
+        .filter_map(|(ptr, _, _, _)| match ptr.0.as_slice() {
+            // We need to include `/*/foo` in order to squash inside `additional*` subschemas,
+            // but we don't want to include those locations that are leaf nodes, since
+            // leaf node recursion is squashed every time we squash a concrete property.
+            [.., Token::NextIndex] => None,
+            [.., Token::NextProperty] => None,
+            [] => None,
+            _ => Some(ptr),
+        })
+        .collect_vec()
 }
 
 pub const DEFAULT_SCHEMA_COMPLEXITY_LIMIT: usize = 1_000;
@@ -68,6 +68,99 @@ pub trait AnnotationBuilder: Annotation {
     fn from_keyword(keyword: &str, value: &sj::Value) -> Result<Self, Error>;
 }
 
+/// Vocabularies of keywords which are enabled when building a schema.
+/// Keywords of a disabled vocabulary are treated as unknown annotations which
+/// are ignored: they're not applied or validated, and are not passed to the
+/// AnnotationBuilder. Keywords which aren't part of a vocabulary, such as
+/// custom annotations, are unaffected.
+///
+/// Keywords are grouped into vocabularies as in JSON Schema 2019-09:
+///
+/// * `core`: `$id`, `$schema`, `$anchor`, `$ref`, `$recursiveRef`,
+///   `$recursiveAnchor`, `$vocabulary`, `$comment`, and `$defs`
+///   (or `definitions`).
+/// * `applicator`: `allOf`, `anyOf`, `oneOf`, `not`, `if`, `then`, `else`,
+///   `dependentSchemas`, `properties`, `patternProperties`, `additionalProperties`,
+///   `unevaluatedProperties`, `propertyNames`, `items`, `additionalItems`,
+///   `unevaluatedItems`, and `contains`.
+/// * `validation`: `type` (and OpenAPI `nullable`), `const`, `enum`, `multipleOf`,
+///   `maximum`, `exclusiveMaximum`, `minimum`, `exclusiveMinimum`, `maxLength`,
+///   `minLength`, `pattern`, `maxItems`, `minItems`, `uniqueItems`, `maxContains`,
+///   `minContains`, `maxProperties`, `minProperties`, `required`, and
+///   `dependentRequired`.
+/// * `format`: `format`.
+/// * `content`: `contentEncoding`, `contentMediaType`, and `contentSchema`.
+/// * `meta_data`: `title`, `description`, `default`, `deprecated`, `readOnly`,
+///   `writeOnly`, and `examples` (or OpenAPI `example`).
+///
+/// All vocabularies are enabled by default.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Vocabularies {
+    pub core: bool,
+    pub applicator: bool,
+    pub validation: bool,
+    pub format: bool,
+    pub content: bool,
+    pub meta_data: bool,
+}
+
+impl Default for Vocabularies {
+    fn default() -> Self {
+        Self {
+            core: true,
+            applicator: true,
+            validation: true,
+            format: true,
+            content: true,
+            meta_data: true,
+        }
+    }
+}
+
+impl Vocabularies {
+    /// Returns true unless `keyword` belongs to a disabled vocabulary.
+    pub fn enables(&self, keyword: &str) -> bool {
+        use keywords::*;
+
+        match keyword {
+            ID | SCHEMA | ANCHOR | REF | RECURSIVE_REF | RECURSIVE_ANCHOR | VOCABULARY
+            | COMMENT | DEF | DEFINITIONS => self.core,
+
+            ALL_OF
+            | ANY_OF
+            | ONE_OF
+            | NOT
+            | IF
+            | THEN
+            | ELSE
+            | DEPENDENT_SCHEMAS
+            | PROPERTIES
+            | PATTERN_PROPERTIES
+            | ADDITIONAL_PROPERTIES
+            | UNEVALUATED_PROPERTIES
+            | PROPERTY_NAMES
+            | ITEMS
+            | ADDITIONAL_ITEMS
+            | UNEVALUATED_ITEMS
+            | CONTAINS => self.applicator,
+
+            TYPE | NULLABLE | CONST | ENUM | MULTIPLE_OF | MAXIMUM | EXCLUSIVE_MAXIMUM
+            | MINIMUM | EXCLUSIVE_MINIMUM | MAX_LENGTH | MIN_LENGTH | PATTERN | MAX_ITEMS
+            | MIN_ITEMS | UNIQUE_ITEMS | MAX_CONTAINS | MIN_CONTAINS | MAX_PROPERTIES
+            | MIN_PROPERTIES | REQUIRED | DEPENDENT_REQUIRED => self.validation,
+
+            FORMAT => self.format,
+
+            CONTENT_ENCODING | CONTENT_MEDIA_TYPE | CONTENT_SCHEMA => self.content,
+
+            TITLE | DESCRIPTION | DEFAULT | DEPRECATED | READ_ONLY | WRITE_ONLY | EXAMPLES
+            | EXAMPLE => self.meta_data,
+
+            _ => true,
+        }
+    }
+}
+
 struct Builder<A>
 where
     A: AnnotationBuilder,
@@ -75,6 +168,7 @@ where
     curi: url::Url,
     kw: Vec<Keyword<A>>,
     tbl: intern::Table,
+    vocabularies: Vocabularies,
 
     // "nullable" support for OpenAPI schemas prior to version 3.1,
     // which are still prevelant as of Sept 2021.
@@ -435,7 +529,7 @@ where
         // Note that it could still override with it's own $id keyword.
         let child_uri = self.curi.join(ptr.as_str()).unwrap();
 
-        let child = build_schema(child_uri, child)?;
+        let child = build_schema_with_vocabularies(child_uri, child, self.vocabularies)?;
         self.kw.push(Keyword::Application(app, child));
 
         Ok(())
@@ -444,6 +538,19 @@ where
 
 /// `build_schema` builds a Schema instance from a JSON-Schema document.
 pub fn build_schema<A>(curi: url::Url, v: &sj::Value) -> Result<Schema<A>, Error>
+where
+    A: AnnotationBuilder,
+{
+    build_schema_with_vocabularies(curi, v, Vocabularies::default())
+}
+
+/// `build_schema_with_vocabularies` builds a Schema instance from a JSON-Schema
+/// document, using only the keywords of enabled `vocabularies`.
+pub fn build_schema_with_vocabularies<A>(
+    curi: url::Url,
+    v: &sj::Value,
+    vocabularies: Vocabularies,
+) -> Result<Schema<A>, Error>
 where
     A: AnnotationBuilder,
 {
@@ -473,12 +580,15 @@ where
     // This is a schema object. We'll walk its properties and JSON values
     // to extract its applications and validations.
 
+    // Keywords of disabled vocabularies are ignored.
+    let get = |keyword: &str| obj.get(keyword).filter(|_| vocabularies.enables(keyword));
+
     let mut builder = Builder {
-        curi: build_curi(curi, obj.get(keywords::ID))?,
+        curi: build_curi(curi, get(keywords::ID))?,
         kw,
         tbl,
-        nullable: obj
-            .get(keywords::NULLABLE)
+        vocabularies,
+        nullable: get(keywords::NULLABLE)
             .and_then(|n| n.as_bool())
             .unwrap_or_default(),
     };
@@ -498,7 +608,9 @@ where
 
     let mut required = None;
     for (k, v) in obj {
-        if k == keywords::REQUIRED {
+        if !vocabularies.enables(k) {
+            continue;
+        } else if k == keywords::REQUIRED {
             required = Some(v);
             continue;
         }
@@ -722,4 +834,129 @@ mod test {
             (intern::MAX_TABLE_SIZE, 3)
         );
     }
+
+    #[test]
+    fn test_invalid_regex_fails_at_build() {
+        for (schema, expect) in [
+            (
+                serde_json::json!({"properties": {"p": {"pattern": "[hello"}}}),
+                "at keyword 'pattern' of schema 'http://example/schema#/properties/p'",
+            ),
+            (
+                serde_json::json!({"patternProperties": {"[hello": true}}),
+                "at keyword 'patternProperties' of schema 'http://example/schema'",
+            ),
+        ] {
+            let curi = url::Url::parse("http://example/schema").unwrap();
+            let err = build_schema::<CoreAnnotation>(curi, &schema).unwrap_err();
+
+            match &err {
+                super::Error::AtKeyword { detail, .. }
+                    if matches!(**detail, super::Error::RegexErr(_)) => {}
+                err => panic!("unexpected error {err:?}"),
+            }
+            assert!(err.to_string().starts_with(expect), "{err}");
+        }
+    }
+
+    #[test]
+    fn test_vocabulary_selection() {
+        use super::{build_schema_with_vocabularies, Vocabularies};
+
+        let schema = serde_json::json!({
+            "type": "object",
+            "properties": {
+                "n": {"type": "integer", "maximum": 10, "title": "A number"},
+                "s": {"type": "string", "format": "date", "minLength": 1},
+            },
+            "required": ["n"],
+        });
+        let curi = url::Url::parse("http://example/schema").unwrap();
+
+        // Summarize the sorted keywords of `schema` and its sub-schemas,
+        // as built with the given vocabularies.
+        let summarize = |vocabularies: Vocabularies| {
+            fn walk(schema: &crate::schema::Schema<CoreAnnotation>, out: &mut Vec<String>) {
+                for kw in &schema.kw {
+                    match kw {
+                        Keyword::Application(app, child) => {
+                            out.push(format!("{app:?}"));
+                            walk(child, out);
+                        }
+                        Keyword::Validation(Validation::Type(_)) => out.push("Type".to_string()),
+                        Keyword::Validation(Validation::Required { .. }) => {
+                            out.push("Required".to_string())
+                        }
+                        Keyword::Validation(val) => out.push(format!("{val:?}")),
+                        Keyword::Annotation(annotation) => out.push(format!("{annotation:?}")),
+                        kw => out.push(format!("{kw:?}")),
+                    }
+                }
+            }
+            let schema = build_schema_with_vocabularies::<CoreAnnotation>(
+                curi.clone(),
+                &schema,
+                vocabularies,
+            )
+            .unwrap();
+
+            let mut out = Vec::new();
+            walk(&schema, &mut out);
+            out.sort();
+            out
+        };
+
+        // All vocabularies are enabled by default.
+        assert_eq!(
+            summarize(Vocabularies::default()),
+            vec![
+                "Format(Date)",
+                "Format(Date)",
+                "Maximum(Unsigned(10))",
+                "MinLength(1)",
+                r#"Properties { name: "n" }"#,
+                r#"Properties { name: "s" }"#,
+                "Required",
+                r#"Title("A number")"#,
+                "Type",
+                "Type",
+                "Type",
+            ]
+        );
+
+        // Disabling the validation vocabulary ignores `type`, `maximum`, `required`, etc.
+        // The format vocabulary remains enabled, and `format` is still validated.
+        assert_eq!(
+            summarize(Vocabularies {
+                validation: false,
+                ..Default::default()
+            }),
+            vec![
+                "Format(Date)",
+                "Format(Date)",
+                r#"Properties { name: "n" }"#,
+                r#"Properties { name: "s" }"#,
+                r#"Title("A number")"#,
+            ]
+        );
+
+        // Disabling the format vocabulary ignores `format` entirely.
+        assert_eq!(
+            summarize(Vocabularies {
+                format: false,
+                ..Default::default()
+            }),
+            vec![
+                "Maximum(Unsigned(10))",
+                "MinLength(1)",
+                r#"Properties { name: "n" }"#,
+                r#"Properties { name: "s" }"#,
+                "Required",
+                r#"Title("A number")"#,
+                "Type",
+                "Type",
+                "Type",
+            ]
+        );
+    }
 }
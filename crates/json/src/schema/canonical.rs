@@ -0,0 +1,169 @@
+use serde_json::Value;
+use std::fmt::Write;
+
+/// Canonicalize a JSON Schema into compact JSON, which is stable across
+/// equivalent representations of the schema and is suitable for hashing,
+/// caching, and detecting no-op changes:
+///
+/// * Object properties are emitted in the sorted order of their keys.
+///   No JSON Schema keyword attaches meaning to the order of object
+///   properties, so this applies throughout the document, including
+///   within `properties` and `$defs`, and within instance values such
+///   as `const`, `enum`, and `default`.
+/// * Arrays retain the order of their items. Order is meaningful to keywords
+///   such as `items` (when a tuple), and to instance values of `const`, `enum`,
+///   and `examples`. Keywords whose arrays are semantically un-ordered
+///   (`type` and `required`) are *not* sorted, as doing so requires
+///   distinguishing schema keywords from instance values.
+/// * Numbers having an integral value are emitted as integers, so that
+///   `1.0`, `1e0`, and `1` are equivalent. Other numbers are emitted in
+///   their shortest representation which round-trips.
+/// * No insignificant whitespace is emitted.
+///
+/// ```
+/// use json::schema::canonical::canonicalize_schema;
+/// use serde_json::json;
+///
+/// let schema = json!({"type": "object", "maximum": 10.0, "$id": "test://example"});
+/// assert_eq!(
+///     canonicalize_schema(&schema),
+///     r#"{"$id":"test://example","maximum":10,"type":"object"}"#,
+/// );
+/// ```
+pub fn canonicalize_schema(schema: &Value) -> String {
+    let mut w = String::new();
+    write_canonical(&mut w, schema);
+    w
+}
+
+fn write_canonical(w: &mut String, value: &Value) {
+    match value {
+        Value::Null | Value::Bool(_) | Value::String(_) => {
+            w.push_str(&serde_json::to_string(value).unwrap());
+        }
+        Value::Number(num) => write_number(w, num),
+        Value::Array(items) => {
+            w.push('[');
+            for (index, item) in items.iter().enumerate() {
+                if index != 0 {
+                    w.push(',');
+                }
+                write_canonical(w, item);
+            }
+            w.push(']');
+        }
+        Value::Object(fields) => {
+            // serde_json::Map is already sorted unless the `preserve_order`
+            // feature is enabled, which we cannot rule out. Sort explicitly.
+            let mut fields: Vec<_> = fields.iter().collect();
+            fields.sort_by_key(|(property, _)| *property);
+
+            w.push('{');
+            for (index, (property, value)) in fields.into_iter().enumerate() {
+                if index != 0 {
+                    w.push(',');
+                }
+                w.push_str(&serde_json::to_string(property).unwrap());
+                w.push(':');
+                write_canonical(w, value);
+            }
+            w.push('}');
+        }
+    }
+}
+
+fn write_number(w: &mut String, num: &serde_json::Number) {
+    if num.is_u64() || num.is_i64() {
+        write!(w, "{num}").unwrap();
+        return;
+    }
+    let f = num.as_f64().unwrap();
+
+    // Integral values within the range of an i64 or u64 are written as
+    // integers, as the equal i64 or u64 would be. Note that -0.0 is also
+    // mapped to zero.
+    if f.fract() == 0.0 && f >= i64::MIN as f64 && f < i64::MAX as f64 {
+        write!(w, "{}", f as i64).unwrap();
+    } else if f.fract() == 0.0 && f >= 0.0 && f < u64::MAX as f64 {
+        write!(w, "{}", f as u64).unwrap();
+    } else {
+        write!(w, "{num}").unwrap();
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::canonicalize_schema;
+    use serde_json::json;
+
+    #[test]
+    fn test_equivalent_schemas_are_identical() {
+        let lhs: serde_json::Value = serde_json::from_str(
+            r#"{
+                "type": "object",
+                "properties": {
+                    "b": {"type": "integer", "minimum": 1.0, "maximum": 1e2},
+                    "a": {"type": ["string", "null"], "const": {"z": 1, "y": [3, 2.5]}}
+                },
+                "required": ["b", "a"]
+            }"#,
+        )
+        .unwrap();
+
+        let rhs: serde_json::Value = serde_json::from_str(
+            r#"{"required":["b","a"],"properties":{"a":{"const":{"y":[3,2.5],"z":1.0},
+                "type":["string","null"]},"b":{"maximum":100,"minimum":1,"type":"integer"}},
+                "type":"object"}"#,
+        )
+        .unwrap();
+
+        assert_eq!(canonicalize_schema(&lhs), canonicalize_schema(&rhs));
+        assert_eq!(
+            canonicalize_schema(&lhs),
+            r#"{"properties":{"a":{"const":{"y":[3,2.5],"z":1},"type":["string","null"]},"b":{"maximum":100,"minimum":1,"type":"integer"}},"required":["b","a"],"type":"object"}"#
+        );
+    }
+
+    #[test]
+    fn test_meaningful_differences_are_distinct() {
+        let cases = [
+            // Differing keyword values.
+            (json!({"maximum": 10}), json!({"maximum": 10.5})),
+            // Tuple `items` are ordered.
+            (
+                json!({"items": [{"type": "string"}, {"type": "integer"}]}),
+                json!({"items": [{"type": "integer"}, {"type": "string"}]}),
+            ),
+            // Instance values are compared exactly.
+            (json!({"const": [1, 2]}), json!({"const": [2, 1]})),
+            (json!({"const": "1"}), json!({"const": 1})),
+        ];
+
+        for (lhs, rhs) in cases {
+            assert_ne!(
+                canonicalize_schema(&lhs),
+                canonicalize_schema(&rhs),
+                "{lhs} vs {rhs}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_number_normalization() {
+        let doc: serde_json::Value = serde_json::from_str(
+            r#"[1, -1, 1.0, -0.0, 1e3, 2.5, -2.5e-3, 18446744073709551615, 1e19, 1e300]"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            canonicalize_schema(&doc),
+            "[1,-1,1,0,1000,2.5,-0.0025,18446744073709551615,10000000000000000000,1e300]"
+        );
+
+        // An integral float beyond the range of an i64 is equal to its u64.
+        assert_eq!(
+            canonicalize_schema(&json!({"maximum": 1e19})),
+            canonicalize_schema(&json!({"maximum": 10000000000000000000u64})),
+        );
+    }
+}
@@ -60,7 +60,7 @@ lazy_static::lazy_static! {
         r#"^(?:(?:[^\x00-\x20""'<>%\\^`{|}]|%[0-9a-f]{2})|\{[+#.\/;?&=,!@|]?(?:[a-z0-9_]|%[0-9a-f]{2})+(?:\:[1-9][0-9]{0,3}|\*)?(?:,(?:[a-z0-9_]|%[0-9a-f]{2})+(?:\:[1-9][0-9]{0,3}|\*)?)*\})*$"#
     )
     .expect("Is a valid regex");
-    static ref ISO_8601_DURATION_RE: Regex = Regex::new(r"^P(?!$)(\d+(?:\.\d+)?Y)?(\d+(?:\.\d+)?M)?(\d+(?:\.\d+)?W)?(\d+(?:\.\d+)?D)?(T(?=\d)(\d+(?:\.\d+)?H)?(\d+(?:\.\d+)?M)?(\d+(?:\.\d+)?S)?)?$").expect("Is a valid regex");
+    static ref ISO_8601_DURATION_RE: Regex = Regex::new(r"^P(?!$)([0-9]+(?:\.[0-9]+)?Y)?([0-9]+(?:\.[0-9]+)?M)?([0-9]+(?:\.[0-9]+)?W)?([0-9]+(?:\.[0-9]+)?D)?(T(?=[0-9])([0-9]+(?:\.[0-9]+)?H)?([0-9]+(?:\.[0-9]+)?M)?([0-9]+(?:\.[0-9]+)?S)?)?$").expect("Is a valid regex");
     static ref ISO_8601_ONLY_WEEKS_RE: Regex = Regex::new(r"^[0-9P|W]*$").expect("Is a valid regex");
     static ref ISO_8601_NO_WEEKS_RE: Regex = Regex::new(r"^[^W]*$").expect("Is a valid regex");
     static ref JSON_POINTER_RE: Regex = Regex::new(r"^(\/([^~]|(~[01]))*)*$").expect("Is a valid regex");
@@ -142,18 +142,7 @@ impl Format {
                 "{val} is the wrong length (missing hyphens?)"
             ))),
 
-            Self::Duration => ValidationResult::from(match ISO_8601_DURATION_RE.is_match(val) {
-                Ok(true) => {
-                    if val.contains("W") {
-                        // If we parse as weeks, ensure that ONLY weeks are provided
-                        ISO_8601_ONLY_WEEKS_RE.is_match(val).unwrap_or(false)
-                    } else {
-                        // Otherwise, ensure that NO weeks are provided
-                        ISO_8601_NO_WEEKS_RE.is_match(val).unwrap_or(false)
-                    }
-                }
-                _ => false,
-            }),
+            Self::Duration => ValidationResult::from(parse_duration(val)),
             Self::Iri => ValidationResult::from(iri_string::validate::iri::<IriSpec>(val)),
             Self::Uri => ValidationResult::from(iri_string::validate::iri::<UriSpec>(val)),
             Self::UriReference => {
@@ -198,11 +187,543 @@ impl Format {
     }
 }
 
+/// Duration is an ISO 8601 duration, as parsed by `parse_duration`.
+/// Weeks are folded into days, and hours and minutes into seconds.
+/// Years, months, and days are calendar-relative and are not folded.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Duration {
+    pub years: f64,
+    pub months: f64,
+    pub days: f64,
+    pub seconds: f64,
+}
+
+/// Parse an ISO 8601 duration into its components.
+/// A string which parses is exactly one which is valid under `format: "duration"`.
+pub fn parse_duration(val: &str) -> Result<Duration, String> {
+    let invalid = || format!("{val} is not a valid ISO 8601 duration");
+
+    let Ok(Some(captures)) = ISO_8601_DURATION_RE.captures(val) else {
+        return Err(invalid());
+    };
+    let weeks_ok = if val.contains('W') {
+        // If we parse as weeks, ensure that ONLY weeks are provided
+        ISO_8601_ONLY_WEEKS_RE.is_match(val).unwrap_or(false)
+    } else {
+        // Otherwise, ensure that NO weeks are provided
+        ISO_8601_NO_WEEKS_RE.is_match(val).unwrap_or(false)
+    };
+    if !weeks_ok {
+        return Err(invalid());
+    }
+
+    // Parse the numeric value of a capture group, stripping its unit designator.
+    let component = |group: usize| -> Result<f64, String> {
+        match captures.get(group) {
+            Some(m) => {
+                let m = m.as_str();
+                m[..m.len() - 1].parse().map_err(|_| invalid())
+            }
+            None => Ok(0.0),
+        }
+    };
+
+    Ok(Duration {
+        years: component(1)?,
+        months: component(2)?,
+        days: component(3)? * 7.0 + component(4)?,
+        seconds: component(6)? * 3600.0 + component(7)? * 60.0 + component(8)?,
+    })
+}
+
+/// Normalize an email address into its canonical form, suitable for use in
+/// deduplication keys. Surrounding whitespace is trimmed and the domain is
+/// lowercased, while the local part is case-sensitive and is preserved.
+/// A trimmed string which normalizes is exactly one which is valid under
+/// `format: "email"`.
+pub fn normalize_email(val: &str) -> Result<String, String> {
+    let address = parse_email_address(val.trim())
+        .map_err(|err| format!("{val} is not a valid email address: {err}"))?;
+
+    let user = address.user();
+    let domain = &address.as_str()[user.len() + 1..];
+
+    Ok(format!("{user}@{}", domain.to_lowercase()))
+}
+
+/// Resolve the RFC 6901 JSON pointer `ptr` against `doc`, returning the
+/// targeted value or None if `ptr` is invalid or doesn't exist in `doc`.
+/// An array index of `-` refers to the (nonexistent) element past the end
+/// of the array, and never resolves.
+pub fn resolve_pointer<'v>(doc: &'v serde_json::Value, ptr: &str) -> Option<&'v serde_json::Value> {
+    if !JSON_POINTER_RE.is_match(ptr).unwrap_or(false) {
+        return None;
+    }
+    doc.pointer(ptr)
+}
+
+/// Target of a resolved relative JSON pointer.
+#[derive(Debug, PartialEq)]
+pub enum RelativeTarget<'v> {
+    /// The pointer resolved to a value of the document.
+    Value(&'v serde_json::Value),
+    /// The pointer ended in `#`, and resolved to the property name of its location.
+    Name(String),
+    /// The pointer ended in `#`, and resolved to the array index of its location.
+    Index(usize),
+}
+
+/// Resolve the relative JSON pointer `rel` against `doc`, relative to
+/// the location of JSON pointer `base`. The pointer's leading integer
+/// ascends that many levels from `base`. It's then followed by either
+/// a JSON pointer to resolve from that location, or by `#` which resolves
+/// to the property name or array index of that location within its parent.
+/// Returns None if either pointer is invalid or doesn't resolve.
+pub fn resolve_relative_pointer<'v>(
+    doc: &'v serde_json::Value,
+    base: &str,
+    rel: &str,
+) -> Option<RelativeTarget<'v>> {
+    if !JSON_POINTER_RE.is_match(base).unwrap_or(false)
+        || !RELATIVE_JSON_POINTER_RE.is_match(rel).unwrap_or(false)
+    {
+        return None;
+    }
+    let split = rel.find(|c: char| !c.is_ascii_digit()).unwrap_or(rel.len());
+    let (up, rest) = (rel[..split].parse::<usize>().ok()?, &rel[split..]);
+
+    // Tokens of `base`, each of which is preceded by '/'.
+    let tokens: Vec<&str> = base.split('/').skip(1).collect();
+    let tokens = &tokens[..tokens.len().checked_sub(up)?];
+
+    let location: String = tokens.iter().map(|token| format!("/{token}")).collect();
+
+    if rest != "#" {
+        return resolve_pointer(doc, &format!("{location}{rest}")).map(RelativeTarget::Value);
+    }
+    // The root document has no name or index.
+    let (last, parent) = tokens.split_last()?;
+    let parent: String = parent.iter().map(|token| format!("/{token}")).collect();
+
+    match resolve_pointer(doc, &parent)? {
+        serde_json::Value::Array(items) => {
+            let index = last
+                .parse::<usize>()
+                .ok()
+                .filter(|index| *index < items.len())?;
+            Some(RelativeTarget::Index(index))
+        }
+        serde_json::Value::Object(fields) => {
+            let name = last.replace("~1", "/").replace("~0", "~");
+            fields
+                .contains_key(&name)
+                .then_some(RelativeTarget::Name(name))
+        }
+        _ => None,
+    }
+}
+
+/// Expand the RFC 6570 URI template `template` using variables `vars`.
+/// Templates of levels one through three are supported: simple string
+/// expansion, as well as the `+`, `#`, `.`, `/`, `;`, `?`, and `&` operators.
+/// Level four modifiers (`:prefix` and `*` explode) are an error.
+///
+/// Variables which are missing, null, or an empty array or object are
+/// undefined, and are omitted from the expansion. Booleans and numbers expand
+/// to their JSON representation, and arrays and objects expand to a
+/// comma-separated list of their items, or of their properties and values.
+pub fn expand_uri_template(
+    template: &str,
+    vars: &serde_json::Map<String, serde_json::Value>,
+) -> Result<String, String> {
+    let mut out = String::with_capacity(template.len());
+    let mut rest = template;
+
+    while let Some(begin) = rest.find('{') {
+        out.push_str(&rest[..begin]);
+
+        let Some(end) = rest[begin..].find('}') else {
+            return Err(format!(
+                "URI template '{template}' has an unclosed expression"
+            ));
+        };
+        expand_uri_expression(template, &rest[begin + 1..begin + end], vars, &mut out)?;
+        rest = &rest[begin + end + 1..];
+    }
+    if rest.contains('}') {
+        return Err(format!(
+            "URI template '{template}' has an unopened expression"
+        ));
+    }
+    out.push_str(rest);
+
+    Ok(out)
+}
+
+// Expand a single URI template `expression`, exclusive of its braces, into `out`.
+fn expand_uri_expression(
+    template: &str,
+    expression: &str,
+    vars: &serde_json::Map<String, serde_json::Value>,
+    out: &mut String,
+) -> Result<(), String> {
+    // Each operator's (operator, first, separator, named, if-empty, allow-reserved).
+    let (op, first, sep, named, if_empty, allow_reserved) = match expression.chars().next() {
+        Some('+') => ("+", "", ",", false, "", true),
+        Some('#') => ("#", "#", ",", false, "", true),
+        Some('.') => (".", ".", ".", false, "", false),
+        Some('/') => ("/", "/", "/", false, "", false),
+        Some(';') => (";", ";", ";", true, "", false),
+        Some('?') => ("?", "?", "&", true, "=", false),
+        Some('&') => ("&", "&", "&", true, "=", false),
+        _ => ("", "", ",", false, "", false),
+    };
+    let mut defined = 0;
+
+    for name in expression[op.len()..].split(',') {
+        if name.ends_with('*') || name.contains(':') {
+            return Err(format!(
+                "URI template '{template}' uses level 4 modifier '{name}', which isn't supported"
+            ));
+        }
+        if name.is_empty()
+            || !name
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '.' | '%'))
+        {
+            return Err(format!(
+                "URI template '{template}' has an invalid variable name '{name}'"
+            ));
+        }
+
+        // Build the encoded items of the variable's value, skipping it if undefined.
+        let mut items = Vec::new();
+        match vars.get(name) {
+            None | Some(serde_json::Value::Null) => continue,
+            Some(serde_json::Value::String(value)) => items.push(value.clone()),
+            Some(serde_json::Value::Array(values)) => {
+                items.extend(values.iter().map(uri_template_scalar));
+            }
+            Some(serde_json::Value::Object(fields)) => {
+                for (property, value) in fields {
+                    items.push(property.clone());
+                    items.push(uri_template_scalar(value));
+                }
+            }
+            Some(value) => items.push(value.to_string()),
+        }
+        if items.is_empty() {
+            continue; // Empty arrays and objects are undefined.
+        }
+
+        out.push_str(if defined == 0 { first } else { sep });
+        defined += 1;
+
+        if named {
+            out.push_str(name);
+
+            if items.len() == 1 && items[0].is_empty() {
+                out.push_str(if_empty);
+                continue;
+            }
+            out.push('=');
+        }
+        for (index, item) in items.iter().enumerate() {
+            if index != 0 {
+                out.push(',');
+            }
+            uri_template_encode(item, allow_reserved, out);
+        }
+    }
+    Ok(())
+}
+
+// Map a scalar item of an array or object variable to its string form.
+fn uri_template_scalar(value: &serde_json::Value) -> String {
+    match value {
+        serde_json::Value::String(value) => value.clone(),
+        serde_json::Value::Null => String::new(),
+        value => value.to_string(),
+    }
+}
+
+// Percent-encode `value` into `out`. Unreserved characters are always passed
+// through, while reserved characters and existing percent-encoded triplets
+// are passed through only if `allow_reserved`.
+fn uri_template_encode(value: &str, allow_reserved: bool, out: &mut String) {
+    use std::fmt::Write;
+
+    for (index, c) in value.char_indices() {
+        let pass = c.is_ascii_alphanumeric()
+            || matches!(c, '-' | '.' | '_' | '~')
+            || (allow_reserved && ":/?#[]@!$&'()*+,;=".contains(c))
+            || (allow_reserved
+                && c == '%'
+                && value.as_bytes()[index + 1..]
+                    .iter()
+                    .take(2)
+                    .filter(|b| b.is_ascii_hexdigit())
+                    .count()
+                    == 2);
+
+        if pass {
+            out.push(c);
+        } else {
+            for b in c.encode_utf8(&mut [0; 4]).bytes() {
+                write!(out, "%{b:02X}").unwrap();
+            }
+        }
+    }
+}
+
 #[cfg(test)]
 mod test {
-    use super::Format;
+    use super::{
+        expand_uri_template, normalize_email, parse_duration, resolve_pointer,
+        resolve_relative_pointer, Duration, Format, RelativeTarget,
+    };
     use crate::validator::ValidationResult;
 
+    #[test]
+    fn test_parse_duration() {
+        for (value, expect) in [
+            (
+                "P1Y2M",
+                Duration {
+                    years: 1.0,
+                    months: 2.0,
+                    ..Default::default()
+                },
+            ),
+            (
+                "PT30S",
+                Duration {
+                    seconds: 30.0,
+                    ..Default::default()
+                },
+            ),
+            (
+                "P1M3DT30H4.5S",
+                Duration {
+                    months: 1.0,
+                    days: 3.0,
+                    seconds: 30.0 * 3600.0 + 4.5,
+                    ..Default::default()
+                },
+            ),
+            (
+                "PT1H30M",
+                Duration {
+                    seconds: 5400.0,
+                    ..Default::default()
+                },
+            ),
+            (
+                "P2W",
+                Duration {
+                    days: 14.0,
+                    ..Default::default()
+                },
+            ),
+        ] {
+            assert_eq!(parse_duration(value), Ok(expect), "{value}");
+        }
+
+        for value in ["P1W3D", "PT", "P", "P1S", "roundtuit", "P١Y", "PT٣S"] {
+            assert_eq!(
+                parse_duration(value),
+                Err(format!("{value} is not a valid ISO 8601 duration"))
+            );
+        }
+    }
+
+    #[test]
+    fn test_normalize_email() {
+        for (value, expect) in [
+            ("john@doe.com", "john@doe.com"),
+            ("John.Doe@Example.COM", "John.Doe@example.com"),
+            ("  jane+tag@Sub.Example.org\n", "jane+tag@sub.example.org"),
+        ] {
+            assert_eq!(normalize_email(value), Ok(expect.to_string()), "{value}");
+        }
+
+        for value in ["john at doe.com", "@doe.com", "john@", ""] {
+            let err = normalize_email(value).unwrap_err();
+            assert!(
+                err.starts_with(&format!("{value} is not a valid email address: ")),
+                "{err}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_resolve_pointer() {
+        let doc = serde_json::json!({
+            "a": {"b": [10, {"c": "d"}]},
+            "e/f": 1,
+            "g~h": 2,
+            "": 3,
+        });
+
+        for (ptr, expect) in [
+            ("", Some(&doc)),
+            ("/a/b/0", Some(&doc["a"]["b"][0])),
+            ("/a/b/1/c", Some(&doc["a"]["b"][1]["c"])),
+            ("/e~1f", Some(&doc["e/f"])),
+            ("/g~0h", Some(&doc["g~h"])),
+            ("/", Some(&doc[""])),
+            // The past-end index never resolves.
+            ("/a/b/-", None),
+            // Neither do out-of-bounds or non-canonical indices.
+            ("/a/b/2", None),
+            ("/a/b/01", None),
+            ("/a/missing", None),
+            // Nor invalid pointers.
+            ("a/b", None),
+            ("/a~2", None),
+        ] {
+            assert_eq!(resolve_pointer(&doc, ptr), expect, "{ptr}");
+        }
+    }
+
+    #[test]
+    fn test_resolve_relative_pointer() {
+        let doc = serde_json::json!({
+            "foo": ["bar", "baz"],
+            "highly": {"nested": {"objects": true}},
+        });
+        let value = |ptr: &str| Some(RelativeTarget::Value(doc.pointer(ptr).unwrap()));
+
+        // Cases of the relative JSON pointer draft, from location `/foo/1`.
+        for (rel, expect) in [
+            ("0", value("/foo/1")),
+            ("1/0", value("/foo/0")),
+            ("2/highly/nested/objects", value("/highly/nested/objects")),
+            ("0#", Some(RelativeTarget::Index(1))),
+            ("1#", Some(RelativeTarget::Name("foo".to_string()))),
+            ("1/-", None),
+            ("2#", None),
+            ("3", None),
+            ("-1", None),
+        ] {
+            assert_eq!(
+                resolve_relative_pointer(&doc, "/foo/1", rel),
+                expect,
+                "{rel}"
+            );
+        }
+
+        // Cases from location `/highly/nested`.
+        for (rel, expect) in [
+            ("0/objects", value("/highly/nested/objects")),
+            ("1/nested/objects", value("/highly/nested/objects")),
+            ("2/foo/0", value("/foo/0")),
+            ("0#", Some(RelativeTarget::Name("nested".to_string()))),
+            ("1#", Some(RelativeTarget::Name("highly".to_string()))),
+        ] {
+            assert_eq!(
+                resolve_relative_pointer(&doc, "/highly/nested", rel),
+                expect,
+                "{rel}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_expand_uri_template() {
+        // Variables and cases of RFC 6570.
+        let vars = serde_json::json!({
+            "var": "value",
+            "hello": "Hello World!",
+            "half": "50%",
+            "path": "/foo/bar",
+            "empty": "",
+            "x": 1024,
+            "y": 768,
+            "list": ["red", "green", "blue"],
+            "keys": {"semi": ";"},
+            "nothing": null,
+            "none": [],
+        });
+        let vars = vars.as_object().unwrap();
+
+        for (template, expect) in [
+            // Level 1: simple string expansion.
+            ("{var}", "value"),
+            ("{hello}", "Hello%20World%21"),
+            ("{half}", "50%25"),
+            ("literal/{var}/here", "literal/value/here"),
+            // Level 2: reserved and fragment expansion.
+            ("{+var}", "value"),
+            ("{+hello}", "Hello%20World!"),
+            ("{+half}", "50%25"),
+            ("{+path}/here", "/foo/bar/here"),
+            ("here?ref={+path}", "here?ref=/foo/bar"),
+            ("X{#var}", "X#value"),
+            ("X{#hello}", "X#Hello%20World!"),
+            // Level 3: multiple variables, and remaining operators.
+            ("map?{x,y}", "map?1024,768"),
+            ("{x,hello,y}", "1024,Hello%20World%21,768"),
+            ("{+path,x}/here", "/foo/bar,1024/here"),
+            ("X{.var}", "X.value"),
+            ("X{.x,y}", "X.1024.768"),
+            ("{/var}", "/value"),
+            ("{/var,x}/here", "/value/1024/here"),
+            ("{/path}", "/%2Ffoo%2Fbar"),
+            ("{;x,y}", ";x=1024;y=768"),
+            ("{;x,y,empty}", ";x=1024;y=768;empty"),
+            ("{?x,y}", "?x=1024&y=768"),
+            ("{?x,y,empty}", "?x=1024&y=768&empty="),
+            ("?fixed=yes{&x}", "?fixed=yes&x=1024"),
+            ("{&x,y,empty}", "&x=1024&y=768&empty="),
+            // Composite values.
+            ("{list}", "red,green,blue"),
+            ("{?list}", "?list=red,green,blue"),
+            ("{/list}", "/red,green,blue"),
+            ("{keys}", "semi,%3B"),
+            ("{+keys}", "semi,;"),
+            // Missing and undefined variables are omitted.
+            ("{undef}", ""),
+            ("{nothing}", ""),
+            ("{none}", ""),
+            ("{/undef}", ""),
+            ("{/undef,var}", "/value"),
+            ("{?undef,x}", "?x=1024"),
+            ("{?undef}", ""),
+            ("X{.undef}Y", "XY"),
+        ] {
+            assert_eq!(
+                expand_uri_template(template, vars).as_deref(),
+                Ok(expect),
+                "{template}"
+            );
+        }
+
+        for (template, expect) in [
+            ("{var", "URI template '{var' has an unclosed expression"),
+            ("var}", "URI template 'var}' has an unopened expression"),
+            ("{}", "URI template '{}' has an invalid variable name ''"),
+            (
+                "{va r}",
+                "URI template '{va r}' has an invalid variable name 'va r'",
+            ),
+            (
+                "{var:3}",
+                "URI template '{var:3}' uses level 4 modifier 'var:3', which isn't supported",
+            ),
+            (
+                "{/list*}",
+                "URI template '{/list*}' uses level 4 modifier 'list*', which isn't supported",
+            ),
+        ] {
+            assert_eq!(
+                expand_uri_template(template, vars).unwrap_err(),
+                expect,
+                "{template}"
+            );
+        }
+    }
+
     #[test]
     fn test_format_cases() {
         // Missing format cases:
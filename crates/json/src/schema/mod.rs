@@ -3,6 +3,7 @@ use serde_json as sj;
 use std::fmt::{Write, Display};
 
 pub mod build;
+pub mod canonical;
 pub mod formats;
 pub mod index;
 pub mod intern;
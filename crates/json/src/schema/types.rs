@@ -19,6 +19,12 @@ pub const INT_OR_FRAC: Set = Set(INTEGER.0 | FRACTIONAL.0);
 // ANY is a composite for all possible types.
 pub const ANY: Set =
     Set(ARRAY.0 | BOOLEAN.0 | FRACTIONAL.0 | INTEGER.0 | NULL.0 | OBJECT.0 | STRING.0);
+// BIG_NUMBER flags a number which cannot be represented as an f64 without a
+// loss of precision. It's not a type: it's never named by `Set::iter`, isn't
+// part of ANY, and is only produced by `Set::for_value_precise`.
+pub const BIG_NUMBER: Set = Set(0b10000000);
+// VALID is every bit which may be set in a well-formed Set.
+const VALID: Set = Set(ANY.0 | BIG_NUMBER.0);
 
 impl std::ops::BitOr for Set {
     type Output = Self;
@@ -49,6 +55,7 @@ impl std::ops::Sub for Set {
 
 /// Iterator that returns the type names for all of the types in a `Set`.
 /// You get this iterator by calling `Set::iter`.
+/// The BIG_NUMBER flag isn't a type, and isn't named by this iterator.
 pub struct Iter {
     types: Set,
     index: usize,
@@ -131,6 +138,17 @@ impl Set {
     /// let mut iter = ty.iter();
     /// assert!(iter.next().is_none());
     /// ```
+    ///
+    /// A Set having both INTEGER and FRACTIONAL is named by the single
+    /// "number" type, rather than by redundant "integer" and "number" types:
+    ///
+    /// ```
+    /// use json::schema::types::*;
+    ///
+    /// assert_eq!(vec!["number"], INT_OR_FRAC.to_type_names());
+    /// assert_eq!(vec!["integer", "string"], (INTEGER | STRING).to_type_names());
+    /// assert_eq!(vec!["number", "string"], (INTEGER | FRACTIONAL | STRING).to_type_names());
+    /// ```
     pub fn iter(&self) -> Iter {
         Iter {
             types: *self,
@@ -138,6 +156,25 @@ impl Set {
         }
     }
 
+    /// Returns an iterator over the type names as JSON string values,
+    /// in the stable order of `Set::iter`.
+    ///
+    /// ```
+    /// use json::schema::types::*;
+    /// use serde_json::Value;
+    ///
+    /// let ty = NULL | INT_OR_FRAC | STRING;
+    ///
+    /// assert_eq!(
+    ///     ty.iter().map(|name| Value::String(name.to_string())).collect::<Vec<_>>(),
+    ///     ty.iter_json().collect::<Vec<_>>(),
+    /// );
+    /// assert!(INVALID.iter_json().next().is_none());
+    /// ```
+    pub fn iter_json(&self) -> impl Iterator<Item = Value> {
+        self.iter().map(Value::from)
+    }
+
     /// Returns a vec containing owned strings representing the types in this set.
     ///
     /// ```
@@ -153,10 +190,79 @@ impl Set {
         self.iter().map(String::from).collect()
     }
 
+    /// Returns the type names of this Set, in the stable order of `Set::iter`.
+    ///
+    /// ```
+    /// use json::schema::types::*;
+    ///
+    /// assert_eq!(vec!["null", "number"], (NULL | INT_OR_FRAC).to_type_names());
+    /// assert!(INVALID.to_type_names().is_empty());
+    /// ```
+    pub fn to_type_names(&self) -> Vec<&'static str> {
+        self.iter().collect()
+    }
+
+    /// Returns a JSON array of the type names of this Set,
+    /// in the stable order of `Set::iter`.
+    ///
+    /// ```
+    /// use json::schema::types::*;
+    /// use serde_json::json;
+    ///
+    /// assert_eq!(json!(["object", "string"]), (OBJECT | STRING).to_value_array());
+    /// assert_eq!(json!([]), INVALID.to_value_array());
+    /// ```
+    pub fn to_value_array(&self) -> Value {
+        Value::Array(self.iter_json().collect())
+    }
+
     pub fn to_json_array(&self) -> String {
         format!("[{}]", self)
     }
 
+    /// Returns the value of a JSON schema `type` keyword which represents this Set,
+    /// or None if the keyword should be omitted altogether.
+    ///
+    /// A Set having exactly one type is represented as a single string, and
+    /// other Sets as an array of strings. `ANY` permits every type, and is
+    /// idiomatically represented by omitting the keyword rather than listing
+    /// every type. `INVALID` permits no types and is represented as an empty
+    /// array, which is a sentinel for an unsatisfiable schema: no instance
+    /// can match it. Callers which require a schema that's also valid
+    /// under the meta-schema should instead emit a `false` schema.
+    ///
+    /// JSON schema has no "fractional" type, so FRACTIONAL is widened to
+    /// "number" (which also permits integers) rather than emitting a
+    /// keyword that validators would reject.
+    ///
+    /// ```
+    /// use json::schema::types::*;
+    /// use serde_json::json;
+    ///
+    /// assert_eq!(None, ANY.to_type_keyword());
+    /// assert_eq!(Some(json!("string")), STRING.to_type_keyword());
+    /// assert_eq!(Some(json!("number")), INT_OR_FRAC.to_type_keyword());
+    /// assert_eq!(Some(json!(["null", "string"])), (STRING | NULL).to_type_keyword());
+    /// assert_eq!(Some(json!([])), INVALID.to_type_keyword());
+    /// assert_eq!(Some(json!(["null", "number"])), (FRACTIONAL | NULL).to_type_keyword());
+    /// ```
+    pub fn to_type_keyword(&self) -> Option<Value> {
+        // BIG_NUMBER isn't a type, and doesn't change the keyword.
+        let ty = *self - BIG_NUMBER;
+        let ty = if ty.overlaps(FRACTIONAL) {
+            ty | INTEGER
+        } else {
+            ty
+        };
+        if ty == ANY {
+            return None;
+        }
+        match ty.to_value_array() {
+            Value::Array(mut names) if names.len() == 1 => names.pop(),
+            names => Some(names),
+        }
+    }
+
     /// Returns the `Set` value for a single type with the given name.
     ///
     /// ```
@@ -184,6 +290,62 @@ impl Set {
         }
     }
 
+    /// Returns the union Set of proto type names, such as the `types` of a
+    /// `flow::Inference`, or an error naming the first unknown type name.
+    ///
+    /// ```
+    /// use json::schema::types::*;
+    ///
+    /// let types = vec!["string".to_string(), "null".to_string()];
+    /// assert_eq!(Ok(STRING | NULL), Set::from_proto_types(&types));
+    /// assert_eq!(Ok(INVALID), Set::from_proto_types(&[]));
+    /// ```
+    pub fn from_proto_types(types: &[String]) -> Result<Set, String> {
+        types.iter().try_fold(INVALID, |set, name| {
+            Self::for_type_name(name)
+                .map(|ty| set | ty)
+                .ok_or_else(|| format!("unknown type name {name:?}"))
+        })
+    }
+
+    /// Returns the union of `sets`, or INVALID if `sets` is empty.
+    ///
+    /// ```
+    /// use json::schema::types::*;
+    ///
+    /// assert_eq!(STRING | NULL | INTEGER, Set::union_all([STRING, NULL, INTEGER | NULL]));
+    /// assert_eq!(INVALID, Set::union_all([]));
+    /// ```
+    pub fn union_all(sets: impl IntoIterator<Item = Set>) -> Set {
+        sets.into_iter().fold(INVALID, |l, r| l | r)
+    }
+
+    /// Returns the intersection of `sets`, or ANY if `sets` is empty.
+    ///
+    /// ```
+    /// use json::schema::types::*;
+    ///
+    /// assert_eq!(NULL, Set::intersect_all([STRING | NULL, INTEGER | NULL]));
+    /// assert_eq!(INVALID, Set::intersect_all([STRING, INTEGER]));
+    /// assert_eq!(ANY, Set::intersect_all([]));
+    /// ```
+    pub fn intersect_all(sets: impl IntoIterator<Item = Set>) -> Set {
+        sets.into_iter().fold(ANY, |l, r| l & r)
+    }
+
+    /// Returns the Set of the type of `val`. Per JSON Schema, a number
+    /// having a zero fractional part is an INTEGER, even if it's written
+    /// with a decimal point (`1.0`), while other numbers are FRACTIONAL.
+    /// See `for_value_strict` and `for_value_lenient` for alternatives.
+    ///
+    /// ```
+    /// use json::schema::types::*;
+    /// use serde_json::json;
+    ///
+    /// assert_eq!(INTEGER, Set::for_value(&json!(1)));
+    /// assert_eq!(INTEGER, Set::for_value(&json!(1.0)));
+    /// assert_eq!(FRACTIONAL, Set::for_value(&json!(1.5)));
+    /// ```
     pub fn for_value(val: &Value) -> Set {
         match val {
             Value::Array(_) => ARRAY,
@@ -195,6 +357,84 @@ impl Set {
         }
     }
 
+    /// Returns the Set of `val`, as does `for_value`, with the addition of
+    /// BIG_NUMBER if `val` is an integer of magnitude greater than 2^53,
+    /// which cannot be represented as an f64 without a loss of precision.
+    /// Other numbers have already been parsed as f64.
+    ///
+    /// ```
+    /// use json::schema::types::*;
+    /// use serde_json::json;
+    ///
+    /// assert_eq!(INTEGER | BIG_NUMBER, Set::for_value_precise(&json!(9007199254740993u64)));
+    /// assert_eq!(INTEGER | BIG_NUMBER, Set::for_value_precise(&json!(-9007199254740993i64)));
+    /// assert_eq!(INTEGER, Set::for_value_precise(&json!(9007199254740992u64)));
+    /// assert_eq!(FRACTIONAL, Set::for_value_precise(&json!(1.5)));
+    /// assert_eq!(STRING, Set::for_value_precise(&json!("9007199254740993")));
+    ///
+    /// // BIG_NUMBER isn't a type name.
+    /// assert_eq!(vec!["integer"], (INTEGER | BIG_NUMBER).to_type_names());
+    /// ```
+    pub fn for_value_precise(val: &Value) -> Set {
+        const MAX_SAFE_INTEGER: u64 = 1 << 53;
+
+        let big = match val {
+            Value::Number(num) => match Number::from(num) {
+                Number::Unsigned(n) => n > MAX_SAFE_INTEGER,
+                Number::Signed(n) => n.unsigned_abs() > MAX_SAFE_INTEGER,
+                Number::Float(_) => false,
+            },
+            _ => false,
+        };
+
+        if big {
+            Self::for_value(val) | BIG_NUMBER
+        } else {
+            Self::for_value(val)
+        }
+    }
+
+    /// Returns the Set of `val`, as does `for_value`, except that a number
+    /// which is written as a float is always FRACTIONAL, even if it has
+    /// a zero fractional part.
+    ///
+    /// ```
+    /// use json::schema::types::*;
+    /// use serde_json::json;
+    ///
+    /// assert_eq!(INTEGER, Set::for_value_strict(&json!(1)));
+    /// assert_eq!(FRACTIONAL, Set::for_value_strict(&json!(1.0)));
+    /// assert_eq!(FRACTIONAL, Set::for_value_strict(&json!(1.5)));
+    /// ```
+    pub fn for_value_strict(val: &Value) -> Set {
+        match val {
+            Value::Number(num) if num.is_f64() => FRACTIONAL,
+            _ => Self::for_value(val),
+        }
+    }
+
+    /// Returns the Set of `val`, as does `for_value`, except that a float
+    /// having a zero fractional part is both INTEGER and FRACTIONAL,
+    /// as it's accepted by either of the "integer" or "number" types.
+    ///
+    /// ```
+    /// use json::schema::types::*;
+    /// use serde_json::json;
+    ///
+    /// assert_eq!(INTEGER, Set::for_value_lenient(&json!(1)));
+    /// assert_eq!(INT_OR_FRAC, Set::for_value_lenient(&json!(1.0)));
+    /// assert_eq!(FRACTIONAL, Set::for_value_lenient(&json!(1.5)));
+    /// ```
+    pub fn for_value_lenient(val: &Value) -> Set {
+        match val {
+            Value::Number(num) if num.is_f64() => match Self::for_value(val) {
+                INTEGER => INT_OR_FRAC,
+                set => set,
+            },
+            _ => Self::for_value(val),
+        }
+    }
+
     pub fn for_number(num: &Number) -> Set {
         match num {
             // The json schema spec says that the "integer" type must match
@@ -207,11 +447,67 @@ impl Set {
         }
     }
 
+    /// Returns the compact bit representation of this Set, which is stable
+    /// and may be persisted. Restore it with `Set::from_bits`.
+    ///
+    /// ```
+    /// use json::schema::types::*;
+    ///
+    /// let ty = STRING | NULL;
+    /// assert_eq!(0b1010000, ty.bits());
+    /// assert_eq!(Some(ty), Set::from_bits(ty.bits()));
+    /// ```
+    #[inline]
+    pub fn bits(&self) -> u32 {
+        self.0
+    }
+
+    /// Returns the Set of the compact bit representation `bits`,
+    /// or None if `bits` has a bit set which is outside of ANY and BIG_NUMBER.
+    ///
+    /// ```
+    /// use json::schema::types::*;
+    ///
+    /// assert_eq!(Some(INVALID), Set::from_bits(0));
+    /// assert_eq!(Some(ANY), Set::from_bits(ANY.bits()));
+    /// assert_eq!(Some(INTEGER | BIG_NUMBER), Set::from_bits((INTEGER | BIG_NUMBER).bits()));
+    /// assert!(Set::from_bits((STRING | NULL).bits() | 1 << 31).is_none());
+    /// ```
+    pub fn from_bits(bits: u32) -> Option<Set> {
+        if bits & !VALID.0 == 0 {
+            Some(Set(bits))
+        } else {
+            None
+        }
+    }
+
     #[inline]
     pub fn overlaps(&self, other: Self) -> bool {
         *self & other != INVALID
     }
 
+    /// Returns true if the type of `value` is permitted by this Set.
+    /// Numbers with a fractional part are FRACTIONAL and are not accepted
+    /// by a Set of only INTEGER, while numbers without one (including
+    /// floats like `5.0`) are INTEGER and are accepted.
+    ///
+    /// ```
+    /// use json::schema::types::*;
+    /// use serde_json::json;
+    ///
+    /// assert!(INTEGER.accepts(&json!(5)));
+    /// assert!(INTEGER.accepts(&json!(5.0)));
+    /// assert!(!INTEGER.accepts(&json!(5.5)));
+    /// assert!(INT_OR_FRAC.accepts(&json!(5.5)));
+    /// assert!((STRING | NULL).accepts(&json!(null)));
+    /// assert!(!(STRING | NULL).accepts(&json!(5)));
+    /// assert!(ANY.accepts(&json!({"a": 1})));
+    /// assert!(!INVALID.accepts(&json!(true)));
+    /// ```
+    pub fn accepts(&self, value: &Value) -> bool {
+        self.overlaps(Set::for_value(value))
+    }
+
     /// Returns true if this Set represents exactly one type beside null.
     ///
     /// ```
@@ -293,12 +589,71 @@ impl Set {
             _ => false,
         }
     }
+
+    /// Returns true if this Set includes at least one type,
+    /// and every type it includes is a scalar (including null).
+    ///
+    /// ```
+    /// use json::schema::types::*;
+    ///
+    /// assert!((STRING | NULL).is_scalar());
+    /// assert!((BOOLEAN | INT_OR_FRAC | STRING).is_scalar());
+    /// assert!(NULL.is_scalar());
+    ///
+    /// assert!(!OBJECT.is_scalar());
+    /// assert!(!(ARRAY | NULL).is_scalar());
+    /// assert!(!(STRING | OBJECT).is_scalar());
+    /// assert!(!INVALID.is_scalar());
+    /// ```
+    pub fn is_scalar(&self) -> bool {
+        *self != INVALID && !self.overlaps(OBJECT | ARRAY)
+    }
+
+    /// Returns true if null is the only type of this Set.
+    ///
+    /// ```
+    /// use json::schema::types::*;
+    ///
+    /// assert!(NULL.requires_null());
+    /// assert!(!(STRING | NULL).requires_null());
+    /// assert!(!STRING.requires_null());
+    /// assert!(!INVALID.requires_null());
+    /// ```
+    pub fn requires_null(&self) -> bool {
+        *self == NULL
+    }
+
+    /// Returns true if null is among the types of this Set.
+    ///
+    /// ```
+    /// use json::schema::types::*;
+    ///
+    /// assert!(NULL.allows_null());
+    /// assert!((STRING | NULL).allows_null());
+    /// assert!(ANY.allows_null());
+    /// assert!(!STRING.allows_null());
+    /// assert!(!INVALID.allows_null());
+    /// ```
+    pub fn allows_null(&self) -> bool {
+        self.overlaps(NULL)
+    }
 }
 
 impl fmt::Debug for Set {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         use itertools::Itertools;
-        write!(f, "{:?}", self.iter().format(", "))
+        write!(f, "{:?}", self.iter().format(", "))?;
+
+        // BIG_NUMBER isn't a type name and isn't iterated, so name it here.
+        if self.overlaps(BIG_NUMBER) {
+            write!(f, " (big number)")?;
+        }
+        // Other bits outside of ANY would otherwise be invisible.
+        // They're never produced by Set operations, and indicate a corrupted Set.
+        if self.0 & !VALID.0 != 0 {
+            write!(f, " (raw={:#b})", self.0)?;
+        }
+        Ok(())
     }
 }
 
@@ -444,6 +799,32 @@ mod test {
         assert_eq!(STRING, serde_json::from_str("\"string\"").unwrap());
     }
 
+    #[test]
+    fn set_to_type_keyword() {
+        use serde_json::json;
+
+        // ANY omits the keyword.
+        assert_eq!(None, ANY.to_type_keyword());
+        assert_eq!(None, (ANY | BIG_NUMBER).to_type_keyword());
+        // A single type is a scalar string.
+        assert_eq!(Some(json!("string")), STRING.to_type_keyword());
+        assert_eq!(Some(json!("integer")), INTEGER.to_type_keyword());
+        assert_eq!(
+            Some(json!("integer")),
+            (INTEGER | BIG_NUMBER).to_type_keyword()
+        );
+        // FRACTIONAL isn't a JSON schema type, and is widened to "number".
+        assert_eq!(Some(json!("number")), FRACTIONAL.to_type_keyword());
+        assert_eq!(None, (ANY - INTEGER).to_type_keyword());
+        // Multiple types which aren't ANY are an array.
+        assert_eq!(
+            Some(json!(["array", "null", "number", "object", "string"])),
+            (ANY - BOOLEAN).to_type_keyword()
+        );
+        // INVALID is the unsatisfiable empty array.
+        assert_eq!(Some(json!([])), INVALID.to_type_keyword());
+    }
+
     #[test]
     fn set_deserialize_returns_error_when_null_is_unquoted() {
         let err = serde_json::from_str::<Set>(r#"["string", null]"#)
@@ -507,4 +888,58 @@ mod test {
             &serde_json::to_string(&(NULL | INTEGER)).unwrap()
         );
     }
+
+    #[test]
+    fn set_debug_flags_stray_bits() {
+        assert_eq!(format!("{:?}", STRING | NULL), r#""null", "string""#);
+
+        let corrupt = Set(STRING.0 | 1 << 10);
+        assert_eq!(Set::from_bits(corrupt.0), None);
+        assert_eq!(format!("{corrupt:?}"), r#""string" (raw=0b10001000000)"#);
+
+        let big = INTEGER | BIG_NUMBER;
+        assert_eq!(Set::from_bits(big.0), Some(big));
+        assert_eq!(format!("{big:?}"), r#""integer" (big number)"#);
+    }
+
+    #[test]
+    fn set_from_proto_types() {
+        let types = |names: &[&str]| names.iter().map(|n| n.to_string()).collect::<Vec<_>>();
+
+        assert_eq!(
+            Ok(STRING | NULL),
+            Set::from_proto_types(&types(&["string", "null"]))
+        );
+        assert_eq!(
+            Ok(INT_OR_FRAC | NULL),
+            Set::from_proto_types(&types(&["null", "number", "integer"]))
+        );
+        assert_eq!(
+            Err(r#"unknown type name "strung""#.to_string()),
+            Set::from_proto_types(&types(&["string", "strung", "nope"]))
+        );
+    }
+
+    #[test]
+    fn set_for_value_of_integral_floats() {
+        use serde_json::json;
+
+        for (value, default, strict, lenient) in [
+            (json!(1), INTEGER, INTEGER, INTEGER),
+            (json!(-3), INTEGER, INTEGER, INTEGER),
+            (json!(1.0), INTEGER, FRACTIONAL, INT_OR_FRAC),
+            (json!(-3.0), INTEGER, FRACTIONAL, INT_OR_FRAC),
+            (json!(1.5), FRACTIONAL, FRACTIONAL, FRACTIONAL),
+            (json!("1.0"), STRING, STRING, STRING),
+        ] {
+            assert_eq!(default, Set::for_value(&value), "{value}");
+            assert_eq!(strict, Set::for_value_strict(&value), "{value}");
+            assert_eq!(lenient, Set::for_value_lenient(&value), "{value}");
+        }
+
+        // Integral floats parsed from JSON text are classified the same way.
+        let value: serde_json::Value = serde_json::from_str("1.0").unwrap();
+        assert_eq!(FRACTIONAL, Set::for_value_strict(&value));
+        assert_eq!(INT_OR_FRAC, Set::for_value_lenient(&value));
+    }
 }
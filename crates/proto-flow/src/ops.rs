@@ -176,6 +176,10 @@ pub struct Stats {
     >,
     #[prost(message, optional, tag = "9")]
     pub interval: ::core::option::Option<stats::Interval>,
+    /// Bytes spilled to disk by the task's combiner, upon its in-memory
+    /// documents exceeding the task's spill threshold.
+    #[prost(uint64, tag = "10")]
+    pub spilled_bytes_total: u64,
 }
 /// Nested message and enum types in `Stats`.
 pub mod stats {
@@ -201,6 +205,9 @@ pub mod stats {
         pub right: ::core::option::Option<DocsAndBytes>,
         #[prost(message, optional, tag = "3")]
         pub out: ::core::option::Option<DocsAndBytes>,
+        /// Documents of a capture which were skipped because they were invalid.
+        #[prost(message, optional, tag = "4")]
+        pub skipped: ::core::option::Option<DocsAndBytes>,
     }
     /// Derivation metrics.
     #[allow(clippy::derive_partial_eq_without_eq)]
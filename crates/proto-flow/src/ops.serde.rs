@@ -772,6 +772,9 @@ impl serde::Serialize for Stats {
         if self.interval.is_some() {
             len += 1;
         }
+        if self.spilled_bytes_total != 0 {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("ops.Stats", len)?;
         if let Some(v) = self.meta.as_ref() {
             struct_ser.serialize_field("_meta", v)?;
@@ -800,6 +803,9 @@ impl serde::Serialize for Stats {
         if let Some(v) = self.interval.as_ref() {
             struct_ser.serialize_field("interval", v)?;
         }
+        if self.spilled_bytes_total != 0 {
+            struct_ser.serialize_field("spilledBytesTotal", &self.spilled_bytes_total)?;
+        }
         struct_ser.end()
     }
 }
@@ -823,6 +829,8 @@ impl<'de> serde::Deserialize<'de> for Stats {
             "derive",
             "materialize",
             "interval",
+            "spilled_bytes_total",
+            "spilledBytesTotal",
         ];
 
         #[allow(clippy::enum_variant_names)]
@@ -836,6 +844,7 @@ impl<'de> serde::Deserialize<'de> for Stats {
             Derive,
             Materialize,
             Interval,
+            SpilledBytesTotal,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -866,6 +875,7 @@ impl<'de> serde::Deserialize<'de> for Stats {
                             "derive" => Ok(GeneratedField::Derive),
                             "materialize" => Ok(GeneratedField::Materialize),
                             "interval" => Ok(GeneratedField::Interval),
+                            "spilledBytesTotal" | "spilled_bytes_total" => Ok(GeneratedField::SpilledBytesTotal),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -894,6 +904,7 @@ impl<'de> serde::Deserialize<'de> for Stats {
                 let mut derive__ = None;
                 let mut materialize__ = None;
                 let mut interval__ = None;
+                let mut spilled_bytes_total__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
                         GeneratedField::Meta => {
@@ -958,6 +969,14 @@ impl<'de> serde::Deserialize<'de> for Stats {
                             }
                             interval__ = map_.next_value()?;
                         }
+                        GeneratedField::SpilledBytesTotal => {
+                            if spilled_bytes_total__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("spilledBytesTotal"));
+                            }
+                            spilled_bytes_total__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
                     }
                 }
                 Ok(Stats {
@@ -970,6 +989,7 @@ impl<'de> serde::Deserialize<'de> for Stats {
                     derive: derive__,
                     materialize: materialize__.unwrap_or_default(),
                     interval: interval__,
+                    spilled_bytes_total: spilled_bytes_total__.unwrap_or_default(),
                 })
             }
         }
@@ -993,6 +1013,9 @@ impl serde::Serialize for stats::Binding {
         if self.out.is_some() {
             len += 1;
         }
+        if self.skipped.is_some() {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("ops.Stats.Binding", len)?;
         if let Some(v) = self.left.as_ref() {
             struct_ser.serialize_field("left", v)?;
@@ -1003,6 +1026,9 @@ impl serde::Serialize for stats::Binding {
         if let Some(v) = self.out.as_ref() {
             struct_ser.serialize_field("out", v)?;
         }
+        if let Some(v) = self.skipped.as_ref() {
+            struct_ser.serialize_field("skipped", v)?;
+        }
         struct_ser.end()
     }
 }
@@ -1016,6 +1042,7 @@ impl<'de> serde::Deserialize<'de> for stats::Binding {
             "left",
             "right",
             "out",
+            "skipped",
         ];
 
         #[allow(clippy::enum_variant_names)]
@@ -1023,6 +1050,7 @@ impl<'de> serde::Deserialize<'de> for stats::Binding {
             Left,
             Right,
             Out,
+            Skipped,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -1047,6 +1075,7 @@ impl<'de> serde::Deserialize<'de> for stats::Binding {
                             "left" => Ok(GeneratedField::Left),
                             "right" => Ok(GeneratedField::Right),
                             "out" => Ok(GeneratedField::Out),
+                            "skipped" => Ok(GeneratedField::Skipped),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -1069,6 +1098,7 @@ impl<'de> serde::Deserialize<'de> for stats::Binding {
                 let mut left__ = None;
                 let mut right__ = None;
                 let mut out__ = None;
+                let mut skipped__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
                         GeneratedField::Left => {
@@ -1089,12 +1119,19 @@ impl<'de> serde::Deserialize<'de> for stats::Binding {
                             }
                             out__ = map_.next_value()?;
                         }
+                        GeneratedField::Skipped => {
+                            if skipped__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("skipped"));
+                            }
+                            skipped__ = map_.next_value()?;
+                        }
                     }
                 }
                 Ok(stats::Binding {
                     left: left__,
                     right: right__,
                     out: out__,
+                    skipped: skipped__,
                 })
             }
         }
@@ -162,6 +162,8 @@ pub struct CaptureRequestExt {
     pub rocksdb_descriptor: ::core::option::Option<RocksDbDescriptor>,
     #[prost(message, optional, tag = "3")]
     pub start_commit: ::core::option::Option<capture_request_ext::StartCommit>,
+    #[prost(message, optional, tag = "4")]
+    pub open: ::core::option::Option<capture_request_ext::Open>,
 }
 /// Nested message and enum types in `CaptureRequestExt`.
 pub mod capture_request_ext {
@@ -174,6 +176,103 @@ pub mod capture_request_ext {
             ::proto_gazette::consumer::Checkpoint,
         >,
     }
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct Open {
+        /// Should the runtime error if a binding's `state_key` is not populated,
+        /// rather than computing it from the binding's resource path?
+        #[prost(bool, tag = "1")]
+        pub strict_state_keys: bool,
+        /// Should the runtime discard its persisted connector state, and open the
+        /// connector with only the `state_json` of the Open request?
+        #[prost(bool, tag = "2")]
+        pub reset_state: bool,
+        /// Number of committed checkpoints which are batched into a single
+        /// Acknowledge of the connector. Zero is treated as one.
+        #[prost(uint32, tag = "3")]
+        pub ack_batch_size: u32,
+        /// Milliseconds spent blocked sending a transaction to the client, beyond
+        /// which a back-pressure warning is logged. Zero disables the warning.
+        #[prost(uint64, tag = "4")]
+        pub client_blocked_threshold_ms: u64,
+        /// Should read checkpoints be committed before a connector error is surfaced?
+        #[prost(bool, tag = "5")]
+        pub drain_on_error: bool,
+        /// Should commits be suppressed? For debugging only.
+        #[prost(bool, tag = "6")]
+        pub dry_commit: bool,
+        /// Milliseconds without a commit, after which an idle connector commits
+        /// an empty heartbeat transaction. Zero disables heartbeats.
+        #[prost(uint64, tag = "7")]
+        pub idle_heartbeat_ms: u64,
+        /// Number of consecutive commits without a Shape update, upon which a
+        /// binding's inferred Shape is stable. Zero uses the runtime default.
+        #[prost(uint64, tag = "8")]
+        pub inference_stable_after: u64,
+        /// Maximum milliseconds of a capture session, after which it drains,
+        /// commits, and restarts. Zero is unlimited.
+        #[prost(uint64, tag = "9")]
+        pub max_run_duration_ms: u64,
+        /// Maximum bytes of a connector state update. Zero is unlimited.
+        #[prost(uint64, tag = "10")]
+        pub max_state_bytes: u64,
+        /// Milliseconds for which the connector may produce no checkpoints
+        /// before the session restarts. Zero is unlimited.
+        #[prost(uint64, tag = "11")]
+        pub poll_timeout_ms: u64,
+        /// Milliseconds over which the restarts of the task's shards are spread.
+        #[prost(uint64, tag = "12")]
+        pub restart_jitter_ms: u64,
+        /// Fraction of captured documents, in \[0, 1\], which are sampled for debugging.
+        #[prost(double, tag = "13")]
+        pub sample_rate: f64,
+        /// Should invalid captured documents be skipped rather than failing?
+        #[prost(bool, tag = "14")]
+        pub skip_invalid: bool,
+        /// Bytes of memory which a combiner MemTable may use before it's spilled.
+        /// Zero uses the combiner's default.
+        #[prost(uint64, tag = "15")]
+        pub spill_threshold_bytes: u64,
+        /// Serialization policy of connector state updates. If unset, state
+        /// updates are not truncated.
+        #[prost(message, optional, tag = "16")]
+        pub state_ser_policy: ::core::option::Option<super::super::flow::SerPolicy>,
+        /// Should captured documents be validated as they're read?
+        #[prost(bool, tag = "17")]
+        pub validate_captured: bool,
+        /// Options of the capture's bindings, index-aligned with the bindings
+        /// of its CaptureSpec. Bindings beyond its length use default options.
+        #[prost(message, repeated, tag = "18")]
+        pub bindings: ::prost::alloc::vec::Vec<open::Binding>,
+    }
+    /// Nested message and enum types in `Open`.
+    pub mod open {
+        #[allow(clippy::derive_partial_eq_without_eq)]
+        #[derive(Clone, PartialEq, ::prost::Message)]
+        pub struct Binding {
+            /// Serialization policy of the binding's captured documents.
+            /// If unset, documents are not truncated.
+            #[prost(message, optional, tag = "1")]
+            pub ser_policy: ::core::option::Option<super::super::super::flow::SerPolicy>,
+            /// JSON pointers of captured document locations which are retained,
+            /// in addition to key and partition locations. If empty, captured
+            /// documents are retained in their entirety.
+            #[prost(string, repeated, tag = "2")]
+            pub projection: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+            /// JSON pointer by which drained documents are sorted, and then by key.
+            /// If empty, drained documents are in key order.
+            #[prost(string, tag = "3")]
+            pub sort_ptr: ::prost::alloc::string::String,
+            /// Should captured documents bypass the combiner, being sent to the
+            /// client in the order in which they were captured?
+            #[prost(bool, tag = "4")]
+            pub passthrough: bool,
+            /// May the connector supply the packed partition values of its
+            /// captured documents?
+            #[prost(bool, tag = "5")]
+            pub connector_partitions: bool,
+        }
+    }
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
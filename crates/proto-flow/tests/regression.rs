@@ -710,6 +710,7 @@ fn ex_stats() -> ops::Stats {
                     docs_total: 1,
                     bytes_total: 100,
                 }),
+                skipped: None,
             },
         )]
         .into(),
@@ -761,6 +762,7 @@ fn ex_stats() -> ops::Stats {
                     docs_total: 3,
                     bytes_total: 300,
                 }),
+                skipped: None,
             },
         )]
         .into(),
@@ -768,6 +770,7 @@ fn ex_stats() -> ops::Stats {
             uptime_seconds: 300,
             usage_rate: 1.5,
         }),
+        spilled_bytes_total: 1024,
     }
 }
 
@@ -16,30 +16,121 @@ impl<T: Stream<Item = anyhow::Result<Response>> + Send + 'static> ResponseStream
 
 #[derive(Debug, Clone)]
 pub struct Task {
+    // Number of committed checkpoints which are batched into a single
+    // Acknowledge of the connector. One acknowledges every transaction.
+    ack_batch_size: u32,
     // Bindings of this task.
     bindings: Vec<Binding>,
+    // Instant at which the connector last produced a checkpoint,
+    // or at which this Task was opened if it hasn't.
+    checkpointed_at: tokio::time::Instant,
+    // Time spent blocked sending a transaction to the client, beyond which
+    // a warning is logged that the client is applying back-pressure.
+    client_blocked_threshold: Option<std::time::Duration>,
+    // Instant at which a transaction was last polled as ready to commit,
+    // or at which this Task was opened if one hasn't been.
+    committed_at: tokio::time::Instant,
+    // When the connector fails after one or more checkpoints of a transaction
+    // have been fully read, should those checkpoints be committed before the
+    // connector error is surfaced?
+    drain_on_error: bool,
+    // Should commits be suppressed? Transactions are read, combined, and sent
+    // to the client as usual, but neither connector state nor the runtime
+    // checkpoint is written to RocksDB. This is intended for debugging only.
+    dry_commit: bool,
     // Does the capture connector want explicit acknowledgements?
     explicit_acknowledgements: bool,
+    // Duration without a commit, after which a running but idle connector
+    // commits an empty heartbeat transaction. Heartbeats advance the runtime
+    // checkpoint without captured documents or connector state. If None,
+    // an idle connector doesn't commit.
+    idle_heartbeat: Option<std::time::Duration>,
+    // Number of consecutive commits in which a binding captured documents
+    // without updating its inferred Shape, upon which the Shape is stable.
+    inference_stable_after: u64,
+    // Maximum duration of this capture session, after which the connector
+    // is no longer read and the session drains, commits, and restarts.
+    max_run_duration: Option<std::time::Duration>,
+    // Maximum size of a connector state update, in bytes, beyond which the
+    // checkpoint fails the transaction rather than being persisted.
+    max_state_bytes: Option<usize>,
+    // Instant at which this Task was opened.
+    opened_at: tokio::time::Instant,
+    // Maximum duration for which the connector may produce no checkpoints,
+    // after which the session restarts as though the connector had exited.
+    poll_timeout: Option<std::time::Duration>,
     // Instant at which this Task is eligible for restart.
     restart: tokio::time::Instant,
+    // Window over which the restarts of the task's shards are spread, so that
+    // shards which EOF together don't all restart at once. Each shard delays
+    // its restart by a fixed offset within the window, derived from its ShardRef.
+    restart_jitter: Option<std::time::Duration>,
+    // Fraction of captured documents, in [0, 1], which are copied into the
+    // Transaction's tap for debugging. Zero disables sampling. The tap is a
+    // side-channel which doesn't alter how documents are combined and committed.
+    sample_rate: f64,
     // ShardRef of this task.
     shard_ref: ops::ShardRef,
+    // Should captured documents which cannot be parsed be skipped and counted
+    // rather than failing the transaction? Documents of combined bindings which
+    // fail validation are also skipped if `validate_captured` is set, as they're
+    // then validated as they're read. Validation failures as documents are
+    // combined or drained, or as passthrough documents are sent, remain errors.
+    skip_invalid: bool,
+    // Bytes of memory which a combiner MemTable may use before it's spilled
+    // to disk. If None, the combiner's default threshold is used.
+    spill_threshold: Option<usize>,
+    // Policy by which merged connector state updates are serialized.
+    // This is a no-op by default, as a truncated state may not be resumable.
+    state_ser_policy: doc::SerPolicy,
+    // Should captured documents of combined bindings be validated against their
+    // write schema as they're read, rather than only as they're combined or drained?
+    validate_captured: bool,
 }
 
 #[derive(Debug, Clone)]
 struct Binding {
     // Target collection.
     collection_name: String,
+    // Instant at which a reduction of this binding's inferred Shape by the
+    // complexity limit was last logged, if it has been in this session.
+    complexity_warned_at: Option<tokio::time::Instant>,
+    // May the connector supply the packed partition values of its captured
+    // documents? If so, connector-supplied partitions take precedence over
+    // those extracted from documents, which remain the fallback.
+    connector_partitions: bool,
     // JSON pointer at which document UUIDs are added.
     document_uuid_ptr: doc::Pointer,
+    // Pointers of key and partition locations, which are always retained
+    // when captured documents are projected.
+    extracted_ptrs: Vec<doc::Pointer>,
     // Key components which are extracted from written documents.
     key_extractors: Vec<doc::Extractor>,
+    // Time at which this binding last captured a document, if it has in this session.
+    last_document_at: Option<std::time::SystemTime>,
     // Partition values which are extracted from written documents.
     partition_extractors: Vec<doc::Extractor>,
+    // Should captured documents bypass the combiner? Passthrough documents are
+    // not reduced or de-duplicated by key, and are sent to the client in the
+    // exact order in which they were captured. They're still validated, have
+    // keys and partitions extracted, and contribute to statistics and inference.
+    // Unlike combined documents, they're held in memory and never spilled.
+    passthrough: bool,
+    // Pointers of captured documents which are retained, with all other
+    // locations being dropped before documents are combined. If None,
+    // captured documents are retained in their entirety.
+    projection: Option<Vec<doc::Pointer>>,
     // Specification of this binding.
     resource_path: Vec<String>,
     // Serialization policy for the Target collection.
     ser_policy: doc::SerPolicy,
+    // Extractor of a secondary sort location. If set, drained documents of
+    // this binding are re-ordered by their extracted sort value (and then by
+    // key) before they're sent, within runs of at most SORT_BUFFER_BYTES.
+    sort_extractor: Option<doc::Extractor>,
+    // Number of consecutive commits in which this binding captured documents
+    // without updating its inferred Shape.
+    stable_inference_commits: u64,
     // Write schema of the target collection.
     write_schema_json: String,
 }
@@ -50,12 +141,32 @@ pub struct Transaction {
     captured_bytes: usize,
     // Number of connector checkpoints rolled up in this transaction.
     checkpoints: u32,
+    // Time spent blocked awaiting the client's consumption of drained responses.
+    client_blocked: std::time::Duration,
+    // Bindings whose inferred Shape was reduced by the complexity limit,
+    // and their (prior, reduced) complexity.
+    complexity_limited: BTreeMap<u32, (usize, usize)>,
     // The connector instance exited at the completion of this transaction.
     connector_eof: bool,
+    // Error of the connector, deferred until this transaction has committed.
+    connector_error: Option<anyhow::Error>,
+    // Connector-supplied packed partitions of combined documents, keyed on
+    // their (binding, packed key). Where documents of a key are combined,
+    // the last-supplied partitions of the key are used.
+    connector_partitions: BTreeMap<(u32, bytes::Bytes), bytes::Bytes>,
+    // Time of the last captured document of each binding.
+    last_document_at: BTreeMap<u32, std::time::SystemTime>,
     // Time of first connector Captured or Checkpoint response.
     started_at: std::time::SystemTime,
-    // Statistics of (read documents, combined documents) for each binding.
-    stats: BTreeMap<u32, (DocsAndBytes, DocsAndBytes)>,
+    // Statistics of (read documents, combined documents, skipped documents) for each binding.
+    stats: BTreeMap<u32, (DocsAndBytes, DocsAndBytes, DocsAndBytes)>,
+    // Documents of passthrough bindings, in the order they were captured,
+    // with their connector-supplied packed partitions (if any).
+    passthrough: Vec<(u32, serde_json::Value, Option<bytes::Bytes>)>,
+    // Sampled copies of captured documents, as (binding, document JSON).
+    sampled: Vec<(u32, String)>,
+    // Bytes spilled to disk by the combiner while reading this transaction.
+    spilled_bytes: u64,
     // Set of bindings which updated their inferred Shape this transaction.
     updated_inferences: BTreeSet<usize>,
 }
@@ -75,15 +186,132 @@ const LONG_POLL_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(5)
 // documents and checkpoints will not be folded into the transaction.
 const COMBINER_BYTE_THRESHOLD: usize = 1 << 25; // 32MB.
 
+// SORT_BUFFER_BYTES bounds the documents of a binding having a sort extractor
+// which are buffered in memory, so that they may be sorted before they're sent.
+// Upon reaching this threshold the buffered run is sorted and sent, and
+// further documents begin a new run. Runs are individually sorted,
+// but a binding with more than one run isn't sorted overall.
+const SORT_BUFFER_BYTES: usize = 1 << 24; // 16MB.
+
+// COMPLEXITY_WARNING_INTERVAL is the minimum interval between logged warnings
+// that a binding's inferred Shape was reduced by the complexity limit.
+// Once a binding reaches the limit, it's likely to be reduced again by many
+// subsequent transactions.
+const COMPLEXITY_WARNING_INTERVAL: std::time::Duration = std::time::Duration::from_secs(300);
+
+// INFERENCE_STABLE_AFTER is the default number of consecutive commits in which
+// a binding captures documents without updating its inferred Shape, after
+// which the inferred schema is considered to be stable.
+const INFERENCE_STABLE_AFTER: u64 = 100;
+
 impl Transaction {
     pub fn new() -> Self {
         Self {
             captured_bytes: 0,
             checkpoints: 0,
+            client_blocked: std::time::Duration::ZERO,
+            complexity_limited: Default::default(),
             connector_eof: false,
+            connector_error: None,
+            connector_partitions: Default::default(),
+            last_document_at: Default::default(),
+            passthrough: Vec::new(),
+            sampled: Vec::new(),
+            spilled_bytes: 0,
             started_at: std::time::SystemTime::UNIX_EPOCH,
             stats: Default::default(),
             updated_inferences: Default::default(),
         }
     }
+
+    /// Snapshot the statistics of this Transaction thus far, aggregated
+    /// by collection. The Transaction is not modified, and a snapshot may
+    /// be taken at any time, such as while it's being read.
+    pub fn stats_snapshot(&self, task: &Task) -> ops::Stats {
+        let mut capture = BTreeMap::<String, ops::stats::Binding>::new();
+
+        for (index, binding_stats) in self.stats.iter() {
+            let index = *index as usize;
+            let entry = capture
+                .entry(task.bindings[index].collection_name.clone())
+                .or_default();
+
+            ops::merge_docs_and_bytes(&binding_stats.0, &mut entry.right);
+            ops::merge_docs_and_bytes(&binding_stats.1, &mut entry.out);
+            ops::merge_docs_and_bytes(&binding_stats.2, &mut entry.skipped);
+        }
+
+        ops::Stats {
+            capture,
+            derive: None,
+            interval: None,
+            materialize: Default::default(),
+            meta: Some(ops::Meta {
+                uuid: crate::UUID_PLACEHOLDER.to_string(),
+            }),
+            // The wall-clock may step backwards during a transaction, in which
+            // case its elapsed duration saturates to zero rather than failing.
+            open_seconds_total: self.started_at.elapsed().unwrap_or_default().as_secs_f64(),
+            shard: Some(task.shard_ref.clone()),
+            spilled_bytes_total: self.spilled_bytes,
+            timestamp: Some(proto_flow::as_timestamp(self.started_at)),
+            txn_count: 1,
+        }
+    }
+}
+
+// SortBuffer re-orders the drained documents of bindings having a sort
+// extractor, buffering a bounded run of documents of a binding which is
+// sorted before it's sent. Documents of other bindings (and connector state)
+// pass through unchanged, in their drained order.
+#[derive(Debug, Default)]
+pub struct SortBuffer {
+    // Binding of the current run.
+    binding: Option<u32>,
+    // Bytes of document JSON within the current run.
+    bytes: usize,
+    // Documents of the current run, and their packed sort values.
+    run: Vec<(bytes::Bytes, Response)>,
+}
+
+impl SortBuffer {
+    /// Push the Response of a drained document having the packed `sort_value`,
+    /// appending Responses which are ready to be sent to `ready`. Documents
+    /// without a sort value are ready immediately, after any current run.
+    pub fn push(
+        &mut self,
+        sort_value: Option<bytes::Bytes>,
+        response: Response,
+        ready: &mut Vec<Response>,
+    ) {
+        let Some(sort_value) = sort_value else {
+            self.flush(ready);
+            ready.push(response);
+            return;
+        };
+        // A run holds documents of only a single binding.
+        let binding = response.captured.as_ref().map(|captured| captured.binding);
+        if self.binding != binding {
+            self.flush(ready);
+            self.binding = binding;
+        }
+        self.bytes += response
+            .captured
+            .as_ref()
+            .map(|captured| captured.doc_json.len())
+            .unwrap_or_default();
+        self.run.push((sort_value, response));
+
+        if self.bytes >= SORT_BUFFER_BYTES {
+            self.flush(ready);
+        }
+    }
+
+    /// Sort the current run and append it to `ready`. Documents having equal
+    /// sort values retain their drained order, which is by key.
+    pub fn flush(&mut self, ready: &mut Vec<Response>) {
+        self.run.sort_by(|(l, _), (r, _)| l.cmp(r));
+        ready.extend(self.run.drain(..).map(|(_, response)| response));
+        self.bytes = 0;
+    }
 }
@@ -7,7 +7,7 @@ use proto_flow::flow;
 use proto_flow::runtime::{
     capture_request_ext,
     capture_response_ext::{self, PollResult},
-    CaptureRequestExt,
+    CaptureRequestExt, CaptureResponseExt,
 };
 use std::collections::BTreeMap;
 
@@ -83,6 +83,13 @@ pub fn recv_connector_unary(request: Request, response: Response) -> anyhow::Res
 }
 
 pub async fn recv_client_open(open: &mut Request, db: &RocksDB) -> anyhow::Result<()> {
+    // Other options of the extension are applied by Task::new.
+    let capture_request_ext::Open {
+        strict_state_keys,
+        reset_state,
+        ..
+    } = open.get_internal()?.open.unwrap_or_default();
+
     let Some(open) = open.open.as_mut() else {
         return verify("client", "Open").fail(open);
     };
@@ -90,17 +97,35 @@ pub async fn recv_client_open(open: &mut Request, db: &RocksDB) -> anyhow::Resul
         return verify("client", "Open.Capture").fail(open);
     };
 
-    open.state_json = db
-        .load_connector_state(
-            models::RawValue::from_str(&open.state_json)
-                .context("failed to parse initial open connector state")?,
-        )
-        .await?
-        .into();
+    let initial = models::RawValue::from_str(&open.state_json)
+        .context("failed to parse initial open connector state")?;
+
+    // When resetting, the persisted state of every binding is discarded and the
+    // connector starts from only the `state_json` of the request. This is
+    // independent of binding `backfill` counters, which continue to be encoded
+    // into each binding's `state_key` below.
+    open.state_json = if reset_state {
+        db.reset_connector_state(initial).await?
+    } else {
+        db.load_connector_state(initial).await?
+    }
+    .into();
 
-    // TODO(johnny): Switch to erroring if `state_key` is not already populated.
-    for binding in capture.bindings.iter_mut() {
-        binding.state_key = assemble::encode_state_key(&binding.resource_path, binding.backfill);
+    // In strict mode, each binding must arrive with its `state_key` populated.
+    // Otherwise it's computed from the binding's resource path, which may change
+    // the key of a binding which was populated differently.
+    // TODO(johnny): Make strict mode the default once all clients populate `state_key`.
+    for (index, binding) in capture.bindings.iter_mut().enumerate() {
+        if !strict_state_keys {
+            binding.state_key =
+                assemble::encode_state_key(&binding.resource_path, binding.backfill);
+        } else if binding.state_key.is_empty() {
+            anyhow::bail!(
+                "binding {index} of capture {} (resource path {:?}) is missing its state_key",
+                capture.name,
+                binding.resource_path,
+            );
+        }
     }
 
     Ok(())
@@ -126,8 +151,8 @@ pub async fn recv_connector_opened(
     let shapes = task.binding_shapes_by_index(std::mem::take(shapes_by_key));
 
     // Create a pair of accumulators. While one is draining, the other is accumulating.
-    let a1 = doc::combine::Accumulator::new(task.combine_spec()?, tempfile::tempfile()?)?;
-    let a2 = doc::combine::Accumulator::new(task.combine_spec()?, tempfile::tempfile()?)?;
+    let a1 = task.new_accumulator()?;
+    let a2 = task.new_accumulator()?;
 
     let checkpoint = db.load_checkpoint().await?;
 
@@ -143,14 +168,28 @@ pub async fn recv_connector_opened(
 pub fn send_client_poll_result(
     buf: &mut bytes::BytesMut,
     task: &Task,
-    txn: &Transaction,
+    txn: &mut Transaction,
 ) -> (bool, Response) {
     let poll_result = if txn.checkpoints != 0 {
         PollResult::Ready
-    } else if txn.connector_eof && !task.restart.elapsed().is_zero() {
+    } else if txn.connector_eof && task.restart_eligible() {
         PollResult::Restart
     } else if txn.connector_eof {
         PollResult::CoolOff
+    } else if task.poll_timed_out() {
+        tracing::warn!(
+            poll_timeout = ?task.poll_timeout,
+            "capture connector produced no checkpoints within its poll timeout and will restart",
+        );
+        PollResult::Restart
+    } else if task.heartbeat_due() {
+        tracing::debug!(
+            idle_heartbeat = ?task.idle_heartbeat,
+            "capture connector is idle; committing a heartbeat transaction",
+        );
+        // A heartbeat has no connector checkpoint to mark its start.
+        txn.started_at = std::time::SystemTime::now();
+        PollResult::Ready
     } else {
         PollResult::NotReady
     };
@@ -170,10 +209,20 @@ pub fn send_client_poll_result(
     )
 }
 
-pub fn send_connector_acknowledge(last_checkpoints: &mut u32, task: &Task) -> Option<Request> {
-    if *last_checkpoints != 0 && task.explicit_acknowledgements {
-        let checkpoints = *last_checkpoints;
-        *last_checkpoints = 0; // Reset.
+// Acknowledge `unacked_checkpoints` to the connector once they reach the
+// Task's `ack_batch_size`, or regardless of batch size if the connector is
+// `idle` and has no transaction ready (it may be awaiting our acknowledgement).
+pub fn send_connector_acknowledge(
+    unacked_checkpoints: &mut u32,
+    idle: bool,
+    task: &Task,
+) -> Option<Request> {
+    if *unacked_checkpoints != 0
+        && task.explicit_acknowledgements
+        && (idle || *unacked_checkpoints >= task.ack_batch_size)
+    {
+        let checkpoints = *unacked_checkpoints;
+        *unacked_checkpoints = 0; // Reset.
 
         Some(Request {
             acknowledge: Some(request::Acknowledge { checkpoints }),
@@ -198,7 +247,7 @@ pub fn send_client_captured_or_checkpoint(
 
     if index == task.bindings.len() {
         // This is a merged checkpoint state update.
-        let updated_json = serde_json::to_string(&doc::SerPolicy::noop().on_owned(&root)).unwrap();
+        let updated_json = serde_json::to_string(&task.state_ser_policy.on_owned(&root)).unwrap();
 
         tracing::debug!(
             state=%updated_json,
@@ -218,8 +267,17 @@ pub fn send_client_captured_or_checkpoint(
 
     let binding = &task.bindings[index];
     let key_packed = doc::Extractor::extract_all_owned(&root, &binding.key_extractors, buf);
-    let partitions_packed =
-        doc::Extractor::extract_all_owned(&root, &binding.partition_extractors, buf);
+
+    // Partitions supplied by the connector take precedence over extracted ones.
+    let supplied = if binding.connector_partitions {
+        txn.connector_partitions
+            .remove(&(index as u32, key_packed.clone()))
+    } else {
+        None
+    };
+    let partitions_packed = supplied.unwrap_or_else(|| {
+        doc::Extractor::extract_all_owned(&root, &binding.partition_extractors, buf)
+    });
     let doc_json = serde_json::to_string(&binding.ser_policy.on_owned(&root))
         .expect("document serialization cannot fail");
 
@@ -228,11 +286,7 @@ pub fn send_client_captured_or_checkpoint(
     stats.bytes_total += doc_json.len() as u64;
 
     if shapes[index].widen_owned(&root) {
-        doc::shape::limits::enforce_shape_complexity_limit(
-            &mut shapes[index],
-            doc::shape::limits::DEFAULT_SCHEMA_COMPLEXITY_LIMIT,
-        );
-        txn.updated_inferences.insert(index);
+        enforce_complexity_limit(&mut shapes[index], index, txn);
     }
 
     Response {
@@ -250,36 +304,96 @@ pub fn send_client_captured_or_checkpoint(
     })
 }
 
-pub fn send_client_final_checkpoint(
+/// Returns the packed sort value of a `drained` document,
+/// or None if its binding doesn't have a sort extractor.
+pub fn drained_sort_value(
     buf: &mut bytes::BytesMut,
+    drained: &doc::combine::DrainedDoc,
     task: &Task,
-    txn: &Transaction,
-) -> Response {
-    let mut capture = BTreeMap::<String, ops::stats::Binding>::new();
+) -> Option<bytes::Bytes> {
+    let extractor = task
+        .bindings
+        .get(drained.meta.binding())?
+        .sort_extractor
+        .as_ref()?;
 
-    for (index, binding_stats) in txn.stats.iter() {
-        let index = *index as usize;
-        let entry = capture
-            .entry(task.bindings[index].collection_name.clone())
-            .or_default();
+    Some(doc::Extractor::extract_all_owned(
+        &drained.root,
+        std::slice::from_ref(extractor),
+        buf,
+    ))
+}
 
-        ops::merge_docs_and_bytes(&binding_stats.0, &mut entry.right);
-        ops::merge_docs_and_bytes(&binding_stats.1, &mut entry.out);
+pub fn send_client_passthrough(
+    buf: &mut bytes::BytesMut,
+    index: u32,
+    doc: serde_json::Value,
+    supplied_partitions: Option<bytes::Bytes>,
+    shapes: &mut [doc::Shape],
+    task: &Task,
+    txn: &mut Transaction,
+    validators: &mut [Option<doc::Validator>],
+) -> anyhow::Result<Response> {
+    let index = index as usize;
+    let binding = &task.bindings[index];
+
+    // Passthrough documents bypass the combiner, which would otherwise validate them.
+    let validator = validators[index]
+        .as_mut()
+        .expect("passthrough bindings have a validator");
+    validator.validate(None, &doc)?.ok().map_err(|err| {
+        doc::combine::Error::FailedValidation(
+            format!("captured collection {}", binding.collection_name),
+            err,
+        )
+    })?;
+
+    let key_packed = doc::Extractor::extract_all(&doc, &binding.key_extractors, buf);
+    let partitions_packed = supplied_partitions
+        .unwrap_or_else(|| doc::Extractor::extract_all(&doc, &binding.partition_extractors, buf));
+    let doc_json = serde_json::to_string(&binding.ser_policy.on(&doc))
+        .expect("document serialization cannot fail");
+
+    let stats = &mut txn.stats.entry(index as u32).or_default().1;
+    stats.docs_total += 1;
+    stats.bytes_total += doc_json.len() as u64;
+
+    if shapes[index].widen(&doc) {
+        enforce_complexity_limit(&mut shapes[index], index, txn);
     }
 
-    let stats = ops::Stats {
-        capture,
-        derive: None,
-        interval: None,
-        materialize: Default::default(),
-        meta: Some(ops::Meta {
-            uuid: crate::UUID_PLACEHOLDER.to_string(),
+    Ok(Response {
+        captured: Some(response::Captured {
+            binding: index as u32,
+            doc_json,
         }),
-        open_seconds_total: txn.started_at.elapsed().unwrap().as_secs_f64(),
-        shard: Some(task.shard_ref.clone()),
-        timestamp: Some(proto_flow::as_timestamp(txn.started_at)),
-        txn_count: 1,
-    };
+        ..Default::default()
+    }
+    .with_internal_buf(buf, |internal| {
+        internal.captured = Some(capture_response_ext::Captured {
+            key_packed,
+            partitions_packed,
+        });
+    }))
+}
+
+// Enforce the complexity limit of the widened, inferred Shape of binding `index`.
+fn enforce_complexity_limit(shape: &mut doc::Shape, index: usize, txn: &mut Transaction) {
+    if let Some(reduction) = doc::shape::limits::enforce_shape_complexity_limit(
+        shape,
+        doc::shape::limits::DEFAULT_SCHEMA_COMPLEXITY_LIMIT,
+    ) {
+        txn.complexity_limited.insert(index as u32, reduction);
+    }
+    txn.updated_inferences.insert(index);
+}
+
+pub fn send_client_final_checkpoint(
+    buf: &mut bytes::BytesMut,
+    task: &Task,
+    txn: &Transaction,
+) -> Response {
+    let stats = txn.stats_snapshot(task);
 
     Response {
         checkpoint: Some(response::Checkpoint { state: None }),
@@ -316,6 +430,14 @@ pub async fn recv_client_start_commit(
         return verify.fail(request);
     };
 
+    if task.dry_commit {
+        tracing::warn!(
+            checkpoints = txn.checkpoints,
+            "dry_commit is set: NOT committing the capture transaction, and connector state and the runtime checkpoint will not advance",
+        );
+        return Ok(());
+    }
+
     // Add the runtime checkpoint to our WriteBatch.
     tracing::debug!(
         checkpoint=?ops::DebugJson(&runtime_checkpoint),
@@ -355,40 +477,144 @@ pub fn send_client_started_commit() -> Response {
 
 pub fn recv_connector_captured(
     accumulator: &mut doc::combine::Accumulator,
-    captured: response::Captured,
+    response: Response,
     task: &Task,
     txn: &mut Transaction,
 ) -> anyhow::Result<()> {
-    let response::Captured { binding, doc_json } = captured;
-
-    let memtable = accumulator.memtable()?;
-    let alloc = memtable.alloc();
-
-    let mut doc = memtable
-        .parse_json_str(&doc_json)
-        .context("couldn't parse captured document as JSON")?;
+    let verify = verify("connector", "Captured");
+    let Some(response::Captured { binding, doc_json }) = response.captured else {
+        return verify.fail(response);
+    };
 
-    let uuid_ptr = &task
+    let task_binding = task
         .bindings
         .get(binding as usize)
-        .with_context(|| "invalid captured binding {binding}")?
-        .document_uuid_ptr;
+        .with_context(|| "invalid captured binding {binding}")?;
+    let uuid_ptr = &task_binding.document_uuid_ptr;
 
-    if !uuid_ptr.0.is_empty() {
-        if let Some(node) = uuid_ptr.create_heap_node(&mut doc, alloc) {
-            *node = doc::HeapNode::String(doc::BumpStr::from_str(crate::UUID_PLACEHOLDER, alloc));
+    let supplied_partitions = if task_binding.connector_partitions {
+        recv_connector_partitions(response.internal, task_binding)
+            .with_context(|| format!("invalid partitions of captured binding {binding}"))?
+    } else {
+        None
+    };
+
+    let parsed: anyhow::Result<()> = if task_binding.passthrough {
+        match serde_json::from_str::<serde_json::Value>(&doc_json) {
+            Ok(doc) => {
+                let mut doc = task_binding.project_value(doc);
+
+                if !uuid_ptr.0.is_empty() {
+                    if let Some(node) = uuid_ptr.create_value(&mut doc) {
+                        *node = serde_json::Value::String(crate::UUID_PLACEHOLDER.to_string());
+                    }
+                }
+                txn.passthrough.push((binding, doc, supplied_partitions));
+                Ok(())
+            }
+            Err(err) => Err(err).context("couldn't parse captured document as JSON"),
         }
-    }
-    memtable.add(binding, doc, false)?;
+    } else {
+        let memtable = accumulator.memtable()?;
+        let alloc = memtable.alloc();
+
+        match memtable.parse_json_str(&doc_json) {
+            Ok(doc) => {
+                let mut doc = task_binding.project_heap_node(doc, memtable);
+
+                if !uuid_ptr.0.is_empty() {
+                    if let Some(node) = uuid_ptr.create_heap_node(&mut doc, alloc) {
+                        *node = doc::HeapNode::String(doc::BumpStr::from_str(
+                            crate::UUID_PLACEHOLDER,
+                            alloc,
+                        ));
+                    }
+                }
+                // Passthrough documents are instead validated as they're sent.
+                let valid = match task.validate_captured {
+                    true => memtable.validate(binding, &doc),
+                    false => Ok(()),
+                };
+                if valid.is_ok() {
+                    if let Some(partitions_packed) = supplied_partitions {
+                        let key_packed = doc::Extractor::extract_all(
+                            &doc,
+                            &task_binding.key_extractors,
+                            &mut bytes::BytesMut::new(),
+                        );
+                        txn.connector_partitions
+                            .insert((binding, key_packed), partitions_packed);
+                    }
+                    memtable.add(binding, doc, false)?;
+                }
+                valid.map_err(anyhow::Error::new)
+            }
+            Err(err) => Err(err).context("couldn't parse captured document as JSON"),
+        }
+    };
 
     let stats = txn.stats.entry(binding).or_default();
+
+    match parsed {
+        Ok(()) => (),
+        Err(error) if task.skip_invalid => {
+            tracing::warn!(
+                ?error,
+                collection = %task_binding.collection_name,
+                bytes = doc_json.len(),
+                "skipping invalid captured document",
+            );
+            stats.2.docs_total += 1;
+            stats.2.bytes_total += doc_json.len() as u64;
+            return Ok(());
+        }
+        Err(error) => return Err(error),
+    }
+
+    let sample = task.should_sample(stats.0.docs_total);
     stats.0.docs_total += 1;
     stats.0.bytes_total += doc_json.len() as u64;
 
     txn.captured_bytes += doc_json.len();
+    txn.last_document_at
+        .insert(binding, std::time::SystemTime::now());
+
+    if sample {
+        txn.sampled.push((binding, doc_json));
+    }
     Ok(())
 }
 
+// Decode the packed partitions which the connector supplied in the internal
+// extension of its Captured response, if any. Supplied partitions must have
+// a component for each partition field of the binding.
+fn recv_connector_partitions(
+    internal: bytes::Bytes,
+    binding: &super::Binding,
+) -> anyhow::Result<Option<bytes::Bytes>> {
+    let Some(capture_response_ext::Captured {
+        partitions_packed, ..
+    }) = CaptureResponseExt::decode(internal)?.captured
+    else {
+        return Ok(None);
+    };
+    if partitions_packed.is_empty() {
+        return Ok(None);
+    }
+
+    let unpacked: Vec<tuple::Element> =
+        tuple::unpack(&partitions_packed).context("couldn't unpack supplied partitions")?;
+    if unpacked.len() != binding.partition_extractors.len() {
+        anyhow::bail!(
+            "connector supplied {} partition values, but collection {} has {} partition fields",
+            unpacked.len(),
+            binding.collection_name,
+            binding.partition_extractors.len(),
+        );
+    }
+    Ok(Some(partitions_packed))
+}
+
 pub fn recv_connector_checkpoint(
     accumulator: &mut doc::combine::Accumulator,
     response: Response,
@@ -404,6 +630,15 @@ pub fn recv_connector_checkpoint(
         merge_patch,
     } = state;
 
+    if let Some(max) = task.max_state_bytes {
+        if updated_json.len() > max {
+            anyhow::bail!(
+                "connector checkpoint state update of {} bytes exceeds the maximum of {max} bytes",
+                updated_json.len(),
+            );
+        }
+    }
+
     let memtable = accumulator.memtable()?;
     let doc = memtable
         .parse_json_str(&updated_json)
@@ -418,3 +653,1787 @@ pub fn recv_connector_checkpoint(
     txn.checkpoints += 1;
     Ok(())
 }
+
+#[cfg(test)]
+mod test {
+    use super::super::{
+        serve::{read_transaction, yield_to_client},
+        SortBuffer, Task, COMPLEXITY_WARNING_INTERVAL, LONG_POLL_TIMEOUT,
+    };
+    use super::*;
+    use futures::channel::oneshot;
+    use futures::StreamExt;
+    use std::time::Duration;
+
+    // Build a Task fixture having `bindings` bindings, each keyed on `/id`.
+    fn task_fixture(bindings: usize) -> Task {
+        task_fixture_with(bindings, Default::default())
+    }
+
+    // Build a Task fixture as task_fixture does, opened with options `ext`.
+    fn task_fixture_with(bindings: usize, ext: capture_request_ext::Open) -> Task {
+        let bindings = (0..bindings)
+            .map(|index| flow::capture_spec::Binding {
+                resource_path: vec![format!("resource-{index}")],
+                collection: Some(flow::CollectionSpec {
+                    name: format!("acmeCo/collection-{index}"),
+                    key: vec!["/id".to_string()],
+                    projections: vec![flow::Projection {
+                        ptr: "/id".to_string(),
+                        field: "id".to_string(),
+                        is_primary_key: true,
+                        inference: Some(Default::default()),
+                        ..Default::default()
+                    }],
+                    uuid_ptr: "/_meta/uuid".to_string(),
+                    write_schema_json: "{}".to_string(),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            })
+            .collect();
+
+        let open = Request {
+            open: Some(request::Open {
+                capture: Some(flow::CaptureSpec {
+                    name: "acmeCo/capture".to_string(),
+                    bindings,
+                    ..Default::default()
+                }),
+                range: Some(flow::RangeSpec {
+                    key_begin: 0,
+                    key_end: u32::MAX,
+                    r_clock_begin: 0,
+                    r_clock_end: u32::MAX,
+                }),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
+        .with_internal(|internal| internal.open = Some(ext));
+        let opened = Response {
+            opened: Some(response::Opened {
+                explicit_acknowledgements: false,
+            }),
+            ..Default::default()
+        };
+
+        Task::new(&open, &opened).unwrap()
+    }
+
+    fn accumulator_fixture(task: &Task) -> doc::combine::Accumulator {
+        task.new_accumulator().unwrap()
+    }
+
+    fn captured(binding: u32, doc_json: &str) -> Response {
+        Response {
+            captured: Some(response::Captured {
+                binding,
+                doc_json: doc_json.to_string(),
+            }),
+            ..Default::default()
+        }
+    }
+
+    fn checkpoint(updated_json: &str) -> Response {
+        Response {
+            checkpoint: Some(response::Checkpoint {
+                state: Some(flow::ConnectorState {
+                    updated_json: updated_json.to_string(),
+                    merge_patch: true,
+                }),
+            }),
+            ..Default::default()
+        }
+    }
+
+    fn poll_result(response: &Response) -> PollResult {
+        response
+            .get_internal()
+            .unwrap()
+            .checkpoint
+            .unwrap()
+            .poll_result()
+    }
+
+    // Drain `accumulator` and commit its transaction to `db`,
+    // returning the client responses which were produced.
+    async fn drain_and_commit(
+        accumulator: doc::combine::Accumulator,
+        db: &RocksDB,
+        shapes: &mut [doc::Shape],
+        task: &Task,
+        txn: &mut Transaction,
+    ) -> Vec<Response> {
+        let mut buf = bytes::BytesMut::new();
+        txn.spilled_bytes = accumulator.spilled_bytes();
+        let mut drainer = accumulator.into_drainer().unwrap();
+        let mut wb = rocksdb::WriteBatch::default();
+        let mut responses = Vec::new();
+        let mut validators = task.passthrough_validators().unwrap();
+
+        for (binding, doc, partitions) in std::mem::take(&mut txn.passthrough) {
+            responses.push(
+                send_client_passthrough(
+                    &mut buf,
+                    binding,
+                    doc,
+                    partitions,
+                    shapes,
+                    task,
+                    txn,
+                    &mut validators,
+                )
+                .unwrap(),
+            );
+        }
+
+        let mut sorter = SortBuffer::default();
+        while let Some(drained) = drainer.drain_next().unwrap() {
+            let sort_value = drained_sort_value(&mut buf, &drained, task);
+            let response =
+                send_client_captured_or_checkpoint(&mut buf, drained, shapes, task, txn, &mut wb);
+            sorter.push(sort_value, response, &mut responses);
+        }
+        sorter.flush(&mut responses);
+        responses.push(send_client_final_checkpoint(&mut buf, task, txn));
+
+        let start_commit = Request::default().with_internal(|internal| {
+            internal.start_commit = Some(capture_request_ext::StartCommit {
+                runtime_checkpoint: Some(Default::default()),
+            });
+        });
+        recv_client_start_commit(db, Some(start_commit), shapes, task, txn, wb)
+            .await
+            .unwrap();
+
+        responses
+    }
+
+    #[tokio::test]
+    async fn test_max_run_duration() {
+        let db = RocksDB::open(None).await.unwrap();
+        let mut task = task_fixture(1);
+        task.max_run_duration = Some(Duration::from_millis(50));
+        let mut shapes = vec![doc::Shape::nothing()];
+
+        // The connector produces one checkpoint and then stalls without exiting.
+        let connector_rx = futures::stream::iter([
+            Ok::<_, anyhow::Error>(captured(0, r#"{"id":1}"#)),
+            Ok(checkpoint(r#"{"cursor":1}"#)),
+        ])
+        .chain(futures::stream::pending())
+        .fuse();
+
+        // Read the first transaction, which is ready and is committed.
+        let (yield_tx, yield_rx) = oneshot::channel();
+        std::mem::drop(yield_tx);
+
+        let (accumulator, connector_rx, task, mut txn) = read_transaction(
+            accumulator_fixture(&task),
+            connector_rx,
+            task,
+            LONG_POLL_TIMEOUT,
+            yield_rx,
+        )
+        .await
+        .unwrap();
+
+        let (ready, response) = send_client_poll_result(&mut Default::default(), &task, &mut txn);
+        assert!(ready);
+        assert_eq!(poll_result(&response), PollResult::Ready);
+
+        let responses = drain_and_commit(accumulator, &db, &mut shapes, &task, &mut txn).await;
+        assert_eq!(responses.len(), 3); // Document, state update, and final checkpoint.
+        assert_eq!(
+            db.load_connector_state(Default::default())
+                .await
+                .unwrap()
+                .get(),
+            r#"{"cursor":1}"#
+        );
+
+        // Once the run cap elapses, the next transaction is empty
+        // and the session restarts.
+        tokio::time::sleep(Duration::from_millis(60)).await;
+        assert!(task.run_expired());
+
+        let (yield_tx, yield_rx) = oneshot::channel();
+        std::mem::drop(yield_tx);
+
+        let (_accumulator, _connector_rx, task, mut txn) = read_transaction(
+            accumulator_fixture(&task),
+            connector_rx,
+            task,
+            Duration::from_millis(10),
+            yield_rx,
+        )
+        .await
+        .unwrap();
+
+        assert!(txn.connector_eof);
+        assert_eq!(txn.checkpoints, 0);
+
+        let (ready, response) = send_client_poll_result(&mut Default::default(), &task, &mut txn);
+        assert!(!ready);
+        assert_eq!(poll_result(&response), PollResult::Restart);
+    }
+
+    // Read a transaction from `connector_rx`, yielding after a brief delay
+    // which allows all ready connector responses to be read.
+    async fn read_delayed_transaction(
+        task: Task,
+        connector_rx: impl super::super::ResponseStream + futures::stream::FusedStream + Unpin,
+    ) -> anyhow::Result<(doc::combine::Accumulator, Task, Transaction)> {
+        let (yield_tx, yield_rx) = oneshot::channel::<()>();
+        let accumulator = accumulator_fixture(&task);
+
+        let signal = async move {
+            tokio::time::sleep(Duration::from_millis(20)).await;
+            std::mem::drop(yield_tx);
+        };
+        let read = read_transaction(accumulator, connector_rx, task, LONG_POLL_TIMEOUT, yield_rx);
+
+        let (result, ()) = futures::join!(read, signal);
+        let (accumulator, _connector_rx, task, txn) = result?;
+        Ok((accumulator, task, txn))
+    }
+
+    #[tokio::test]
+    async fn test_drain_on_connector_error() {
+        // The connector fully sends one checkpoint, and then fails.
+        let connector_rx = || {
+            futures::stream::iter([
+                Ok(captured(0, r#"{"id":1}"#)),
+                Ok(captured(0, r#"{"id":2}"#)),
+                Ok(checkpoint(r#"{"cursor":2}"#)),
+                Err(anyhow::anyhow!("connector crashed")),
+            ])
+            .fuse()
+        };
+
+        // By default, the transaction fails and nothing is committed.
+        let err = read_delayed_transaction(task_fixture(1), connector_rx())
+            .await
+            .unwrap_err();
+        assert_eq!(err.to_string(), "connector crashed");
+
+        // With `drain_on_error`, the fully-read checkpoint is committed
+        // and the error is deferred until after its commit.
+        let db = RocksDB::open(None).await.unwrap();
+        let mut task = task_fixture(1);
+        task.drain_on_error = true;
+        let mut shapes = vec![doc::Shape::nothing()];
+
+        let (accumulator, task, mut txn) = read_delayed_transaction(task, connector_rx())
+            .await
+            .unwrap();
+
+        assert_eq!(txn.checkpoints, 1);
+        assert!(txn.connector_eof);
+        assert!(send_client_poll_result(&mut Default::default(), &task, &mut txn).0);
+
+        let responses = drain_and_commit(accumulator, &db, &mut shapes, &task, &mut txn).await;
+        assert_eq!(responses.len(), 4); // Two documents, state update, and final checkpoint.
+        assert_eq!(
+            db.load_connector_state(Default::default())
+                .await
+                .unwrap()
+                .get(),
+            r#"{"cursor":2}"#
+        );
+        assert_eq!(
+            txn.connector_error.unwrap().to_string(),
+            "connector crashed"
+        );
+
+        // An error which interrupts a partially-read checkpoint is not drained,
+        // as its documents cannot be separated from those of prior checkpoints.
+        let mut task = task_fixture(1);
+        task.drain_on_error = true;
+
+        let connector_rx = futures::stream::iter([
+            Ok(captured(0, r#"{"id":1}"#)),
+            Ok(checkpoint(r#"{"cursor":1}"#)),
+            Ok(captured(0, r#"{"id":2}"#)),
+            Err(anyhow::anyhow!("connector crashed")),
+        ])
+        .fuse();
+
+        let err = read_delayed_transaction(task, connector_rx)
+            .await
+            .unwrap_err();
+        assert_eq!(err.to_string(), "connector crashed");
+    }
+
+    #[tokio::test]
+    async fn test_client_blocked_time() {
+        let (txn_tx, txn_rx) = oneshot::channel();
+
+        // A coroutine which sends responses to a client, as `serve_session` does.
+        let sender = coroutines::coroutine(move |mut co| async move {
+            let mut txn = Transaction::new();
+            for cursor in 1..=3 {
+                let response = checkpoint(&format!(r#"{{"cursor":{cursor}}}"#));
+                () = yield_to_client(&mut co, response, &mut txn).await;
+            }
+            let _ = txn_tx.send(txn);
+        });
+        tokio::pin!(sender);
+
+        // A slow client which waits before reading each response.
+        let mut received = 0;
+        while let Some(_response) = sender.next().await {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+            received += 1;
+        }
+        assert_eq!(received, 3);
+
+        let txn = txn_rx.await.unwrap();
+        assert!(
+            txn.client_blocked >= Duration::from_millis(30),
+            "{:?}",
+            txn.client_blocked
+        );
+    }
+
+    #[test]
+    fn test_last_document_at() {
+        let mut task = task_fixture(2);
+        let mut accumulator = accumulator_fixture(&task);
+
+        assert_eq!(
+            task.describe_bindings(),
+            vec![
+                ("acmeCo/collection-0", None, 0),
+                ("acmeCo/collection-1", None, 0)
+            ]
+        );
+
+        // Capture a document into the first binding only.
+        let mut txn = Transaction::new();
+        recv_connector_captured(
+            &mut accumulator,
+            captured(0, r#"{"id":1}"#),
+            &task,
+            &mut txn,
+        )
+        .unwrap();
+        task.record_last_documents(&txn);
+
+        let bindings = task.describe_bindings();
+        let first = bindings[0].1.expect("binding 0 captured a document");
+        assert_eq!(bindings[1], ("acmeCo/collection-1", None, 0));
+
+        // A later transaction advances the first binding, while the idle second binding stays put.
+        std::thread::sleep(Duration::from_millis(5));
+        let mut txn = Transaction::new();
+        recv_connector_captured(
+            &mut accumulator,
+            captured(0, r#"{"id":2}"#),
+            &task,
+            &mut txn,
+        )
+        .unwrap();
+        task.record_last_documents(&txn);
+
+        let bindings = task.describe_bindings();
+        assert!(bindings[0].1.unwrap() > first);
+        assert_eq!(bindings[1], ("acmeCo/collection-1", None, 0));
+
+        // An empty transaction leaves all bindings unchanged.
+        task.record_last_documents(&Transaction::new());
+        assert_eq!(task.describe_bindings(), bindings);
+    }
+
+    #[tokio::test]
+    async fn test_passthrough() {
+        let db = RocksDB::open(None).await.unwrap();
+        let mut task = task_fixture(2);
+        task.bindings[0].passthrough = true;
+        let mut accumulator = accumulator_fixture(&task);
+        let mut shapes = vec![doc::Shape::nothing(), doc::Shape::nothing()];
+        let mut txn = Transaction::new();
+
+        // Both bindings capture the same documents, including a repeated key.
+        for doc_json in [
+            r#"{"id":3,"v":1}"#,
+            r#"{"id":1}"#,
+            r#"{"id":3,"v":2}"#,
+            r#"{"id":2}"#,
+        ] {
+            for binding in [0, 1] {
+                recv_connector_captured(
+                    &mut accumulator,
+                    captured(binding, doc_json),
+                    &task,
+                    &mut txn,
+                )
+                .unwrap();
+            }
+        }
+        recv_connector_checkpoint(
+            &mut accumulator,
+            checkpoint(r#"{"cursor":1}"#),
+            &task,
+            &mut txn,
+        )
+        .unwrap();
+
+        let responses = drain_and_commit(accumulator, &db, &mut shapes, &task, &mut txn).await;
+        let captured = |binding| {
+            responses
+                .iter()
+                .filter_map(|r| r.captured.as_ref())
+                .filter(|c| c.binding == binding)
+                .map(|c| c.doc_json.as_str())
+                .collect::<Vec<_>>()
+        };
+
+        // The passthrough binding preserves the exact order and count of its documents.
+        let uuid = crate::UUID_PLACEHOLDER;
+        assert_eq!(
+            captured(0),
+            vec![
+                format!(r#"{{"_meta":{{"uuid":"{uuid}"}},"id":3,"v":1}}"#),
+                format!(r#"{{"_meta":{{"uuid":"{uuid}"}},"id":1}}"#),
+                format!(r#"{{"_meta":{{"uuid":"{uuid}"}},"id":3,"v":2}}"#),
+                format!(r#"{{"_meta":{{"uuid":"{uuid}"}},"id":2}}"#),
+            ]
+        );
+        // The combined binding reduces and orders documents by key.
+        assert_eq!(
+            captured(1),
+            vec![
+                format!(r#"{{"_meta":{{"uuid":"{uuid}"}},"id":1}}"#),
+                format!(r#"{{"_meta":{{"uuid":"{uuid}"}},"id":2}}"#),
+                format!(r#"{{"_meta":{{"uuid":"{uuid}"}},"id":3,"v":2}}"#),
+            ]
+        );
+
+        // Statistics accumulate for passthrough bindings as usual.
+        let (read, out, _) = &txn.stats[&0];
+        assert_eq!((read.docs_total, out.docs_total), (4, 4));
+        let (read, out, _) = &txn.stats[&1];
+        assert_eq!((read.docs_total, out.docs_total), (4, 3));
+    }
+
+    #[tokio::test]
+    async fn test_complexity_limit_warnings() {
+        let db = RocksDB::open(None).await.unwrap();
+        let mut task = task_fixture(2);
+        let mut accumulator = accumulator_fixture(&task);
+        let mut shapes = vec![doc::Shape::nothing(), doc::Shape::nothing()];
+        let mut txn = Transaction::new();
+
+        // The first binding captures a document having more locations than the
+        // complexity limit allows, while the second binding stays well under it.
+        let limit = doc::shape::limits::DEFAULT_SCHEMA_COMPLEXITY_LIMIT;
+        let wide: serde_json::Map<_, _> = (0..limit + 100)
+            .map(|i| (format!("f{i}"), serde_json::json!(i)))
+            .chain([("id".to_string(), serde_json::json!(1))])
+            .collect();
+        let wide = serde_json::to_string(&wide).unwrap();
+
+        for (binding, doc_json) in [(0, wide.as_str()), (1, r#"{"id":1,"v":"small"}"#)] {
+            recv_connector_captured(
+                &mut accumulator,
+                captured(binding, doc_json),
+                &task,
+                &mut txn,
+            )
+            .unwrap();
+        }
+        recv_connector_checkpoint(
+            &mut accumulator,
+            checkpoint(r#"{"cursor":1}"#),
+            &task,
+            &mut txn,
+        )
+        .unwrap();
+        drain_and_commit(accumulator, &db, &mut shapes, &task, &mut txn).await;
+
+        // Only the first binding was reduced, and is to be warned of.
+        let now = tokio::time::Instant::now();
+        let limited = task.complexity_limited_bindings(&txn, now);
+        assert_eq!(limited.len(), 1);
+
+        let (collection, prior, reduced) = limited[0];
+        assert_eq!(collection, "acmeCo/collection-0");
+        assert!(prior > limit, "{prior}");
+        assert!(reduced <= limit, "{reduced}");
+
+        // Further reductions are not warned of again until the interval elapses.
+        assert!(task
+            .complexity_limited_bindings(&txn, now + Duration::from_secs(1))
+            .is_empty());
+        assert_eq!(
+            task.complexity_limited_bindings(&txn, now + COMPLEXITY_WARNING_INTERVAL)
+                .len(),
+            1
+        );
+
+        // A transaction which stays under the limit isn't warned of.
+        let later = now + COMPLEXITY_WARNING_INTERVAL * 2;
+        assert!(task
+            .complexity_limited_bindings(&Transaction::new(), later)
+            .is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_inference_stability() {
+        let db = RocksDB::open(None).await.unwrap();
+        let mut task = task_fixture(2);
+        task.inference_stable_after = 3;
+        let mut shapes = vec![doc::Shape::nothing(), doc::Shape::nothing()];
+        let mut observed = Vec::new();
+
+        for (step, doc_json) in [
+            Some(r#"{"id":1}"#), // Updates the inferred schema.
+            Some(r#"{"id":1}"#),
+            None, // Idle transactions don't extend the streak.
+            Some(r#"{"id":1}"#),
+            Some(r#"{"id":1}"#), // Reaches the threshold.
+            Some(r#"{"id":1}"#),
+            Some(r#"{"id":1,"v":"new"}"#), // Updates the schema, and resets.
+            Some(r#"{"id":1,"v":"new"}"#),
+        ]
+        .into_iter()
+        .enumerate()
+        {
+            // The first binding captures `doc_json`, if any.
+            let mut accumulator = accumulator_fixture(&task);
+            let mut txn = Transaction::new();
+
+            if let Some(doc_json) = doc_json {
+                recv_connector_captured(&mut accumulator, captured(0, doc_json), &task, &mut txn)
+                    .unwrap();
+            }
+            recv_connector_checkpoint(
+                &mut accumulator,
+                checkpoint(r#"{"cursor":1}"#),
+                &task,
+                &mut txn,
+            )
+            .unwrap();
+            drain_and_commit(accumulator, &db, &mut shapes, &task, &mut txn).await;
+
+            for (collection, commits) in task.record_inference_stability(&txn) {
+                observed.push((step, collection.to_string(), commits));
+            }
+            let bindings = task.describe_bindings();
+            observed.push((step, "streak".to_string(), bindings[0].2));
+
+            // The idle second binding never accrues stability.
+            assert_eq!(bindings[1].2, 0);
+        }
+
+        assert_eq!(
+            observed,
+            vec![
+                (0, "streak".to_string(), 0),
+                (1, "streak".to_string(), 1),
+                (2, "streak".to_string(), 1),
+                (3, "streak".to_string(), 2),
+                (4, "acmeCo/collection-0".to_string(), 3),
+                (4, "streak".to_string(), 3),
+                (5, "streak".to_string(), 4),
+                (6, "streak".to_string(), 0),
+                (7, "streak".to_string(), 1),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_poll_timeout() {
+        // Poll an empty transaction from a connector which stalls without exiting.
+        async fn poll(task: Task) -> (Task, PollResult) {
+            let (yield_tx, yield_rx) = oneshot::channel();
+            std::mem::drop(yield_tx);
+
+            let (_accumulator, _connector_rx, task, mut txn) = read_transaction(
+                accumulator_fixture(&task),
+                futures::stream::pending::<anyhow::Result<Response>>().fuse(),
+                task,
+                Duration::from_millis(10),
+                yield_rx,
+            )
+            .await
+            .unwrap();
+
+            assert!(!txn.connector_eof);
+            assert_eq!(txn.checkpoints, 0);
+
+            let (ready, response) =
+                send_client_poll_result(&mut Default::default(), &task, &mut txn);
+            assert!(!ready);
+            (task, poll_result(&response))
+        }
+
+        // Without a poll timeout, a stalled connector is polled indefinitely.
+        let (task, result) = poll(task_fixture(1)).await;
+        assert_eq!(result, PollResult::NotReady);
+        tokio::time::sleep(Duration::from_millis(60)).await;
+        let (_task, result) = poll(task).await;
+        assert_eq!(result, PollResult::NotReady);
+
+        let mut task = task_fixture(1);
+        task.poll_timeout = Some(Duration::from_millis(50));
+
+        let (mut task, result) = poll(task).await;
+        assert_eq!(result, PollResult::NotReady);
+        tokio::time::sleep(Duration::from_millis(60)).await;
+        assert!(task.poll_timed_out());
+
+        // A polled transaction without checkpoints doesn't reset the timeout.
+        task.record_poll(&Transaction::new(), false);
+        let (mut task, result) = poll(task).await;
+        assert_eq!(result, PollResult::Restart);
+
+        // A checkpoint does.
+        let mut txn = Transaction::new();
+        recv_connector_checkpoint(
+            &mut accumulator_fixture(&task),
+            checkpoint(r#"{"cursor":1}"#),
+            &task,
+            &mut txn,
+        )
+        .unwrap();
+        task.record_poll(&txn, true);
+        assert!(!task.poll_timed_out());
+
+        let (_task, result) = poll(task).await;
+        assert_eq!(result, PollResult::NotReady);
+    }
+
+    #[tokio::test]
+    async fn test_skip_invalid() {
+        let db = RocksDB::open(None).await.unwrap();
+        let mut shapes = vec![doc::Shape::nothing(), doc::Shape::nothing()];
+        let mut task = task_fixture(2);
+        task.bindings[1].passthrough = true;
+
+        let malformed = r#"{"id":1,"#;
+
+        // By default, a malformed document fails the transaction.
+        for binding in [0, 1] {
+            let mut accumulator = accumulator_fixture(&task);
+            let err = recv_connector_captured(
+                &mut accumulator,
+                captured(binding, malformed),
+                &task,
+                &mut Transaction::new(),
+            )
+            .unwrap_err();
+            assert_eq!(err.to_string(), "couldn't parse captured document as JSON");
+        }
+
+        // When skipping, malformed documents are counted and then dropped.
+        task.skip_invalid = true;
+        let mut accumulator = accumulator_fixture(&task);
+        let mut txn = Transaction::new();
+
+        for (binding, doc_json) in [
+            (0, r#"{"id":1}"#),
+            (0, malformed),
+            (1, malformed),
+            (1, malformed),
+            (1, r#"{"id":2}"#),
+        ] {
+            recv_connector_captured(
+                &mut accumulator,
+                captured(binding, doc_json),
+                &task,
+                &mut txn,
+            )
+            .unwrap();
+        }
+        recv_connector_checkpoint(
+            &mut accumulator,
+            checkpoint(r#"{"cursor":1}"#),
+            &task,
+            &mut txn,
+        )
+        .unwrap();
+
+        assert_eq!(txn.captured_bytes, 16);
+        assert_eq!(txn.passthrough.len(), 1);
+
+        let responses = drain_and_commit(accumulator, &db, &mut shapes, &task, &mut txn).await;
+        assert_eq!(responses.len(), 4); // Two documents, state update, and final checkpoint.
+
+        let stats = responses
+            .last()
+            .unwrap()
+            .get_internal()
+            .unwrap()
+            .checkpoint
+            .unwrap()
+            .stats
+            .unwrap();
+
+        let mut summary: Vec<_> = stats
+            .capture
+            .iter()
+            .map(|(collection, binding)| {
+                let docs = |s: &Option<ops::stats::DocsAndBytes>| {
+                    s.as_ref().map(|s| (s.docs_total, s.bytes_total))
+                };
+                (
+                    collection.as_str(),
+                    docs(&binding.right),
+                    docs(&binding.skipped),
+                )
+            })
+            .collect();
+        summary.sort();
+
+        assert_eq!(
+            summary,
+            vec![
+                ("acmeCo/collection-0", Some((1, 8)), Some((1, 8))),
+                ("acmeCo/collection-1", Some((1, 8)), Some((2, 16))),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_inferred_schemas() {
+        let db = RocksDB::open(None).await.unwrap();
+        let task = task_fixture(2);
+        let mut shapes = vec![doc::Shape::nothing(), doc::Shape::nothing()];
+
+        let mut accumulator = accumulator_fixture(&task);
+        let mut txn = Transaction::new();
+
+        for (binding, doc_json) in [(0, r#"{"id":1}"#), (1, r#"{"id":"one"}"#)] {
+            recv_connector_captured(
+                &mut accumulator,
+                captured(binding, doc_json),
+                &task,
+                &mut txn,
+            )
+            .unwrap();
+        }
+        recv_connector_checkpoint(
+            &mut accumulator,
+            checkpoint(r#"{"cursor":1}"#),
+            &task,
+            &mut txn,
+        )
+        .unwrap();
+        drain_and_commit(accumulator, &db, &mut shapes, &task, &mut txn).await;
+
+        let before = shapes.clone();
+        let schemas = task.inferred_schemas(&shapes);
+        assert_eq!(shapes, before);
+
+        assert_eq!(
+            schemas.keys().collect::<Vec<_>>(),
+            vec!["acmeCo/collection-0", "acmeCo/collection-1"]
+        );
+        assert_eq!(
+            schemas["acmeCo/collection-0"].pointer("/properties/id/type"),
+            Some(&serde_json::json!("integer"))
+        );
+        assert_eq!(
+            schemas["acmeCo/collection-1"].pointer("/properties/id/type"),
+            Some(&serde_json::json!("string"))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_recv_client_open_state_keys() {
+        let db = RocksDB::open(None).await.unwrap();
+
+        let open = |state_key: &str, strict_state_keys: bool| {
+            Request {
+                open: Some(request::Open {
+                    capture: Some(flow::CaptureSpec {
+                        name: "acmeCo/capture".to_string(),
+                        bindings: vec![flow::capture_spec::Binding {
+                            resource_path: vec!["some".to_string(), "table".to_string()],
+                            state_key: state_key.to_string(),
+                            ..Default::default()
+                        }],
+                        ..Default::default()
+                    }),
+                    state_json: "{}".to_string(),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            }
+            .with_internal(|internal| {
+                internal.open = Some(capture_request_ext::Open {
+                    strict_state_keys,
+                    ..Default::default()
+                });
+            })
+        };
+        let state_key = |open: &Request| {
+            open.open
+                .as_ref()
+                .unwrap()
+                .capture
+                .as_ref()
+                .unwrap()
+                .bindings[0]
+                .state_key
+                .clone()
+        };
+        let expect = assemble::encode_state_key(&["some", "table"], 0);
+
+        // By default, a missing state_key is computed from the resource path.
+        let mut request = open("", false);
+        recv_client_open(&mut request, &db).await.unwrap();
+        assert_eq!(state_key(&request), expect);
+
+        // As is a populated state_key.
+        let mut request = open("stale", false);
+        recv_client_open(&mut request, &db).await.unwrap();
+        assert_eq!(state_key(&request), expect);
+
+        // In strict mode, a populated state_key is used as-is.
+        let mut request = open("provided", true);
+        recv_client_open(&mut request, &db).await.unwrap();
+        assert_eq!(state_key(&request), "provided");
+
+        // And a missing state_key is an error.
+        let err = recv_client_open(&mut open("", true), &db)
+            .await
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            r#"binding 0 of capture acmeCo/capture (resource path ["some", "table"]) is missing its state_key"#
+        );
+    }
+
+    #[tokio::test]
+    async fn test_recv_client_open_reset_state() {
+        let db = RocksDB::open(None).await.unwrap();
+
+        let open = |state_json: &str, reset_state: bool| {
+            Request {
+                open: Some(request::Open {
+                    capture: Some(flow::CaptureSpec {
+                        name: "acmeCo/capture".to_string(),
+                        ..Default::default()
+                    }),
+                    state_json: state_json.to_string(),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            }
+            .with_internal(|internal| {
+                internal.open = Some(capture_request_ext::Open {
+                    reset_state,
+                    ..Default::default()
+                });
+            })
+        };
+        let state_json = |open: &Request| open.open.as_ref().unwrap().state_json.clone();
+
+        // The first open initializes the persisted state.
+        let mut request = open(r#"{"initial":true}"#, false);
+        recv_client_open(&mut request, &db).await.unwrap();
+        assert_eq!(state_json(&request), r#"{"initial":true}"#);
+
+        // A committed connector state update is persisted.
+        let mut wb = rocksdb::WriteBatch::default();
+        wb.merge(RocksDB::CONNECTOR_STATE_KEY, r#"{"cursor":42}"#);
+        db.write_opt(wb, Default::default()).await.unwrap();
+
+        // By default, the persisted state is preserved.
+        let mut request = open(r#"{"initial":true}"#, false);
+        recv_client_open(&mut request, &db).await.unwrap();
+        assert_eq!(state_json(&request), r#"{"cursor":42,"initial":true}"#);
+
+        // When reset, the connector is opened with only the request's state.
+        let mut request = open(r#"{"fresh":true}"#, true);
+        recv_client_open(&mut request, &db).await.unwrap();
+        assert_eq!(state_json(&request), r#"{"fresh":true}"#);
+
+        // And the reset state is durable for later opens.
+        let mut request = open("{}", false);
+        recv_client_open(&mut request, &db).await.unwrap();
+        assert_eq!(state_json(&request), r#"{"fresh":true}"#);
+    }
+
+    #[test]
+    fn test_sample_tap() {
+        let capture = |task: &Task| {
+            let mut accumulator = accumulator_fixture(task);
+            let mut txn = Transaction::new();
+
+            for (binding, count) in [(0, 8), (1, 4)] {
+                for id in 1..=count {
+                    let doc_json = format!(r#"{{"id":{id}}}"#);
+                    recv_connector_captured(
+                        &mut accumulator,
+                        captured(binding, &doc_json),
+                        task,
+                        &mut txn,
+                    )
+                    .unwrap();
+                }
+            }
+            txn
+        };
+
+        let mut task = task_fixture(2);
+        let primary = capture(&task);
+        assert!(primary.sampled.is_empty());
+
+        task.sample_rate = 0.25;
+        let sampled = capture(&task);
+
+        // The primary path is unaffected by sampling.
+        assert_eq!(primary.stats, sampled.stats);
+        assert_eq!(primary.captured_bytes, sampled.captured_bytes);
+
+        // The tap receives an evenly-spaced quarter of each binding's documents.
+        assert_eq!(
+            sampled.sampled,
+            vec![
+                (0, r#"{"id":4}"#.to_string()),
+                (0, r#"{"id":8}"#.to_string()),
+                (1, r#"{"id":4}"#.to_string()),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_stats_snapshot() {
+        let db = RocksDB::open(None).await.unwrap();
+        let task = task_fixture(2);
+        let mut shapes = vec![doc::Shape::nothing(), doc::Shape::nothing()];
+
+        let mut accumulator = accumulator_fixture(&task);
+        let mut txn = Transaction::new();
+        txn.started_at = std::time::SystemTime::now();
+
+        for (binding, doc_json) in [(0, r#"{"id":1}"#), (1, r#"{"id":2}"#), (1, r#"{"id":3}"#)] {
+            recv_connector_captured(
+                &mut accumulator,
+                captured(binding, doc_json),
+                &task,
+                &mut txn,
+            )
+            .unwrap();
+        }
+        recv_connector_checkpoint(
+            &mut accumulator,
+            checkpoint(r#"{"cursor":1}"#),
+            &task,
+            &mut txn,
+        )
+        .unwrap();
+
+        // A mid-flight snapshot reflects documents read thus far.
+        let docs = |stats: &ops::Stats| -> Vec<_> {
+            stats
+                .capture
+                .iter()
+                .map(|(collection, binding)| {
+                    let docs = |s: &Option<ops::stats::DocsAndBytes>| {
+                        s.as_ref().map(|s| (s.docs_total, s.bytes_total))
+                    };
+                    (collection.clone(), docs(&binding.right), docs(&binding.out))
+                })
+                .collect()
+        };
+        let snapshot = txn.stats_snapshot(&task);
+        assert_eq!(
+            docs(&snapshot),
+            vec![
+                ("acmeCo/collection-0".to_string(), Some((1, 8)), None),
+                ("acmeCo/collection-1".to_string(), Some((2, 16)), None),
+            ]
+        );
+        // Taking a snapshot doesn't modify the transaction.
+        assert_eq!(docs(&txn.stats_snapshot(&task)), docs(&snapshot));
+
+        let responses = drain_and_commit(accumulator, &db, &mut shapes, &task, &mut txn).await;
+        let committed = responses
+            .last()
+            .unwrap()
+            .get_internal()
+            .unwrap()
+            .checkpoint
+            .unwrap()
+            .stats
+            .unwrap();
+
+        // The committed stats match a snapshot of the fully-drained transaction,
+        // apart from the time for which the transaction has been open.
+        let mut snapshot = txn.stats_snapshot(&task);
+        snapshot.open_seconds_total = committed.open_seconds_total;
+        assert_eq!(snapshot, committed);
+        assert_eq!(docs(&committed)[0].1, Some((1, 8)));
+        assert!(docs(&committed)[1].2.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_validate_captured() {
+        let db = RocksDB::open(None).await.unwrap();
+        let mut shapes = vec![doc::Shape::nothing()];
+        let mut task = task_fixture(1);
+        task.bindings[0].write_schema_json = r#"{"required":["id","name"]}"#.to_string();
+
+        let valid = r#"{"id":1,"name":"a"}"#;
+        let invalid = r#"{"id":2}"#;
+
+        // Without pre-flight validation, the invalid document is accepted
+        // and would fail only as it's combined or drained.
+        let mut accumulator = accumulator_fixture(&task);
+        recv_connector_captured(
+            &mut accumulator,
+            captured(0, invalid),
+            &task,
+            &mut Transaction::new(),
+        )
+        .unwrap();
+
+        // With pre-flight validation, it fails the transaction as it's read.
+        task.validate_captured = true;
+        let mut accumulator = accumulator_fixture(&task);
+        let err = recv_connector_captured(
+            &mut accumulator,
+            captured(0, invalid),
+            &task,
+            &mut Transaction::new(),
+        )
+        .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "captured collection acmeCo/collection-0 document failed validation against its collection JSON Schema"
+        );
+
+        // When also skipping, the invalid document is counted and then dropped.
+        task.skip_invalid = true;
+        let mut accumulator = accumulator_fixture(&task);
+        let mut txn = Transaction::new();
+
+        for doc_json in [valid, invalid] {
+            recv_connector_captured(&mut accumulator, captured(0, doc_json), &task, &mut txn)
+                .unwrap();
+        }
+        recv_connector_checkpoint(
+            &mut accumulator,
+            checkpoint(r#"{"cursor":1}"#),
+            &task,
+            &mut txn,
+        )
+        .unwrap();
+
+        let (read, _, skipped) = &txn.stats[&0];
+        assert_eq!((read.docs_total, read.bytes_total), (1, 19));
+        assert_eq!((skipped.docs_total, skipped.bytes_total), (1, 8));
+
+        // Only the valid document is combined and committed.
+        let responses = drain_and_commit(accumulator, &db, &mut shapes, &task, &mut txn).await;
+        assert_eq!(responses.len(), 3); // Document, state update, and final checkpoint.
+    }
+
+    #[test]
+    fn test_ack_batch_size() {
+        let mut task = task_fixture(1);
+        task.explicit_acknowledgements = true;
+
+        // Acknowledge the checkpoints of a series of committed transactions,
+        // returning the acknowledged count (or zero) after each one.
+        let run = |task: &Task, commits: &[(u32, bool)]| {
+            let mut unacked = 0;
+            commits
+                .iter()
+                .map(|(checkpoints, idle)| {
+                    unacked += checkpoints;
+                    match send_connector_acknowledge(&mut unacked, *idle, task) {
+                        Some(Request {
+                            acknowledge: Some(request::Acknowledge { checkpoints }),
+                            ..
+                        }) => checkpoints,
+                        None => 0,
+                        Some(request) => panic!("unexpected request {request:?}"),
+                    }
+                })
+                .collect::<Vec<_>>()
+        };
+        let commits = [
+            (1, false),
+            (1, false),
+            (2, false),
+            (1, false),
+            (1, true),
+            (0, true),
+        ];
+
+        // By default, every committed transaction is acknowledged.
+        assert_eq!(run(&task, &commits), vec![1, 1, 2, 1, 1, 0]);
+
+        // Batched acknowledgements are emitted once three checkpoints are
+        // committed, or when the connector is idle with checkpoints outstanding.
+        task.ack_batch_size = 3;
+        assert_eq!(run(&task, &commits), vec![0, 0, 4, 0, 2, 0]);
+
+        // Without explicit acknowledgements, none are sent.
+        task.explicit_acknowledgements = false;
+        assert_eq!(run(&task, &commits), vec![0; 6]);
+    }
+
+    #[test]
+    fn test_max_state_bytes() {
+        let mut task = task_fixture(1);
+        task.max_state_bytes = Some(16);
+
+        let mut accumulator = accumulator_fixture(&task);
+        let mut txn = Transaction::new();
+
+        // A state update within the limit is accepted.
+        let state = r#"{"cursor":"abc"}"#;
+        assert_eq!(state.len(), 16);
+        recv_connector_checkpoint(&mut accumulator, checkpoint(state), &task, &mut txn).unwrap();
+        assert_eq!(txn.checkpoints, 1);
+
+        // One which exceeds it fails the transaction.
+        let err = recv_connector_checkpoint(
+            &mut accumulator,
+            checkpoint(r#"{"cursor":"abcd"}"#),
+            &task,
+            &mut txn,
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "connector checkpoint state update of 17 bytes exceeds the maximum of 16 bytes"
+        );
+        assert_eq!(txn.checkpoints, 1);
+    }
+
+    #[test]
+    fn test_restart_jitter() {
+        let mut task = task_fixture(1);
+        let mut other = task_fixture(1);
+        other.shard_ref.key_begin = "80000000".to_string();
+
+        // Without jitter, restarts are not delayed.
+        assert_eq!(task.restart_delay(), Duration::ZERO);
+
+        // With jitter, shards are delayed by distinct offsets within the window.
+        let window = Duration::from_secs(60);
+        task.restart_jitter = Some(window);
+        other.restart_jitter = Some(window);
+
+        let (delay, other_delay) = (task.restart_delay(), other.restart_delay());
+        assert!(delay < window && other_delay < window);
+        assert_ne!(delay, other_delay);
+
+        // Delays are deterministic.
+        assert_eq!(delay, task.clone().restart_delay());
+
+        // A task which has reached its restart instant still cools off until
+        // its delay has also elapsed.
+        let mut txn = Transaction::new();
+        txn.connector_eof = true;
+
+        task.restart = tokio::time::Instant::now() - Duration::from_millis(1);
+        task.restart_jitter = None;
+        let (_, response) = send_client_poll_result(&mut bytes::BytesMut::new(), &task, &mut txn);
+        assert_eq!(poll_result(&response), PollResult::Restart);
+
+        task.restart_jitter = Some(window);
+        task.restart = tokio::time::Instant::now() - delay / 2;
+        let (_, response) = send_client_poll_result(&mut bytes::BytesMut::new(), &task, &mut txn);
+        assert_eq!(poll_result(&response), PollResult::CoolOff);
+    }
+
+    #[tokio::test]
+    async fn test_dry_commit() {
+        let capture = |db: RocksDB, task: Task| async move {
+            let mut shapes = vec![doc::Shape::nothing()];
+            let mut accumulator = accumulator_fixture(&task);
+            let mut txn = Transaction::new();
+
+            recv_connector_captured(
+                &mut accumulator,
+                captured(0, r#"{"id":1}"#),
+                &task,
+                &mut txn,
+            )
+            .unwrap();
+            recv_connector_checkpoint(
+                &mut accumulator,
+                checkpoint(r#"{"cursor":1}"#),
+                &task,
+                &mut txn,
+            )
+            .unwrap();
+
+            let responses = drain_and_commit(accumulator, &db, &mut shapes, &task, &mut txn).await;
+            assert_eq!(responses.len(), 3); // Document, state update, and final checkpoint.
+
+            let mut persisted = Vec::new();
+            for key in [RocksDB::CONNECTOR_STATE_KEY, RocksDB::CHECKPOINT_KEY] {
+                persisted.push(db.get_opt(key, Default::default()).await.unwrap().is_some());
+            }
+            persisted
+        };
+
+        let mut task = task_fixture(1);
+        let db = RocksDB::open(None).await.unwrap();
+        assert_eq!(capture(db, task.clone()).await, vec![true, true]);
+
+        // Under dry_commit, documents are still produced but RocksDB isn't mutated.
+        task.dry_commit = true;
+        let db = RocksDB::open(None).await.unwrap();
+        assert_eq!(capture(db, task).await, vec![false, false]);
+    }
+
+    #[tokio::test]
+    async fn test_binding_truncation() {
+        let db = RocksDB::open(None).await.unwrap();
+        let mut shapes = vec![doc::Shape::nothing(), doc::Shape::nothing()];
+        let task = task_fixture_with(
+            2,
+            capture_request_ext::Open {
+                bindings: vec![capture_request_ext::open::Binding {
+                    ser_policy: Some(flow::SerPolicy {
+                        str_truncate_after: 40,
+                        array_truncate_after: 2,
+                        ..Default::default()
+                    }),
+                    ..Default::default()
+                }],
+                ..Default::default()
+            },
+        );
+
+        let mut accumulator = accumulator_fixture(&task);
+        let mut txn = Transaction::new();
+
+        let long = "x".repeat(50);
+        let doc_json = format!(r#"{{"id":1,"a":[1,2,3],"s":"{long}"}}"#);
+
+        for binding in [0, 1] {
+            recv_connector_captured(
+                &mut accumulator,
+                captured(binding, &doc_json),
+                &task,
+                &mut txn,
+            )
+            .unwrap();
+        }
+        recv_connector_checkpoint(
+            &mut accumulator,
+            checkpoint(&format!(r#"{{"cursor":"{long}"}}"#)),
+            &task,
+            &mut txn,
+        )
+        .unwrap();
+
+        let responses = drain_and_commit(accumulator, &db, &mut shapes, &task, &mut txn).await;
+        let doc_json = |response: &Response| response.captured.as_ref().unwrap().doc_json.clone();
+
+        // Binding zero is truncated per its policy, while binding one
+        // and the connector state use the default policy.
+        let uuid = crate::UUID_PLACEHOLDER;
+        let short = "x".repeat(40);
+        assert_eq!(
+            doc_json(&responses[0]),
+            format!(r#"{{"_meta":{{"uuid":"{uuid}"}},"a":[1,2],"id":1,"s":"{short}"}}"#)
+        );
+        assert_eq!(
+            doc_json(&responses[1]),
+            format!(r#"{{"_meta":{{"uuid":"{uuid}"}},"a":[1,2,3],"id":1,"s":"{long}"}}"#)
+        );
+        assert_eq!(
+            responses[2]
+                .checkpoint
+                .as_ref()
+                .unwrap()
+                .state
+                .as_ref()
+                .unwrap()
+                .updated_json,
+            format!(r#"{{"cursor":"{long}"}}"#)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_spill_threshold() {
+        let capture = |task: Task| async move {
+            let db = RocksDB::open(None).await.unwrap();
+            let mut shapes = vec![doc::Shape::nothing()];
+            let mut accumulator = accumulator_fixture(&task);
+            let mut txn = Transaction::new();
+
+            for id in 1..=3 {
+                recv_connector_captured(
+                    &mut accumulator,
+                    captured(0, &format!(r#"{{"id":{id}}}"#)),
+                    &task,
+                    &mut txn,
+                )
+                .unwrap();
+            }
+            recv_connector_checkpoint(
+                &mut accumulator,
+                checkpoint(r#"{"cursor":3}"#),
+                &task,
+                &mut txn,
+            )
+            .unwrap();
+
+            let responses = drain_and_commit(accumulator, &db, &mut shapes, &task, &mut txn).await;
+            assert_eq!(responses.len(), 5); // Three documents, state update, and final checkpoint.
+
+            responses
+                .last()
+                .unwrap()
+                .get_internal()
+                .unwrap()
+                .checkpoint
+                .unwrap()
+                .stats
+                .unwrap()
+                .spilled_bytes_total
+        };
+
+        // By default, a small transaction is combined entirely in memory.
+        let mut task = task_fixture(1);
+        assert_eq!(capture(task.clone()).await, 0);
+
+        // A tiny threshold forces the combiner to spill as documents are added.
+        task.spill_threshold = Some(1);
+        assert!(capture(task).await > 0);
+    }
+
+    #[tokio::test]
+    async fn test_binding_projection() {
+        let db = RocksDB::open(None).await.unwrap();
+        let mut shapes = vec![doc::Shape::nothing(), doc::Shape::nothing()];
+        let task = task_fixture_with(
+            2,
+            capture_request_ext::Open {
+                bindings: vec![capture_request_ext::open::Binding {
+                    projection: vec!["/a/b".to_string(), "/d".to_string(), "/missing".to_string()],
+                    ..Default::default()
+                }],
+                ..Default::default()
+            },
+        );
+
+        let mut accumulator = accumulator_fixture(&task);
+        let mut txn = Transaction::new();
+
+        let doc_json = r#"{"id":1,"a":{"b":2,"c":3},"d":[4],"e":"five"}"#;
+
+        for binding in [0, 1] {
+            recv_connector_captured(
+                &mut accumulator,
+                captured(binding, doc_json),
+                &task,
+                &mut txn,
+            )
+            .unwrap();
+        }
+        recv_connector_checkpoint(
+            &mut accumulator,
+            checkpoint(r#"{"cursor":1}"#),
+            &task,
+            &mut txn,
+        )
+        .unwrap();
+
+        let responses = drain_and_commit(accumulator, &db, &mut shapes, &task, &mut txn).await;
+        let doc_json = |response: &Response| response.captured.as_ref().unwrap().doc_json.clone();
+
+        // Binding zero retains only its projected locations and its key,
+        // while binding one is unprojected. Both are stamped with a UUID.
+        let uuid = crate::UUID_PLACEHOLDER;
+        assert_eq!(
+            doc_json(&responses[0]),
+            format!(r#"{{"_meta":{{"uuid":"{uuid}"}},"a":{{"b":2}},"d":[4],"id":1}}"#)
+        );
+        assert_eq!(
+            doc_json(&responses[1]),
+            format!(
+                r#"{{"_meta":{{"uuid":"{uuid}"}},"a":{{"b":2,"c":3}},"d":[4],"e":"five","id":1}}"#
+            )
+        );
+    }
+
+    #[tokio::test]
+    async fn test_duplicate_state_keys() {
+        let db = RocksDB::open(None).await.unwrap();
+
+        let binding = |table: &str| flow::capture_spec::Binding {
+            resource_path: vec!["some".to_string(), table.to_string()],
+            ..Default::default()
+        };
+        let mut open = Request {
+            open: Some(request::Open {
+                capture: Some(flow::CaptureSpec {
+                    name: "acmeCo/capture".to_string(),
+                    bindings: vec![binding("table"), binding("other"), binding("table")],
+                    ..Default::default()
+                }),
+                range: Some(flow::RangeSpec {
+                    key_begin: 0,
+                    key_end: u32::MAX,
+                    r_clock_begin: 0,
+                    r_clock_end: u32::MAX,
+                }),
+                state_json: "{}".to_string(),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        recv_client_open(&mut open, &db).await.unwrap();
+
+        let opened = Response {
+            opened: Some(response::Opened {
+                explicit_acknowledgements: false,
+            }),
+            ..Default::default()
+        };
+
+        // Bindings zero and two have identical resource paths, and collide.
+        let err = Task::new(&open, &opened).unwrap_err();
+        let state_key = assemble::encode_state_key(&["some", "table"], 0);
+        assert_eq!(
+            err.to_string(),
+            format!(
+                r#"bindings 0 and 2 of capture acmeCo/capture have the same state_key {state_key:?} (resource path ["some", "table"])"#
+            )
+        );
+    }
+
+    #[tokio::test]
+    async fn test_connector_partitions() {
+        let db = RocksDB::open(None).await.unwrap();
+        let mut task = task_fixture(2);
+        let extractors = vec![doc::Extractor::new("/region", &doc::SerPolicy::noop())];
+        for binding in task.bindings.iter_mut() {
+            binding.partition_extractors = extractors.clone();
+        }
+        task.bindings[1].connector_partitions = true;
+
+        let mut accumulator = accumulator_fixture(&task);
+        let mut shapes = vec![doc::Shape::nothing(), doc::Shape::nothing()];
+        let mut txn = Transaction::new();
+
+        let pack = |region: &str| {
+            doc::Extractor::extract_all(
+                &serde_json::json!({"region": region}),
+                &extractors,
+                &mut bytes::BytesMut::new(),
+            )
+        };
+        let supplied = |binding, doc_json: &str, partitions_packed: bytes::Bytes| {
+            captured(binding, doc_json).with_internal(|internal| {
+                internal.captured = Some(capture_response_ext::Captured {
+                    key_packed: Default::default(),
+                    partitions_packed,
+                });
+            })
+        };
+
+        // Document 1 has connector-supplied partitions, while document 2 does not.
+        for binding in [0, 1] {
+            for response in [
+                supplied(binding, r#"{"id":1,"region":"east"}"#, pack("west")),
+                captured(binding, r#"{"id":2,"region":"east"}"#),
+            ] {
+                recv_connector_captured(&mut accumulator, response, &task, &mut txn).unwrap();
+            }
+        }
+        recv_connector_checkpoint(
+            &mut accumulator,
+            checkpoint(r#"{"cursor":1}"#),
+            &task,
+            &mut txn,
+        )
+        .unwrap();
+
+        let responses = drain_and_commit(accumulator, &db, &mut shapes, &task, &mut txn).await;
+        let partitions = |binding| {
+            responses
+                .iter()
+                .filter(|r| matches!(&r.captured, Some(c) if c.binding == binding))
+                .map(|r| {
+                    r.get_internal()
+                        .unwrap()
+                        .captured
+                        .unwrap()
+                        .partitions_packed
+                })
+                .collect::<Vec<_>>()
+        };
+
+        // Binding 0 extracts partitions, ignoring those supplied by the connector.
+        assert_eq!(partitions(0), vec![pack("east"), pack("east")]);
+        // Binding 1 prefers supplied partitions, falling back to extraction.
+        assert_eq!(partitions(1), vec![pack("west"), pack("east")]);
+
+        // Supplied partitions must have a component for each partition field.
+        let mut accumulator = accumulator_fixture(&task);
+        let err = recv_connector_captured(
+            &mut accumulator,
+            supplied(1, r#"{"id":3}"#, bytes::Bytes::from_static(b"\x01")),
+            &task,
+            &mut Transaction::new(),
+        )
+        .unwrap_err();
+        assert_eq!(err.to_string(), "invalid partitions of captured binding 1");
+    }
+
+    #[tokio::test]
+    async fn test_binding_sort() {
+        let db = RocksDB::open(None).await.unwrap();
+        let task = task_fixture_with(
+            2,
+            capture_request_ext::Open {
+                bindings: vec![capture_request_ext::open::Binding {
+                    sort_ptr: "/ts".to_string(),
+                    ..Default::default()
+                }],
+                ..Default::default()
+            },
+        );
+        let mut accumulator = accumulator_fixture(&task);
+        let mut shapes = vec![doc::Shape::nothing(), doc::Shape::nothing()];
+        let mut txn = Transaction::new();
+
+        for doc_json in [
+            r#"{"id":1,"ts":30}"#,
+            r#"{"id":2,"ts":10}"#,
+            r#"{"id":3,"ts":20}"#,
+            r#"{"id":4,"ts":10}"#,
+            r#"{"id":5}"#,
+        ] {
+            for binding in [0, 1] {
+                recv_connector_captured(
+                    &mut accumulator,
+                    captured(binding, doc_json),
+                    &task,
+                    &mut txn,
+                )
+                .unwrap();
+            }
+        }
+        recv_connector_checkpoint(
+            &mut accumulator,
+            checkpoint(r#"{"cursor":1}"#),
+            &task,
+            &mut txn,
+        )
+        .unwrap();
+
+        let responses = drain_and_commit(accumulator, &db, &mut shapes, &task, &mut txn).await;
+        let ids = |binding| {
+            responses
+                .iter()
+                .filter_map(|r| r.captured.as_ref())
+                .filter(|c| c.binding == binding)
+                .map(|c| {
+                    serde_json::from_str::<serde_json::Value>(&c.doc_json).unwrap()["id"].clone()
+                })
+                .collect::<Vec<_>>()
+        };
+
+        // Binding 0 is ordered by `/ts`, then by key. A missing `/ts` sorts as null, first.
+        assert_eq!(ids(0), vec![5, 2, 4, 3, 1]);
+        // Binding 1 retains the combiner's key order.
+        assert_eq!(ids(1), vec![1, 2, 3, 4, 5]);
+
+        // The connector state update and final checkpoint follow all documents.
+        assert_eq!(responses.len(), 12);
+        assert!(responses[10].checkpoint.is_some());
+        assert!(responses[11].checkpoint.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_idle_heartbeat() {
+        let db = RocksDB::open(None).await.unwrap();
+        let mut shapes = vec![doc::Shape::nothing()];
+
+        // Without an idle heartbeat, an idle connector is never ready.
+        let mut task = task_fixture(1);
+        tokio::time::sleep(Duration::from_millis(60)).await;
+        let (ready, response) =
+            send_client_poll_result(&mut Default::default(), &task, &mut Transaction::new());
+        assert!(!ready);
+        assert_eq!(poll_result(&response), PollResult::NotReady);
+
+        // Enable heartbeats, and reset the idle interval as though a
+        // transaction was just committed.
+        task.idle_heartbeat = Some(Duration::from_millis(50));
+        task.record_poll(&Transaction::new(), true);
+
+        // Before the heartbeat interval elapses, the idle connector isn't ready.
+        let mut txn = Transaction::new();
+        let (ready, response) = send_client_poll_result(&mut Default::default(), &task, &mut txn);
+        assert!(!ready);
+        assert_eq!(poll_result(&response), PollResult::NotReady);
+
+        // Once it does, an empty transaction is ready to commit.
+        tokio::time::sleep(Duration::from_millis(60)).await;
+        let mut txn = Transaction::new();
+        let (ready, response) = send_client_poll_result(&mut Default::default(), &task, &mut txn);
+        assert!(ready);
+        assert_eq!(poll_result(&response), PollResult::Ready);
+
+        // The heartbeat commits only a final checkpoint, and no connector state.
+        let responses = drain_and_commit(
+            accumulator_fixture(&task),
+            &db,
+            &mut shapes,
+            &task,
+            &mut txn,
+        )
+        .await;
+        assert_eq!(responses.len(), 1);
+        assert!(responses[0].checkpoint.is_some());
+        assert_eq!(
+            db.load_connector_state(Default::default())
+                .await
+                .unwrap()
+                .get(),
+            "null"
+        );
+
+        // Its stats are of a transaction which started with the heartbeat.
+        let stats = responses[0]
+            .get_internal()
+            .unwrap()
+            .checkpoint
+            .unwrap()
+            .stats
+            .unwrap();
+        let now = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_secs() as i64;
+        assert!((0.0..60.0).contains(&stats.open_seconds_total), "{stats:?}");
+        assert!(
+            (now - 60..=now).contains(&stats.timestamp.as_ref().unwrap().seconds),
+            "{stats:?}"
+        );
+
+        // The heartbeat resets the idle interval, but not the poll timeout.
+        let checkpointed_at = task.checkpointed_at;
+        task.record_poll(&txn, ready);
+        assert!(!task.heartbeat_due());
+        assert_eq!(task.checkpointed_at, checkpointed_at);
+    }
+
+    #[test]
+    fn test_final_checkpoint_open_seconds() {
+        let task = task_fixture(1);
+        let mut buf = bytes::BytesMut::new();
+
+        let open_seconds = |txn: &Transaction, buf: &mut bytes::BytesMut| {
+            send_client_final_checkpoint(buf, &task, txn)
+                .get_internal()
+                .unwrap()
+                .checkpoint
+                .unwrap()
+                .stats
+                .unwrap()
+                .open_seconds_total
+        };
+
+        // A transaction which was only just started.
+        let mut txn = Transaction::new();
+        txn.started_at = std::time::SystemTime::now();
+        let seconds = open_seconds(&txn, &mut buf);
+        assert!((0.0..60.0).contains(&seconds), "{seconds}");
+
+        // A transaction which appears to start in the future, as happens
+        // when the wall-clock steps backwards, is open for zero seconds.
+        txn.started_at = std::time::SystemTime::now() + Duration::from_secs(60);
+        assert_eq!(open_seconds(&txn, &mut buf), 0.0);
+    }
+
+    #[test]
+    fn test_open_extension_options() {
+        // Without options, each knob takes its default.
+        let task = task_fixture(2);
+        assert_eq!(task.ack_batch_size, 1);
+        assert_eq!(task.client_blocked_threshold, None);
+        assert_eq!(
+            task.inference_stable_after,
+            super::super::INFERENCE_STABLE_AFTER
+        );
+        assert_eq!(task.max_run_duration, None);
+        assert_eq!(task.spill_threshold, None);
+        assert_eq!(task.state_ser_policy.str_truncate_after, usize::MAX);
+        assert!(!task.drain_on_error && !task.dry_commit && !task.skip_invalid);
+        assert!(!task.bindings[0].passthrough && task.bindings[0].projection.is_none());
+
+        let task = task_fixture_with(
+            2,
+            capture_request_ext::Open {
+                ack_batch_size: 5,
+                client_blocked_threshold_ms: 250,
+                drain_on_error: true,
+                dry_commit: true,
+                idle_heartbeat_ms: 1_000,
+                inference_stable_after: 7,
+                max_run_duration_ms: 60_000,
+                max_state_bytes: 1 << 20,
+                poll_timeout_ms: 30_000,
+                restart_jitter_ms: 5_000,
+                sample_rate: 0.5,
+                skip_invalid: true,
+                spill_threshold_bytes: 1 << 16,
+                state_ser_policy: Some(flow::SerPolicy {
+                    str_truncate_after: 100,
+                    ..Default::default()
+                }),
+                validate_captured: true,
+                bindings: vec![
+                    Default::default(),
+                    capture_request_ext::open::Binding {
+                        connector_partitions: true,
+                        passthrough: true,
+                        ser_policy: Some(flow::SerPolicy {
+                            str_truncate_after: 1,
+                            ..Default::default()
+                        }),
+                        ..Default::default()
+                    },
+                ],
+                ..Default::default()
+            },
+        );
+
+        assert_eq!(task.ack_batch_size, 5);
+        assert_eq!(
+            task.client_blocked_threshold,
+            Some(Duration::from_millis(250))
+        );
+        assert!(task.drain_on_error && task.dry_commit);
+        assert_eq!(task.idle_heartbeat, Some(Duration::from_secs(1)));
+        assert_eq!(task.inference_stable_after, 7);
+        assert_eq!(task.max_run_duration, Some(Duration::from_secs(60)));
+        assert_eq!(task.max_state_bytes, Some(1 << 20));
+        assert_eq!(task.poll_timeout, Some(Duration::from_secs(30)));
+        assert_eq!(task.restart_jitter, Some(Duration::from_secs(5)));
+        assert_eq!(task.sample_rate, 0.5);
+        assert!(task.skip_invalid && task.validate_captured);
+        assert_eq!(task.spill_threshold, Some(1 << 16));
+        assert_eq!(task.state_ser_policy.str_truncate_after, 100);
+
+        // Binding zero has default options. Binding one's string truncation
+        // is clamped to the length of the UUID placeholder.
+        assert!(!task.bindings[0].passthrough && !task.bindings[0].connector_partitions);
+        assert!(task.bindings[1].passthrough && task.bindings[1].connector_partitions);
+        assert_eq!(
+            task.bindings[1].ser_policy.str_truncate_after,
+            crate::UUID_PLACEHOLDER.len()
+        );
+    }
+}
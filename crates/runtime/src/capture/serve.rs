@@ -1,4 +1,4 @@
-use super::{connector, protocol::*, RequestStream, ResponseStream, Task, Transaction};
+use super::{connector, protocol::*, RequestStream, ResponseStream, SortBuffer, Task, Transaction};
 use crate::{rocksdb::RocksDB, verify, LogHandler, Runtime};
 use anyhow::Context;
 use futures::channel::oneshot;
@@ -95,11 +95,14 @@ async fn serve_session<L: LogHandler>(
     let (mut connector_tx, mut connector_rx) = connector::start(runtime, open.clone()).await?;
     let opened = TryStreamExt::try_next(&mut connector_rx).await?;
 
-    let (task, task_clone, mut shapes, accumulator, mut next_accumulator, opened) =
+    let (mut task, task_clone, mut shapes, accumulator, mut next_accumulator, opened) =
         recv_connector_opened(db, open, opened, shapes_by_key).await?;
 
     () = co.yield_(opened).await;
 
+    // Validators of passthrough bindings, which bypass the combiner.
+    let mut validators = task.passthrough_validators()?;
+
     // Spawn a task that reads the first transaction from the connector.
     let (mut yield_tx, yield_rx) = oneshot::channel();
     let mut next_txn = tokio::spawn(read_transaction(
@@ -110,7 +113,8 @@ async fn serve_session<L: LogHandler>(
         yield_rx,
     ));
 
-    let mut last_checkpoints: u32 = 0; // Checkpoints in the last transaction.
+    let mut unacked_checkpoints: u32 = 0; // Committed checkpoints not yet acknowledged.
+    let mut idle = false; // Did the last poll find no ready transaction?
     let mut buf = bytes::BytesMut::new();
     loop {
         // Receive initial request of a transaction: Acknowledge, Open, or EOF.
@@ -138,7 +142,7 @@ async fn serve_session<L: LogHandler>(
         };
 
         // Acknowledge committed checkpoints to the connector.
-        if let Some(ack) = send_connector_acknowledge(&mut last_checkpoints, &task) {
+        if let Some(ack) = send_connector_acknowledge(&mut unacked_checkpoints, idle, &task) {
             tokio::select! {
                 _ = tokio::time::sleep(std::time::Duration::from_secs(10)) => anyhow::bail!(
                     "connector requested acknowledgements but is not processing its input",
@@ -163,8 +167,10 @@ async fn serve_session<L: LogHandler>(
         ));
         yield_tx = next_yield_tx;
 
-        let (ready, response) = send_client_poll_result(&mut buf, &task, &txn);
+        let (ready, response) = send_client_poll_result(&mut buf, &task, &mut txn);
         () = co.yield_(response).await;
+        task.record_poll(&txn, ready);
+        idle = !ready;
 
         if !ready {
             next_accumulator = accumulator;
@@ -172,6 +178,7 @@ async fn serve_session<L: LogHandler>(
         }
 
         // Prepare to drain `accumulator`.
+        txn.spilled_bytes = accumulator.spilled_bytes();
         let mut drainer = accumulator
             .into_drainer()
             .context("preparing to drain combiner")?;
@@ -179,7 +186,28 @@ async fn serve_session<L: LogHandler>(
         // Atomic WriteBatch into which we'll stage connector and runtime state updates.
         let mut wb = rocksdb::WriteBatch::default();
 
+        // Send documents of passthrough bindings in their captured order,
+        // ahead of combined documents and the connector state update.
+        for (binding, doc, partitions) in std::mem::take(&mut txn.passthrough) {
+            let response = send_client_passthrough(
+                &mut buf,
+                binding,
+                doc,
+                partitions,
+                &mut shapes,
+                &task,
+                &mut txn,
+                &mut validators,
+            )?;
+            () = yield_to_client(co, response, &mut txn).await;
+        }
+
+        // Documents of bindings having a sort extractor are re-ordered in bounded runs.
+        let mut sorter = SortBuffer::default();
+        let mut ready = Vec::new();
+
         while let Some(drained) = drainer.drain_next()? {
+            let sort_value = drained_sort_value(&mut buf, &drained, &task);
             let response = send_client_captured_or_checkpoint(
                 &mut buf,
                 drained,
@@ -188,22 +216,89 @@ async fn serve_session<L: LogHandler>(
                 &mut txn,
                 &mut wb,
             );
-            () = co.yield_(response).await;
+            sorter.push(sort_value, response, &mut ready);
+
+            for response in ready.drain(..) {
+                () = yield_to_client(co, response, &mut txn).await;
+            }
+        }
+        sorter.flush(&mut ready);
+        for response in ready.drain(..) {
+            () = yield_to_client(co, response, &mut txn).await;
         }
 
         let checkpoint = send_client_final_checkpoint(&mut buf, &task, &txn);
-        () = co.yield_(checkpoint).await;
+        () = yield_to_client(co, checkpoint, &mut txn).await;
+
+        if matches!(task.client_blocked_threshold, Some(threshold) if txn.client_blocked >= threshold)
+        {
+            tracing::warn!(
+                client_blocked = ?txn.client_blocked,
+                threshold = ?task.client_blocked_threshold,
+                checkpoints = txn.checkpoints,
+                "capture was blocked on a slow client while sending its transaction",
+            );
+        }
 
         let start_commit = request_rx.try_next().await?;
         recv_client_start_commit(&db, start_commit, &shapes, &task, &txn, wb).await?;
 
         () = co.yield_(send_client_started_commit()).await;
 
-        last_checkpoints = txn.checkpoints;
+        task.record_last_documents(&txn);
+
+        for (binding, doc_json) in std::mem::take(&mut txn.sampled) {
+            tracing::debug!(
+                collection = %task.bindings[binding as usize].collection_name,
+                doc = %doc_json,
+                "sampled captured document",
+            );
+        }
+
+        for (collection, prior, reduced) in
+            task.complexity_limited_bindings(&txn, tokio::time::Instant::now())
+        {
+            tracing::warn!(
+                %collection,
+                prior_complexity = prior,
+                reduced_complexity = reduced,
+                limit = doc::shape::limits::DEFAULT_SCHEMA_COMPLEXITY_LIMIT,
+                "inferred schema of the captured collection exceeds the complexity limit and was simplified",
+            );
+        }
+        for (collection, commits) in task.record_inference_stability(&txn) {
+            tracing::info!(
+                %collection,
+                commits,
+                "inferred schema of the captured collection is stable",
+            );
+        }
+        tracing::debug!(bindings = ?task.describe_bindings(), "committed capture transaction");
+
+        // Now that its prior checkpoints are committed, surface a deferred connector error.
+        if let Some(err) = txn.connector_error.take() {
+            return Err(
+                err.context("capture connector failed after committing its prior checkpoints")
+            );
+        }
+
+        unacked_checkpoints += txn.checkpoints;
         next_accumulator = drainer.into_new_accumulator()?;
     }
 }
 
+// Yield a drained `response` to the client, and account for the time spent
+// blocked until the client has consumed it.
+pub async fn yield_to_client(
+    co: &mut coroutines::Suspend<Response, ()>,
+    response: Response,
+    txn: &mut Transaction,
+) {
+    let started_at = std::time::Instant::now();
+    () = co.yield_(response).await;
+    txn.client_blocked += started_at.elapsed();
+}
+
 pub async fn read_transaction<R: ResponseStream + FusedStream + Unpin>(
     mut accumulator: doc::combine::Accumulator,
     mut connector_rx: R,
@@ -218,13 +313,25 @@ pub async fn read_transaction<R: ResponseStream + FusedStream + Unpin>(
 
     // Loop over one or more response checkpoints.
     loop {
+        // Stop reading the connector once the task exceeds its maximum run duration.
+        // We only check in between checkpoints, so checkpoints already folded into
+        // this transaction are committed as usual.
+        if !txn.connector_eof && task.run_expired() {
+            tracing::info!(
+                max_run_duration = ?task.max_run_duration,
+                checkpoints = txn.checkpoints,
+                "capture reached its maximum run duration and will drain and restart",
+            );
+            txn.connector_eof = true;
+        }
+
         let (woken, initial) = tokio::select! {
-            initial = connector_rx.try_next(), if !txn.connector_eof && txn.captured_bytes < super::COMBINER_BYTE_THRESHOLD => (false, initial?),
-            _ = &mut timeout => (true, None),
-            _ = &mut yield_rx => (true, None),
+            initial = connector_rx.try_next(), if !txn.connector_eof && txn.captured_bytes < super::COMBINER_BYTE_THRESHOLD => (false, initial),
+            _ = &mut timeout => (true, Ok(None)),
+            _ = &mut yield_rx => (true, Ok(None)),
         };
         match (woken, initial) {
-            (false, Some(initial)) => {
+            (false, Ok(Some(initial))) => {
                 if txn.checkpoints == 0 {
                     txn.started_at = std::time::SystemTime::now();
                 }
@@ -243,9 +350,25 @@ pub async fn read_transaction<R: ResponseStream + FusedStream + Unpin>(
                     return Ok((accumulator, connector_rx, task, txn));
                 }
             }
-            (false, None) => {
+            (false, Ok(None)) => {
+                txn.connector_eof = true;
+            }
+            // The connector failed in between checkpoints. Documents of the
+            // checkpoints already read are intact, and are committed before the
+            // error is surfaced. An error which interrupts a partially-read
+            // checkpoint is instead returned by `read_checkpoint` above, because
+            // its documents have already been combined with those of prior
+            // checkpoints and the transaction cannot be committed.
+            (false, Err(err)) if task.drain_on_error && txn.checkpoints != 0 => {
+                tracing::warn!(
+                    error = ?err,
+                    checkpoints = txn.checkpoints,
+                    "capture connector failed; committing its prior checkpoints before failing",
+                );
                 txn.connector_eof = true;
+                txn.connector_error = Some(err);
             }
+            (false, Err(err)) => return Err(err),
             (true, _none) => {
                 // Have we been asked to yield, and either have a non-empty transaction or reached our timeout?
                 if yield_rx.is_terminated() && (txn.checkpoints != 0 || timeout.is_terminated()) {
@@ -264,8 +387,8 @@ async fn read_checkpoint(
     txn: &mut Transaction,
 ) -> anyhow::Result<()> {
     // Read all Captured responses of the checkpoint.
-    while let Some(captured) = response.captured {
-        recv_connector_captured(accumulator, captured, task, txn)?;
+    while response.captured.is_some() {
+        recv_connector_captured(accumulator, response, task, txn)?;
 
         // Read next response.
         response = match connector_rx.try_next().await? {
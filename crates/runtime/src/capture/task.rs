@@ -2,6 +2,7 @@ use super::{Binding, Task};
 use anyhow::Context;
 use proto_flow::capture::{request, response, Request, Response};
 use proto_flow::flow;
+use proto_flow::runtime::capture_request_ext;
 use std::collections::BTreeMap;
 
 impl Task {
@@ -17,6 +18,27 @@ impl Task {
             explicit_acknowledgements,
         } = opened.clone().opened.context("expected Opened")?;
 
+        let capture_request_ext::Open {
+            ack_batch_size,
+            bindings: binding_options,
+            client_blocked_threshold_ms,
+            drain_on_error,
+            dry_commit,
+            idle_heartbeat_ms,
+            inference_stable_after,
+            max_run_duration_ms,
+            max_state_bytes,
+            poll_timeout_ms,
+            reset_state: _,
+            restart_jitter_ms,
+            sample_rate,
+            skip_invalid,
+            spill_threshold_bytes,
+            state_ser_policy,
+            strict_state_keys: _,
+            validate_captured,
+        } = open.get_internal()?.open.unwrap_or_default();
+
         let flow::CaptureSpec {
             bindings,
             config_json: _,
@@ -33,12 +55,40 @@ impl Task {
             anyhow::bail!("captures cannot split on r-clock: {range:?}");
         }
 
-        let ser_policy = doc::SerPolicy::noop();
+        // Bindings having the same state_key would silently share connector state.
+        // Bindings without a state_key are not checked.
+        let mut state_keys = BTreeMap::<&str, usize>::new();
+        for (index, binding) in bindings.iter().enumerate() {
+            if binding.state_key.is_empty() {
+                continue;
+            }
+            if let Some(prior) = state_keys.insert(&binding.state_key, index) {
+                anyhow::bail!(
+                    "bindings {prior} and {index} of capture {name} have the same state_key {:?} (resource path {:?})",
+                    binding.state_key,
+                    binding.resource_path,
+                );
+            }
+        }
+
+        if binding_options.len() > bindings.len() {
+            anyhow::bail!(
+                "capture {name} has {} bindings but options for {}",
+                bindings.len(),
+                binding_options.len(),
+            );
+        }
+        if !(0.0..=1.0).contains(&sample_rate) {
+            anyhow::bail!("sample_rate {sample_rate} of capture {name} is not within [0, 1]");
+        }
 
         let bindings = bindings
             .into_iter()
             .enumerate()
-            .map(|(index, spec)| Binding::new(spec, ser_policy.clone()).context(index))
+            .map(|(index, spec)| {
+                let options = binding_options.get(index).cloned().unwrap_or_default();
+                Binding::new(spec, options).context(index)
+            })
             .collect::<Result<Vec<_>, _>>()?;
 
         let restart = std::time::Duration::from_secs(*interval_seconds as u64);
@@ -51,14 +101,188 @@ impl Task {
             r_clock_begin: format!("{:08x}", range.r_clock_begin),
         };
 
+        // Zero-valued options of the Open extension select their defaults.
+        let millis = |ms: u64| (ms != 0).then(|| std::time::Duration::from_millis(ms));
+        let bytes = |n: u64| (n != 0).then_some(n as usize);
+
         Ok(Self {
+            ack_batch_size: ack_batch_size.max(1),
             bindings,
+            checkpointed_at: tokio::time::Instant::now(),
+            client_blocked_threshold: millis(client_blocked_threshold_ms),
+            committed_at: tokio::time::Instant::now(),
+            drain_on_error,
+            dry_commit,
             explicit_acknowledgements,
+            idle_heartbeat: millis(idle_heartbeat_ms),
+            inference_stable_after: match inference_stable_after {
+                0 => super::INFERENCE_STABLE_AFTER,
+                n => n,
+            },
+            max_run_duration: millis(max_run_duration_ms),
+            max_state_bytes: bytes(max_state_bytes),
+            opened_at: tokio::time::Instant::now(),
+            poll_timeout: millis(poll_timeout_ms),
             restart,
+            restart_jitter: millis(restart_jitter_ms),
+            sample_rate,
             shard_ref,
+            skip_invalid,
+            spill_threshold: bytes(spill_threshold_bytes),
+            state_ser_policy: state_ser_policy
+                .as_ref()
+                .map(extractors::map_policy)
+                .unwrap_or_else(doc::SerPolicy::noop),
+            validate_captured,
         })
     }
 
+    /// Returns true if this Task has been open for longer than its `max_run_duration`.
+    /// An expired Task reads no further connector checkpoints: those already read
+    /// are drained and committed, and the session then restarts as though the
+    /// connector had exited.
+    pub fn run_expired(&self) -> bool {
+        matches!(self.max_run_duration, Some(max) if self.opened_at.elapsed() >= max)
+    }
+
+    /// Returns true if the connector has produced no checkpoints for longer
+    /// than this Task's `poll_timeout`.
+    pub fn poll_timed_out(&self) -> bool {
+        matches!(self.poll_timeout, Some(timeout) if self.checkpointed_at.elapsed() >= timeout)
+    }
+
+    /// Returns true if an idle heartbeat is due, because this Task hasn't
+    /// committed a transaction for longer than its `idle_heartbeat`.
+    pub fn heartbeat_due(&self) -> bool {
+        matches!(self.idle_heartbeat, Some(idle) if self.committed_at.elapsed() >= idle)
+    }
+
+    /// Returns the delay of this Task's restart beyond its `restart` instant,
+    /// which is a deterministic offset within `[0, restart_jitter)` derived
+    /// from a hash of the Task's ShardRef.
+    pub fn restart_delay(&self) -> std::time::Duration {
+        let Some(window) = self.restart_jitter.filter(|w| !w.is_zero()) else {
+            return std::time::Duration::ZERO;
+        };
+        let ops::ShardRef {
+            kind: _,
+            name,
+            key_begin,
+            r_clock_begin,
+        } = &self.shard_ref;
+
+        let shard = [name.as_str(), key_begin, r_clock_begin].join("\n");
+        let hash = xxhash_rust::xxh3::xxh3_64(shard.as_bytes());
+        std::time::Duration::from_nanos(hash % window.as_nanos() as u64)
+    }
+
+    /// Returns true if this Task is eligible for restart, having
+    /// passed its `restart` instant and its `restart_delay`.
+    pub fn restart_eligible(&self) -> bool {
+        self.restart.elapsed() > self.restart_delay()
+    }
+
+    /// Returns true if the next captured document of a binding, which has
+    /// already read `read` documents this transaction, should be sampled.
+    /// Sampling is deterministic: over `n` documents, `floor(n * sample_rate)`
+    /// are sampled at an even spacing.
+    pub fn should_sample(&self, read: u64) -> bool {
+        self.sample_rate > 0.0
+            && ((read + 1) as f64 * self.sample_rate).floor()
+                > (read as f64 * self.sample_rate).floor()
+    }
+
+    /// Record that the connector produced checkpoints within the polled
+    /// Transaction, and whether it was `ready` to commit. Transactions
+    /// without checkpoints don't reset the poll timeout, even if they're
+    /// ready heartbeats.
+    pub fn record_poll(&mut self, txn: &super::Transaction, ready: bool) {
+        let now = tokio::time::Instant::now();
+
+        if txn.checkpoints != 0 {
+            self.checkpointed_at = now;
+        }
+        if ready {
+            self.committed_at = now;
+        }
+    }
+
+    /// Record the times at which bindings last captured a document within
+    /// the Transaction. Bindings which were idle are unchanged.
+    pub fn record_last_documents(&mut self, txn: &super::Transaction) {
+        for (index, last_document_at) in txn.last_document_at.iter() {
+            self.bindings[*index as usize].last_document_at = Some(*last_document_at);
+        }
+    }
+
+    /// Record the stability of the inferred Shape of each binding as of the
+    /// committed Transaction. A binding's streak of stable commits resets when
+    /// its Shape is updated, and extends with each commit in which it captured
+    /// documents without updating its Shape. Idle bindings are unchanged.
+    /// Returns bindings whose streak just reached `inference_stable_after`,
+    /// as their target collection and streak.
+    pub fn record_inference_stability(&mut self, txn: &super::Transaction) -> Vec<(&str, u64)> {
+        let mut stable = Vec::new();
+
+        for (index, binding) in self.bindings.iter_mut().enumerate() {
+            if txn.updated_inferences.contains(&index) {
+                binding.stable_inference_commits = 0;
+            } else if txn.stats.contains_key(&(index as u32)) {
+                binding.stable_inference_commits += 1;
+
+                if binding.stable_inference_commits == self.inference_stable_after {
+                    stable.push((
+                        binding.collection_name.as_str(),
+                        binding.stable_inference_commits,
+                    ));
+                }
+            }
+        }
+        stable
+    }
+
+    /// Describe each binding of this Task as its target collection, the
+    /// time at which it last captured a document in this session (if ever),
+    /// and its number of consecutive commits without an inferred Shape update.
+    pub fn describe_bindings(&self) -> Vec<(&str, Option<std::time::SystemTime>, u64)> {
+        self.bindings
+            .iter()
+            .map(|binding| {
+                (
+                    binding.collection_name.as_str(),
+                    binding.last_document_at,
+                    binding.stable_inference_commits,
+                )
+            })
+            .collect()
+    }
+
+    /// Returns bindings whose inferred Shape was reduced by the complexity limit
+    /// within the Transaction, as their target collection and (prior, reduced)
+    /// complexity. A binding is returned at most once per
+    /// COMPLEXITY_WARNING_INTERVAL, as of `now`.
+    pub fn complexity_limited_bindings(
+        &mut self,
+        txn: &super::Transaction,
+        now: tokio::time::Instant,
+    ) -> Vec<(&str, usize, usize)> {
+        let mut limited = Vec::new();
+
+        for (index, binding) in self.bindings.iter_mut().enumerate() {
+            let Some((prior, reduced)) = txn.complexity_limited.get(&(index as u32)) else {
+                continue;
+            };
+            if let Some(warned_at) = binding.complexity_warned_at {
+                if now.duration_since(warned_at) < super::COMPLEXITY_WARNING_INTERVAL {
+                    continue;
+                }
+            }
+            binding.complexity_warned_at = Some(now);
+            limited.push((binding.collection_name.as_str(), *prior, *reduced));
+        }
+        limited
+    }
+
     pub fn binding_shapes_by_index(
         &self,
         mut by_key: BTreeMap<String, doc::Shape>,
@@ -87,6 +311,48 @@ impl Task {
         by_key
     }
 
+    /// Build the current inferred JSON schema of each target collection from
+    /// `shapes`, which are indexed by binding and are not modified. Bindings
+    /// which capture into the same collection have their Shapes unioned.
+    pub fn inferred_schemas(&self, shapes: &[doc::Shape]) -> BTreeMap<String, serde_json::Value> {
+        let mut by_collection = BTreeMap::<&str, doc::Shape>::new();
+
+        for (binding, shape) in self.bindings.iter().zip(shapes) {
+            let shape = match by_collection.remove(binding.collection_name.as_str()) {
+                Some(prior) => doc::Shape::union(prior, shape.clone()),
+                None => shape.clone(),
+            };
+            by_collection.insert(&binding.collection_name, shape);
+        }
+
+        by_collection
+            .into_iter()
+            .map(|(collection, shape)| {
+                let schema = doc::shape::schema::to_schema(shape);
+                (
+                    collection.to_string(),
+                    serde_json::to_value(schema).expect("schema serialization cannot fail"),
+                )
+            })
+            .collect()
+    }
+
+    /// Build schema validators of passthrough bindings, indexed by binding.
+    /// Other bindings are validated by the combiner, and are None.
+    pub fn passthrough_validators(&self) -> anyhow::Result<Vec<Option<doc::Validator>>> {
+        self.bindings
+            .iter()
+            .enumerate()
+            .map(|(index, binding)| {
+                if binding.passthrough {
+                    binding.validator().context(index).map(Some)
+                } else {
+                    Ok(None)
+                }
+            })
+            .collect()
+    }
+
     pub fn combine_spec(&self) -> anyhow::Result<doc::combine::Spec> {
         let combiner_spec = self
             .bindings
@@ -115,12 +381,26 @@ impl Task {
 
         Ok(combiner_spec)
     }
+
+    /// Build a new combiner Accumulator of this Task, backed by a temporary
+    /// spill file and using the Task's `spill_threshold`, if set.
+    pub fn new_accumulator(&self) -> anyhow::Result<doc::combine::Accumulator> {
+        let spec = self.combine_spec()?;
+        let spill = tempfile::tempfile()?;
+
+        Ok(match self.spill_threshold {
+            Some(threshold) => {
+                doc::combine::Accumulator::with_spill_threshold(spec, spill, threshold)?
+            }
+            None => doc::combine::Accumulator::new(spec, spill)?,
+        })
+    }
 }
 
 impl Binding {
     pub fn new(
         spec: &flow::capture_spec::Binding,
-        ser_policy: doc::SerPolicy,
+        options: capture_request_ext::open::Binding,
     ) -> anyhow::Result<Self> {
         let flow::capture_spec::Binding {
             backfill: _,
@@ -143,29 +423,132 @@ impl Binding {
             write_schema_json,
         } = collection.as_ref().context("missing collection")?;
 
+        let capture_request_ext::open::Binding {
+            connector_partitions,
+            passthrough,
+            projection,
+            ser_policy,
+            sort_ptr,
+        } = options;
+
         let document_uuid_ptr = doc::Pointer::from(uuid_ptr);
-        let key_extractors = extractors::for_key(&key, &projections, &ser_policy)?;
+        let extracted_ptrs: Vec<doc::Pointer> = key
+            .iter()
+            .map(String::as_str)
+            .chain(partition_fields.iter().filter_map(|field| {
+                projections
+                    .iter()
+                    .find(|projection| &projection.field == field)
+                    .map(|projection| projection.ptr.as_str())
+            }))
+            .map(doc::Pointer::from)
+            .collect();
+
+        // Keys and partitions are extracted from documents prior to truncation.
+        let extract_policy = doc::SerPolicy::noop();
+        let key_extractors = extractors::for_key(&key, &projections, &extract_policy)?;
         let partition_extractors =
-            extractors::for_fields(&partition_fields, &projections, &ser_policy)?;
+            extractors::for_fields(&partition_fields, &projections, &extract_policy)?;
+
+        // Strings are never truncated below the length of the UUID placeholder,
+        // which is replaced with the document's UUID after serialization.
+        let ser_policy = match ser_policy {
+            Some(policy) => {
+                let policy = extractors::map_policy(&policy);
+                doc::SerPolicy {
+                    str_truncate_after: policy
+                        .str_truncate_after
+                        .max(crate::UUID_PLACEHOLDER.len()),
+                    ..policy
+                }
+            }
+            None => doc::SerPolicy::noop(),
+        };
+        // Projected documents always retain their key and partition locations.
+        // Pointers which don't exist within a captured document are skipped.
+        let projection = (!projection.is_empty()).then(|| {
+            projection
+                .iter()
+                .map(|ptr| doc::Pointer::from_str(ptr))
+                .chain(extracted_ptrs.iter().cloned())
+                .collect()
+        });
+        let sort_extractor =
+            (!sort_ptr.is_empty()).then(|| doc::Extractor::new(&sort_ptr, &doc::SerPolicy::noop()));
 
         Ok(Self {
             collection_name: name.clone(),
+            complexity_warned_at: None,
+            connector_partitions,
             document_uuid_ptr,
+            extracted_ptrs,
             key_extractors,
+            last_document_at: None,
             partition_extractors,
+            passthrough,
+            projection,
             resource_path: resource_path.clone(),
             ser_policy,
+            sort_extractor,
+            stable_inference_commits: 0,
             write_schema_json: write_schema_json.clone(),
         })
     }
 
-    pub fn combiner_spec(
+    /// Project a captured HeapNode document to the locations of this
+    /// Binding's projection, or return it unmodified if there is none.
+    pub fn project_heap_node<'m>(
         &self,
-    ) -> anyhow::Result<(bool, Vec<doc::Extractor>, String, doc::Validator)> {
+        doc: doc::HeapNode<'m>,
+        memtable: &'m doc::combine::MemTable,
+    ) -> doc::HeapNode<'m> {
+        let Some(ptrs) = &self.projection else {
+            return doc;
+        };
+        let alloc = memtable.alloc();
+        let mut projected = doc::HeapNode::Object(doc::BumpVec::new());
+
+        for ptr in ptrs {
+            let Some(value) = ptr.query(&doc) else {
+                continue;
+            };
+            if let Some(node) = ptr.create_heap_node(&mut projected, alloc) {
+                *node = doc::HeapNode::from_node(value, alloc);
+            }
+        }
+        projected
+    }
+
+    /// Project a captured passthrough document to the locations of this
+    /// Binding's projection, or return it unmodified if there is none.
+    pub fn project_value(&self, doc: serde_json::Value) -> serde_json::Value {
+        let Some(ptrs) = &self.projection else {
+            return doc;
+        };
+        let mut projected = serde_json::Value::Object(Default::default());
+
+        for ptr in ptrs {
+            let Some(value) = ptr.query(&doc) else {
+                continue;
+            };
+            if let Some(node) = ptr.create_value(&mut projected) {
+                *node = value.clone();
+            }
+        }
+        projected
+    }
+
+    pub fn validator(&self) -> anyhow::Result<doc::Validator> {
         let built_schema = doc::validation::build_bundle(&self.write_schema_json)
             .context("collection write_schema_json is not a JSON schema")?;
-        let validator =
-            doc::Validator::new(built_schema).context("could not build a schema validator")?;
+
+        doc::Validator::new(built_schema).context("could not build a schema validator")
+    }
+
+    pub fn combiner_spec(
+        &self,
+    ) -> anyhow::Result<(bool, Vec<doc::Extractor>, String, doc::Validator)> {
+        let validator = self.validator()?;
 
         Ok((
             false,
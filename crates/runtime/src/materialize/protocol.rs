@@ -442,6 +442,7 @@ pub fn send_client_flushed(buf: &mut bytes::BytesMut, task: &Task, txn: &Transac
         }),
         open_seconds_total: txn.started_at.elapsed().unwrap().as_secs_f64(),
         shard: Some(task.shard_ref.clone()),
+        spilled_bytes_total: 0,
         timestamp: Some(proto_flow::as_timestamp(txn.started_at)),
         txn_count: 1,
     };
@@ -180,6 +180,27 @@ impl RocksDB {
         Ok(initial)
     }
 
+    /// Reset a persisted connector state to `initial`, discarding any current state.
+    pub async fn reset_connector_state(
+        &self,
+        initial: models::RawValue,
+    ) -> anyhow::Result<models::RawValue> {
+        let mut wo = rocksdb::WriteOptions::default();
+        wo.set_sync(true);
+
+        // A PUT supersedes the current value and any of its queued MERGE operands.
+        let mut wb = rocksdb::WriteBatch::default();
+        wb.put(Self::CONNECTOR_STATE_KEY, initial.get());
+
+        self.write_opt(wb, wo)
+            .await
+            .context("put-ing reset connector state")?;
+
+        tracing::info!(state=?ops::DebugJson(&initial), "reset the persisted connector state");
+
+        Ok(initial)
+    }
+
     // Key encoding under which the last-applied specification is stored.
     pub const LAST_APPLIED: &'static str = "last-applied";
     // Key encoding under which a marshalled checkpoint is stored.
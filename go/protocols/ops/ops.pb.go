@@ -319,11 +319,14 @@ type Stats struct {
 	Capture map[string]*Stats_Binding `protobuf:"bytes,6,rep,name=capture,proto3" json:"capture,omitempty" protobuf_key:"bytes,1,opt,name=key,proto3" protobuf_val:"bytes,2,opt,name=value,proto3"`
 	Derive  *Stats_Derive             `protobuf:"bytes,7,opt,name=derive,proto3" json:"derive,omitempty"`
 	// Materialization metrics.
-	Materialize          map[string]*Stats_Binding `protobuf:"bytes,8,rep,name=materialize,proto3" json:"materialize,omitempty" protobuf_key:"bytes,1,opt,name=key,proto3" protobuf_val:"bytes,2,opt,name=value,proto3"`
-	Interval             *Stats_Interval           `protobuf:"bytes,9,opt,name=interval,proto3" json:"interval,omitempty"`
-	XXX_NoUnkeyedLiteral struct{}                  `json:"-"`
-	XXX_unrecognized     []byte                    `json:"-"`
-	XXX_sizecache        int32                     `json:"-"`
+	Materialize map[string]*Stats_Binding `protobuf:"bytes,8,rep,name=materialize,proto3" json:"materialize,omitempty" protobuf_key:"bytes,1,opt,name=key,proto3" protobuf_val:"bytes,2,opt,name=value,proto3"`
+	Interval    *Stats_Interval           `protobuf:"bytes,9,opt,name=interval,proto3" json:"interval,omitempty"`
+	// Bytes spilled to disk by the task's combiner, upon its in-memory
+	// documents exceeding the task's spill threshold.
+	SpilledBytesTotal    uint64   `protobuf:"varint,10,opt,name=spilled_bytes_total,json=spilledBytesTotal,proto3" json:"spilled_bytes_total,omitempty"`
+	XXX_NoUnkeyedLiteral struct{} `json:"-"`
+	XXX_unrecognized     []byte   `json:"-"`
+	XXX_sizecache        int32    `json:"-"`
 }
 
 func (m *Stats) Reset()         { *m = Stats{} }
@@ -409,6 +412,8 @@ type Stats_Binding struct {
 	Left                 *Stats_DocsAndBytes `protobuf:"bytes,1,opt,name=left,proto3" json:"left,omitempty"`
 	Right                *Stats_DocsAndBytes `protobuf:"bytes,2,opt,name=right,proto3" json:"right,omitempty"`
 	Out                  *Stats_DocsAndBytes `protobuf:"bytes,3,opt,name=out,proto3" json:"out,omitempty"`
+	// Documents of a capture which were skipped because they were invalid.
+	Skipped              *Stats_DocsAndBytes `protobuf:"bytes,4,opt,name=skipped,proto3" json:"skipped,omitempty"`
 	XXX_NoUnkeyedLiteral struct{}            `json:"-"`
 	XXX_unrecognized     []byte              `json:"-"`
 	XXX_sizecache        int32               `json:"-"`
@@ -610,77 +615,78 @@ func init() {
 func init() { proto.RegisterFile("go/protocols/ops/ops.proto", fileDescriptor_37de94a5cb9d0036) }
 
 var fileDescriptor_37de94a5cb9d0036 = []byte{
-	// 1112 bytes of a gzipped FileDescriptorProto
-	0x1f, 0x8b, 0x08, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0xff, 0xc4, 0x56, 0x41, 0x6f, 0xe3, 0x44,
-	0x14, 0xae, 0x93, 0x38, 0x8d, 0x9f, 0xb7, 0xad, 0x99, 0x5d, 0x81, 0x95, 0x5d, 0xd2, 0x6e, 0x00,
-	0x29, 0xbb, 0x0b, 0x89, 0x36, 0x08, 0x09, 0xad, 0x84, 0xc4, 0xa6, 0x0b, 0x12, 0xa8, 0xad, 0x60,
-	0x9a, 0x53, 0x2f, 0xd6, 0xc4, 0x9e, 0xb8, 0x26, 0xce, 0x8c, 0xe5, 0x19, 0xb7, 0x1b, 0x8e, 0x1c,
-	0x39, 0xf1, 0x13, 0x38, 0xf3, 0x4b, 0x7a, 0x42, 0xf0, 0x03, 0x00, 0xb1, 0xfc, 0x11, 0x34, 0x33,
-	0x76, 0xea, 0x6d, 0xbb, 0xac, 0x10, 0x07, 0x0e, 0xad, 0xde, 0x7c, 0xef, 0x9b, 0x99, 0xf7, 0xde,
-	0xf7, 0xde, 0x38, 0xd0, 0x8d, 0xf9, 0x28, 0xcb, 0xb9, 0xe4, 0x21, 0x4f, 0xc5, 0x88, 0x67, 0xfa,
-	0x6f, 0xa8, 0x11, 0xd4, 0xe4, 0x99, 0xe8, 0xde, 0x7b, 0x89, 0x30, 0x4f, 0xf9, 0xb9, 0xfe, 0x67,
-	0x28, 0xdd, 0x3b, 0x31, 0x8f, 0xb9, 0x36, 0x47, 0xca, 0x2a, 0xd1, 0xdd, 0x98, 0xf3, 0x38, 0xa5,
-	0x66, 0xdf, 0xac, 0x98, 0x8f, 0x64, 0xb2, 0xa4, 0x42, 0x92, 0x65, 0x66, 0x08, 0xfd, 0x9f, 0x1a,
-	0xb0, 0x75, 0x7c, 0x4a, 0xf2, 0xe8, 0x80, 0xcc, 0x68, 0x9a, 0xb0, 0x18, 0xdd, 0x01, 0x7b, 0x56,
-	0x24, 0x69, 0xe4, 0x5b, 0x7b, 0xd6, 0xc0, 0xc1, 0x66, 0x81, 0xba, 0xd0, 0x39, 0xe5, 0x42, 0x32,
-	0xb2, 0xa4, 0x7e, 0x43, 0x3b, 0xd6, 0x6b, 0xf4, 0x08, 0x9c, 0x94, 0xc7, 0x41, 0x4a, 0xcf, 0x68,
-	0xea, 0x37, 0xf7, 0xac, 0xc1, 0xf6, 0x78, 0x7b, 0xa8, 0x82, 0x3f, 0xe0, 0xf1, 0xf0, 0x40, 0xa1,
-	0xb8, 0x93, 0xf2, 0x58, 0x5b, 0xe8, 0x11, 0xd8, 0x39, 0x61, 0x31, 0xf5, 0xed, 0x3d, 0x6b, 0xe0,
-	0x8e, 0x77, 0x86, 0x3a, 0x07, 0xac, 0xa0, 0xe3, 0x8c, 0x86, 0x93, 0xd6, 0xc5, 0xef, 0xbb, 0x1b,
-	0xd8, 0x70, 0xd0, 0x7d, 0xb8, 0x25, 0xb2, 0x34, 0x91, 0x81, 0xe0, 0x45, 0x1e, 0x52, 0xbf, 0xad,
-	0x6f, 0x76, 0x35, 0x76, 0xac, 0xa1, 0x4b, 0x8a, 0x24, 0x79, 0x4c, 0xa5, 0xbf, 0x59, 0xa3, 0x4c,
-	0x35, 0x84, 0xee, 0x82, 0x23, 0x89, 0x58, 0x04, 0x3a, 0xf8, 0x8e, 0x09, 0x5e, 0x01, 0x47, 0x2a,
-	0xf8, 0x87, 0xa5, 0x53, 0xae, 0x32, 0xea, 0x3b, 0x3a, 0xf8, 0x2d, 0x1d, 0xfc, 0x94, 0x88, 0xc5,
-	0x74, 0x95, 0x51, 0xc3, 0x55, 0x56, 0xff, 0x3b, 0x0b, 0x3a, 0xba, 0x58, 0x98, 0xce, 0xd1, 0x7d,
-	0x68, 0x2d, 0x12, 0x66, 0xca, 0x74, 0x6d, 0x8f, 0x76, 0x21, 0x04, 0xad, 0x5a, 0xc1, 0xb4, 0xad,
-	0x82, 0x59, 0xd0, 0x55, 0x30, 0xa3, 0x71, 0xc2, 0x74, 0xb1, 0x1c, 0xdc, 0x59, 0xd0, 0xd5, 0x44,
-	0xad, 0x51, 0x1f, 0xb6, 0xf2, 0x20, 0x4c, 0x79, 0xb8, 0x28, 0x09, 0x2d, 0x93, 0x4d, 0xbe, 0xaf,
-	0x30, 0xcd, 0xe9, 0x77, 0xa1, 0x75, 0x48, 0x25, 0x51, 0x87, 0x17, 0x45, 0x52, 0xc9, 0xa4, 0xed,
-	0xfe, 0xcf, 0x4d, 0x68, 0x1e, 0xf0, 0x18, 0xf5, 0xa0, 0xb5, 0xa4, 0x92, 0x68, 0x9f, 0x3b, 0x76,
-	0x74, 0x6c, 0x6a, 0x13, 0xb6, 0x03, 0x85, 0xa3, 0x77, 0xc0, 0x16, 0x2a, 0x0f, 0x1d, 0x99, 0x5b,
-	0x06, 0x5f, 0x65, 0x86, 0x8d, 0x0f, 0x3d, 0x06, 0x67, 0xdd, 0x2d, 0x3a, 0x52, 0x77, 0xdc, 0x1d,
-	0x9a, 0x7e, 0x1a, 0x56, 0xfd, 0x34, 0x9c, 0x56, 0x0c, 0xdc, 0x90, 0x02, 0xbd, 0x0b, 0xb6, 0xe9,
-	0x82, 0xd6, 0x8d, 0x5d, 0x60, 0x9c, 0xc8, 0x87, 0xcd, 0x25, 0x15, 0x82, 0x94, 0x4d, 0xe0, 0xe0,
-	0x6a, 0x89, 0x4e, 0x60, 0x67, 0x9e, 0xd0, 0x34, 0x12, 0xc1, 0x37, 0x82, 0xb3, 0x60, 0x49, 0x32,
-	0xbf, 0xbd, 0xd7, 0x1c, 0xb8, 0xe3, 0xbb, 0xeb, 0x93, 0x3e, 0xd7, 0xfe, 0x2f, 0x05, 0x67, 0x87,
-	0x24, 0xfb, 0x8c, 0xc9, 0x7c, 0x35, 0xb9, 0xf7, 0xfd, 0x1f, 0xbb, 0x3e, 0x65, 0x21, 0x8f, 0x12,
-	0x16, 0x8f, 0xd4, 0xce, 0x21, 0x26, 0xe7, 0x87, 0xe6, 0x4c, 0xdc, 0x36, 0x27, 0xa2, 0x1e, 0xd8,
-	0x22, 0x23, 0x4c, 0xf8, 0x9b, 0xfa, 0xc4, 0x4e, 0x75, 0x22, 0x36, 0x70, 0xf7, 0x53, 0x40, 0xd7,
-	0xcf, 0x46, 0x1e, 0x34, 0x17, 0x74, 0x55, 0x16, 0x59, 0x99, 0x6a, 0x3e, 0xce, 0x48, 0x5a, 0x54,
-	0xaa, 0x9a, 0xc5, 0x93, 0xc6, 0xc7, 0x56, 0xff, 0x6b, 0xb0, 0x4d, 0x8f, 0xdf, 0x86, 0x9d, 0x82,
-	0x45, 0x74, 0x9e, 0x30, 0x1a, 0x99, 0xb1, 0xf0, 0x36, 0x90, 0x03, 0x36, 0xcd, 0x73, 0x9e, 0x7b,
-	0x16, 0xea, 0x40, 0xeb, 0x9c, 0xe4, 0xcc, 0x6b, 0x28, 0x2b, 0x61, 0x73, 0xee, 0x35, 0x95, 0x3b,
-	0xa2, 0xb3, 0x22, 0xf6, 0x5a, 0xca, 0x94, 0x39, 0x09, 0xa9, 0x67, 0xf7, 0x7f, 0x75, 0xc0, 0x3e,
-	0x96, 0x44, 0x8a, 0xff, 0x4d, 0xd2, 0xf7, 0x01, 0xf1, 0x8c, 0xb2, 0x40, 0xd0, 0x90, 0xb3, 0x48,
-	0x04, 0x92, 0x4b, 0x62, 0xf4, 0xb5, 0xb0, 0xa7, 0x3c, 0xc7, 0xc6, 0x31, 0x55, 0xb8, 0x1e, 0xb5,
-	0xe7, 0x2c, 0x08, 0x79, 0xc1, 0xa4, 0x16, 0x77, 0x0b, 0x77, 0xe4, 0x73, 0xb6, 0xaf, 0xd6, 0xe8,
-	0x31, 0x6c, 0x86, 0x24, 0x93, 0x45, 0x4e, 0x4b, 0x55, 0xdf, 0x32, 0x41, 0xaa, 0xfc, 0x86, 0xfb,
-	0xc6, 0xa3, 0xab, 0x8e, 0x2b, 0x1e, 0x7a, 0x00, 0xed, 0x88, 0xe6, 0xc9, 0x19, 0xd5, 0x73, 0xed,
-	0x8e, 0xdf, 0xa8, 0xed, 0x78, 0xa6, 0x1d, 0xb8, 0x24, 0xa0, 0x4f, 0xc0, 0x5d, 0x12, 0x49, 0xf3,
-	0x84, 0xa4, 0xc9, 0xb7, 0x6a, 0xce, 0x2f, 0xfb, 0xc6, 0xf0, 0x0f, 0x2f, 0xbd, 0xe6, 0x96, 0x3a,
-	0x1f, 0x8d, 0xa0, 0x93, 0x30, 0x49, 0xf3, 0x33, 0x92, 0xea, 0x67, 0xc0, 0x1d, 0xdf, 0xae, 0xed,
-	0xfd, 0xa2, 0x74, 0xe1, 0x35, 0xa9, 0x7b, 0x04, 0xb7, 0x9e, 0xf1, 0x50, 0x3c, 0x65, 0xd1, 0x64,
-	0x25, 0xa9, 0x40, 0x6f, 0x03, 0x44, 0x3c, 0xac, 0x0a, 0x64, 0xe9, 0xdc, 0x1d, 0x85, 0x98, 0xca,
-	0xec, 0x82, 0x3b, 0x53, 0xbc, 0xd2, 0xaf, 0x54, 0x6a, 0x61, 0xd0, 0x90, 0x26, 0x74, 0x7f, 0xb0,
-	0x60, 0x73, 0x92, 0x30, 0xd5, 0xc3, 0xe8, 0x11, 0xb4, 0x52, 0x3a, 0x97, 0xa5, 0xd8, 0xf5, 0x32,
-	0xd5, 0xaf, 0xc4, 0x9a, 0x84, 0x3e, 0x00, 0x3b, 0x4f, 0xe2, 0x53, 0x59, 0x2a, 0xff, 0x4a, 0xb6,
-	0x61, 0xa1, 0x07, 0xd0, 0xe4, 0x85, 0x2c, 0xd5, 0x7f, 0x25, 0x59, 0x71, 0x54, 0x8a, 0x75, 0x59,
-	0x6e, 0x18, 0x86, 0x41, 0x7d, 0x18, 0xdc, 0x31, 0xaa, 0x1d, 0x57, 0xe6, 0x52, 0x1b, 0x90, 0xee,
-	0x6f, 0x0d, 0x68, 0x1b, 0xd5, 0xd0, 0x53, 0x00, 0x99, 0x13, 0x26, 0xe6, 0x3c, 0x5f, 0x0a, 0xdf,
-	0xd2, 0x62, 0xdd, 0xbf, 0x26, 0xee, 0x70, 0xba, 0xe6, 0x18, 0xc9, 0x6a, 0x9b, 0xd0, 0x47, 0xe0,
-	0x64, 0xc5, 0x2c, 0x4d, 0xc4, 0x29, 0x8d, 0x5e, 0x97, 0xfb, 0x25, 0xf3, 0xdf, 0xe4, 0x8f, 0xc1,
-	0x59, 0x07, 0x80, 0xde, 0x84, 0x76, 0xf9, 0x15, 0x32, 0xf9, 0x97, 0x2b, 0x55, 0xfe, 0x84, 0x65,
-	0xc5, 0xeb, 0xcb, 0xaf, 0x59, 0xdd, 0x13, 0xd8, 0xb9, 0x92, 0xd4, 0x0d, 0x65, 0x7d, 0xfc, 0x72,
-	0x59, 0xef, 0xfe, 0x43, 0x61, 0xea, 0xf5, 0xc5, 0xe0, 0x5d, 0x6d, 0xf2, 0xff, 0xac, 0xd9, 0x57,
-	0xd0, 0xa9, 0x9a, 0x1f, 0xbd, 0x07, 0xdb, 0x45, 0xa6, 0x1e, 0x90, 0xea, 0x35, 0x28, 0xdb, 0x7c,
-	0xcb, 0xa0, 0xe5, 0x4b, 0xa0, 0x26, 0xa1, 0x50, 0x4f, 0x6f, 0x90, 0x13, 0x69, 0x6e, 0x69, 0x60,
-	0x47, 0x23, 0x98, 0x48, 0xfa, 0xf0, 0x08, 0x3a, 0xd5, 0x77, 0x12, 0x79, 0x70, 0x2b, 0x61, 0x67,
-	0x24, 0x4d, 0x22, 0xfd, 0x01, 0xf6, 0x36, 0x90, 0xbb, 0x7e, 0x24, 0x3c, 0x0b, 0x6d, 0x03, 0xe8,
-	0xe9, 0x26, 0x32, 0xe1, 0xea, 0xb9, 0xbc, 0x0d, 0x3b, 0x97, 0x33, 0x6b, 0xc0, 0xe6, 0xe4, 0xc9,
-	0xc5, 0x9f, 0xbd, 0x8d, 0x8b, 0x17, 0x3d, 0xeb, 0x97, 0x17, 0x3d, 0xeb, 0xc7, 0xbf, 0x7a, 0xd6,
-	0xc9, 0x20, 0x4e, 0xe4, 0x69, 0x31, 0x1b, 0x86, 0x7c, 0x39, 0xa2, 0x42, 0x16, 0x24, 0x5f, 0x99,
-	0xdf, 0x4b, 0x57, 0x7f, 0x62, 0xcd, 0xda, 0x7a, 0xf9, 0xe1, 0xdf, 0x01, 0x00, 0x00, 0xff, 0xff,
-	0x20, 0x7a, 0xa8, 0x9a, 0x7d, 0x09, 0x00, 0x00,
+	// 1132 bytes of a gzipped FileDescriptorProto
+	0x1f, 0x8b, 0x08, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0xff, 0xc5, 0x56, 0x4f, 0x6f, 0xe3, 0x44,
+	0x14, 0x6f, 0x12, 0x3b, 0x8d, 0x5f, 0xfa, 0x27, 0x3b, 0x5d, 0x41, 0xe4, 0x2e, 0x2d, 0x1b, 0x58,
+	0xa9, 0xbb, 0x0b, 0x8e, 0x36, 0x08, 0x09, 0xad, 0x84, 0xc4, 0xa6, 0x0b, 0x12, 0xa8, 0xad, 0x16,
+	0x37, 0xa7, 0xbd, 0x58, 0x8e, 0x3d, 0x71, 0x4d, 0x9d, 0x19, 0xcb, 0x1e, 0xb7, 0x5b, 0x8e, 0x1c,
+	0xf9, 0x14, 0x9c, 0x39, 0xf3, 0x0d, 0xb8, 0xf4, 0x84, 0xf8, 0x02, 0x80, 0x80, 0x2f, 0xc2, 0x9b,
+	0x19, 0x3b, 0xf1, 0xb6, 0xdd, 0x5d, 0x21, 0x0e, 0x1c, 0x12, 0xbd, 0xf9, 0xbd, 0xdf, 0x7b, 0xf3,
+	0xe6, 0xfd, 0x4b, 0xc0, 0x8e, 0xf8, 0x30, 0xcd, 0xb8, 0xe0, 0x01, 0x4f, 0xf2, 0x21, 0x4f, 0xd5,
+	0xc7, 0x51, 0x08, 0x69, 0xa1, 0x68, 0xdf, 0x79, 0x89, 0x30, 0x4b, 0xf8, 0xb9, 0xfa, 0xd2, 0x14,
+	0xfb, 0x76, 0xc4, 0x23, 0xae, 0xc4, 0xa1, 0x94, 0x4a, 0x74, 0x37, 0xe2, 0x3c, 0x4a, 0xa8, 0xb6,
+	0x9b, 0x16, 0xb3, 0xa1, 0x88, 0xe7, 0x34, 0x17, 0xfe, 0x3c, 0xd5, 0x84, 0xc1, 0x8f, 0x4d, 0x58,
+	0x3f, 0x3e, 0xf1, 0xb3, 0xf0, 0xc0, 0x9f, 0xd2, 0x24, 0x66, 0x11, 0xb9, 0x0d, 0xe6, 0xb4, 0x88,
+	0x93, 0xb0, 0xdf, 0x78, 0xb7, 0xb1, 0x67, 0xb9, 0xfa, 0x40, 0x6c, 0xe8, 0x9c, 0xf0, 0x5c, 0x30,
+	0x7f, 0x4e, 0xfb, 0x4d, 0xa5, 0x58, 0x9c, 0xc9, 0x43, 0xb0, 0x12, 0x1e, 0x79, 0x09, 0x3d, 0xa3,
+	0x49, 0xbf, 0x85, 0xca, 0x8d, 0xd1, 0x86, 0x23, 0x83, 0x3f, 0xe0, 0x91, 0x73, 0x20, 0x51, 0xb7,
+	0x83, 0x04, 0x25, 0x21, 0xd9, 0xcc, 0x7c, 0x16, 0xd1, 0xbe, 0x89, 0xc4, 0xee, 0x68, 0xd3, 0x51,
+	0x6f, 0x70, 0x25, 0x74, 0x9c, 0xd2, 0x60, 0x6c, 0x5c, 0xfe, 0xbe, 0xbb, 0xe2, 0x6a, 0x0e, 0xb9,
+	0x0b, 0x6b, 0x79, 0x9a, 0xc4, 0xc2, 0xcb, 0x79, 0x91, 0x05, 0xb4, 0xdf, 0x56, 0x37, 0x77, 0x15,
+	0x76, 0xac, 0xa0, 0x25, 0x45, 0xf8, 0x59, 0x44, 0x45, 0x7f, 0xb5, 0x46, 0x99, 0x28, 0x88, 0x6c,
+	0x83, 0x25, 0xfc, 0xfc, 0xd4, 0x53, 0xc1, 0x77, 0x74, 0xf0, 0x12, 0x38, 0x92, 0xc1, 0x3f, 0x28,
+	0x95, 0xe2, 0x22, 0xa5, 0x7d, 0x4b, 0x05, 0xbf, 0xae, 0x82, 0x9f, 0x20, 0x3a, 0x41, 0x50, 0x73,
+	0xa5, 0x34, 0xf8, 0xae, 0x01, 0x1d, 0x95, 0x2c, 0x97, 0xce, 0xf0, 0x62, 0xe3, 0x34, 0x66, 0x3a,
+	0x4d, 0xd7, 0x6c, 0x94, 0x8a, 0x10, 0x30, 0x6a, 0x09, 0x53, 0xb2, 0x0c, 0xe6, 0x94, 0x5e, 0x78,
+	0x53, 0x1a, 0xc5, 0x4c, 0x25, 0x0b, 0x83, 0x41, 0x60, 0x2c, 0xcf, 0x64, 0x00, 0xeb, 0x99, 0x17,
+	0x24, 0x3c, 0x38, 0x2d, 0x09, 0x86, 0x7e, 0x4d, 0xb6, 0x2f, 0x31, 0xc5, 0x19, 0xd8, 0x60, 0x1c,
+	0x52, 0xe1, 0x4b, 0xe7, 0x45, 0x11, 0x57, 0x65, 0x52, 0xf2, 0xe0, 0x97, 0x16, 0xb4, 0x30, 0xe9,
+	0x64, 0x07, 0x8c, 0x39, 0x72, 0x94, 0xae, 0x3b, 0xb2, 0x54, 0x6c, 0xd2, 0xc8, 0x35, 0x3d, 0x89,
+	0x93, 0xf7, 0xc0, 0xcc, 0xe5, 0x3b, 0x54, 0x64, 0xdd, 0x32, 0xf8, 0xea, 0x65, 0xae, 0xd6, 0x91,
+	0x47, 0x98, 0x99, 0xaa, 0x5b, 0x54, 0xa4, 0xdd, 0x91, 0xed, 0xe8, 0x7e, 0x72, 0xaa, 0x7e, 0x72,
+	0x26, 0x15, 0xc3, 0x6d, 0x8a, 0x9c, 0xbc, 0x0f, 0xa6, 0xee, 0x02, 0xe3, 0xc6, 0x2e, 0xd0, 0x4a,
+	0xd2, 0x87, 0x55, 0x34, 0xca, 0xfd, 0xb2, 0x09, 0x2c, 0xb7, 0x3a, 0x92, 0xe7, 0xb0, 0x39, 0x8b,
+	0x69, 0x12, 0xe6, 0xde, 0x37, 0x39, 0x67, 0xde, 0xdc, 0x4f, 0xb1, 0xe4, 0x2d, 0xbc, 0x78, 0x7b,
+	0xe1, 0xe9, 0x0b, 0xa5, 0xff, 0x0a, 0xd5, 0x87, 0x7e, 0xfa, 0x39, 0x13, 0xd9, 0xc5, 0xf8, 0xce,
+	0xf7, 0x7f, 0xec, 0xf6, 0x29, 0x0b, 0x78, 0x88, 0xfd, 0x3b, 0x94, 0x96, 0xd8, 0x4f, 0xe7, 0x87,
+	0xda, 0xa7, 0xdb, 0xd6, 0x1e, 0x31, 0x27, 0x66, 0x9e, 0xfa, 0x2c, 0xc7, 0x0e, 0x91, 0x1e, 0x3b,
+	0x95, 0x47, 0x57, 0xc3, 0xf6, 0x67, 0x40, 0xae, 0xfb, 0x26, 0x3d, 0x68, 0x61, 0x75, 0xca, 0x24,
+	0x4b, 0x51, 0xce, 0xc7, 0x99, 0x9f, 0x14, 0x55, 0x55, 0xf5, 0xe1, 0x71, 0xf3, 0x93, 0xc6, 0xe0,
+	0x6b, 0x30, 0x75, 0x8f, 0x6f, 0xc1, 0x66, 0xc1, 0x42, 0x3a, 0x8b, 0x19, 0x0d, 0xf5, 0x58, 0xf4,
+	0x56, 0x88, 0x05, 0x26, 0xcd, 0x32, 0x9e, 0xf5, 0x1a, 0xa4, 0x03, 0xc6, 0xb9, 0x9f, 0xb1, 0x5e,
+	0x53, 0x4a, 0x31, 0x9b, 0xf1, 0x5e, 0x4b, 0xaa, 0x43, 0x3a, 0x2d, 0xa2, 0x9e, 0x21, 0x45, 0x91,
+	0xf9, 0x01, 0xed, 0x99, 0x83, 0x9f, 0x00, 0xcc, 0x63, 0xe1, 0x8b, 0xfc, 0x7f, 0x2b, 0xe9, 0x07,
+	0x40, 0x78, 0x4a, 0x99, 0x97, 0xd3, 0x80, 0x33, 0x2c, 0x8c, 0xe0, 0xc2, 0xd7, 0xf5, 0x6d, 0xb8,
+	0x3d, 0xa9, 0x39, 0xd6, 0x8a, 0x89, 0xc4, 0xd5, 0xa8, 0xbd, 0x60, 0x5e, 0xc0, 0x0b, 0x26, 0x54,
+	0x71, 0xd7, 0x71, 0x7c, 0x5e, 0xb0, 0x7d, 0x79, 0xc6, 0xdb, 0x57, 0x03, 0x3f, 0x15, 0x45, 0x46,
+	0xcb, 0xaa, 0xbe, 0xad, 0x83, 0x94, 0xef, 0x73, 0xf6, 0xb5, 0x46, 0x65, 0xdd, 0xad, 0x78, 0xe4,
+	0x3e, 0xb4, 0x43, 0x9a, 0xc5, 0x67, 0x54, 0xcd, 0x75, 0x77, 0x74, 0xab, 0x66, 0xf1, 0x54, 0x29,
+	0xdc, 0x92, 0x40, 0x3e, 0x85, 0xee, 0xdc, 0x17, 0x28, 0xfb, 0x49, 0xfc, 0xad, 0x9c, 0xf3, 0x65,
+	0xdf, 0x68, 0xfe, 0xe1, 0x52, 0xab, 0x6f, 0xa9, 0xf3, 0xc9, 0x10, 0x3a, 0x31, 0xc3, 0x23, 0x96,
+	0x53, 0xad, 0x81, 0xee, 0x68, 0xab, 0x66, 0xfb, 0x65, 0xa9, 0x72, 0x17, 0x24, 0xe2, 0xc0, 0x56,
+	0x9e, 0xc6, 0x49, 0x82, 0x25, 0x9e, 0x5e, 0x08, 0x5a, 0x65, 0x06, 0xd0, 0xd6, 0x70, 0x6f, 0x95,
+	0xaa, 0xb1, 0xd4, 0xa8, 0xd4, 0xd8, 0x47, 0xb0, 0xf6, 0x94, 0x07, 0xf9, 0x13, 0xa6, 0x41, 0xf2,
+	0x0e, 0x40, 0x88, 0xe7, 0xd2, 0xac, 0xa1, 0x72, 0x65, 0x49, 0x44, 0x67, 0x72, 0x17, 0xba, 0x75,
+	0xb7, 0x4d, 0xe5, 0x16, 0xa6, 0x4b, 0x7f, 0x3f, 0x37, 0x60, 0x75, 0x8c, 0x5b, 0x46, 0xee, 0xec,
+	0x87, 0x60, 0x24, 0x74, 0x26, 0xca, 0xe6, 0xa8, 0xa7, 0xb5, 0x7e, 0xa5, 0xab, 0x48, 0xe4, 0x43,
+	0xdc, 0xc0, 0x71, 0x74, 0x22, 0xca, 0x4e, 0x79, 0x25, 0x5b, 0xb3, 0xb0, 0x04, 0x2d, 0x5e, 0x88,
+	0xb2, 0x5b, 0x5e, 0x49, 0x96, 0x1c, 0x59, 0xe0, 0xfc, 0x34, 0x4e, 0x53, 0x1a, 0xaa, 0x06, 0x79,
+	0x0d, 0xbd, 0xe2, 0xc9, 0xac, 0xd4, 0x2b, 0x7f, 0xc3, 0xbc, 0xed, 0xd5, 0xe7, 0xad, 0x3b, 0x22,
+	0x35, 0x97, 0xe5, 0xf3, 0x6b, 0x33, 0x68, 0xff, 0xd6, 0x84, 0xb6, 0x6e, 0x0c, 0xf2, 0x04, 0x00,
+	0xc7, 0x88, 0xe5, 0x33, 0x9e, 0xcd, 0x73, 0xf4, 0x28, 0xfb, 0xe1, 0xee, 0xb5, 0xfe, 0x71, 0x26,
+	0x0b, 0x8e, 0xee, 0x8a, 0x9a, 0x11, 0xf9, 0x18, 0xac, 0xb4, 0x98, 0x26, 0x71, 0x7e, 0x42, 0xc3,
+	0x37, 0xa5, 0x6b, 0xc9, 0xfc, 0x17, 0x29, 0xb3, 0x5d, 0xb0, 0x16, 0x01, 0x90, 0xb7, 0xa0, 0x5d,
+	0xfe, 0xd0, 0xe9, 0xf7, 0x97, 0x27, 0x59, 0xb1, 0x98, 0xa5, 0xc5, 0x9b, 0x2b, 0xa6, 0x58, 0x36,
+	0x6e, 0xd1, 0x2b, 0x8f, 0xba, 0x21, 0xad, 0x8f, 0x5e, 0x4e, 0xeb, 0xf6, 0x6b, 0x12, 0x53, 0xcf,
+	0xaf, 0x0b, 0xbd, 0xab, 0x73, 0xf4, 0x9f, 0x6b, 0xf6, 0x0c, 0x3a, 0xd5, 0x7c, 0x91, 0x7b, 0xb0,
+	0x51, 0xa4, 0x72, 0x47, 0x55, 0x0b, 0xa7, 0x9c, 0x8c, 0x75, 0x8d, 0x96, 0xcb, 0x46, 0x0e, 0x4f,
+	0x21, 0xb7, 0xbb, 0x97, 0x61, 0x30, 0xea, 0x96, 0xa6, 0x6b, 0x29, 0xc4, 0x45, 0xe0, 0xc1, 0x11,
+	0x74, 0xaa, 0x9f, 0x62, 0x8c, 0x6e, 0x2d, 0x66, 0xe8, 0x3a, 0x0e, 0xd5, 0x6f, 0x3c, 0x6e, 0xe2,
+	0xee, 0x62, 0x0f, 0xe1, 0x2e, 0xde, 0xc0, 0x31, 0x94, 0xef, 0xf5, 0x45, 0xcc, 0xe5, 0x46, 0xc6,
+	0xdd, 0xbd, 0x5c, 0x0b, 0x1a, 0x6c, 0x8d, 0x1f, 0x5f, 0xfe, 0xb9, 0xb3, 0x72, 0xf9, 0xd7, 0x4e,
+	0xe3, 0x57, 0xfc, 0xfc, 0xf0, 0xf7, 0x4e, 0xe3, 0xf9, 0x5e, 0x14, 0x8b, 0x93, 0x62, 0xea, 0x04,
+	0x7c, 0x3e, 0xc4, 0x6d, 0x59, 0xf8, 0xd9, 0x85, 0xfe, 0x4b, 0x76, 0xf5, 0x5f, 0xdc, 0xb4, 0xad,
+	0x8e, 0x1f, 0xfd, 0x03, 0x4c, 0x79, 0x9f, 0x6f, 0xe0, 0x09, 0x00, 0x00,
 }
 
 func (m *ShardLabeling) Marshal() (dAtA []byte, err error) {
@@ -984,6 +990,11 @@ func (m *Stats) MarshalToSizedBuffer(dAtA []byte) (int, error) {
 		i -= len(m.XXX_unrecognized)
 		copy(dAtA[i:], m.XXX_unrecognized)
 	}
+	if m.SpilledBytesTotal != 0 {
+		i = encodeVarintOps(dAtA, i, uint64(m.SpilledBytesTotal))
+		i--
+		dAtA[i] = 0x50
+	}
 	if m.Interval != nil {
 		{
 			size, err := m.Interval.MarshalToSizedBuffer(dAtA[:i])
@@ -1171,6 +1182,18 @@ func (m *Stats_Binding) MarshalToSizedBuffer(dAtA []byte) (int, error) {
 		i -= len(m.XXX_unrecognized)
 		copy(dAtA[i:], m.XXX_unrecognized)
 	}
+	if m.Skipped != nil {
+		{
+			size, err := m.Skipped.MarshalToSizedBuffer(dAtA[:i])
+			if err != nil {
+				return 0, err
+			}
+			i -= size
+			i = encodeVarintOps(dAtA, i, uint64(size))
+		}
+		i--
+		dAtA[i] = 0x22
+	}
 	if m.Out != nil {
 		{
 			size, err := m.Out.MarshalToSizedBuffer(dAtA[:i])
@@ -1568,6 +1591,9 @@ func (m *Stats) ProtoSize() (n int) {
 		l = m.Interval.ProtoSize()
 		n += 1 + l + sovOps(uint64(l))
 	}
+	if m.SpilledBytesTotal != 0 {
+		n += 1 + sovOps(uint64(m.SpilledBytesTotal))
+	}
 	if m.XXX_unrecognized != nil {
 		n += len(m.XXX_unrecognized)
 	}
@@ -1610,6 +1636,10 @@ func (m *Stats_Binding) ProtoSize() (n int) {
 		l = m.Out.ProtoSize()
 		n += 1 + l + sovOps(uint64(l))
 	}
+	if m.Skipped != nil {
+		l = m.Skipped.ProtoSize()
+		n += 1 + l + sovOps(uint64(l))
+	}
 	if m.XXX_unrecognized != nil {
 		n += len(m.XXX_unrecognized)
 	}
@@ -3092,6 +3122,25 @@ func (m *Stats) Unmarshal(dAtA []byte) error {
 				return err
 			}
 			iNdEx = postIndex
+		case 10:
+			if wireType != 0 {
+				return fmt.Errorf("proto: wrong wireType = %d for field SpilledBytesTotal", wireType)
+			}
+			m.SpilledBytesTotal = 0
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowOps
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				m.SpilledBytesTotal |= uint64(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
 		default:
 			iNdEx = preIndex
 			skippy, err := skipOps(dAtA[iNdEx:])
@@ -3340,6 +3389,42 @@ func (m *Stats_Binding) Unmarshal(dAtA []byte) error {
 				return err
 			}
 			iNdEx = postIndex
+		case 4:
+			if wireType != 2 {
+				return fmt.Errorf("proto: wrong wireType = %d for field Skipped", wireType)
+			}
+			var msglen int
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowOps
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				msglen |= int(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
+			if msglen < 0 {
+				return ErrInvalidLengthOps
+			}
+			postIndex := iNdEx + msglen
+			if postIndex < 0 {
+				return ErrInvalidLengthOps
+			}
+			if postIndex > l {
+				return io.ErrUnexpectedEOF
+			}
+			if m.Skipped == nil {
+				m.Skipped = &Stats_DocsAndBytes{}
+			}
+			if err := m.Skipped.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
+				return err
+			}
+			iNdEx = postIndex
 		default:
 			iNdEx = preIndex
 			skippy, err := skipOps(dAtA[iNdEx:])
@@ -373,6 +373,7 @@ type CaptureRequestExt struct {
 	// RocksDB descriptor which should be opened. Sent (only) with the first Request.
 	RocksdbDescriptor    *RocksDBDescriptor             `protobuf:"bytes,2,opt,name=rocksdb_descriptor,json=rocksdbDescriptor,proto3" json:"rocksdb_descriptor,omitempty"`
 	StartCommit          *CaptureRequestExt_StartCommit `protobuf:"bytes,3,opt,name=start_commit,json=startCommit,proto3" json:"start_commit,omitempty"`
+	Open                 *CaptureRequestExt_Open        `protobuf:"bytes,4,opt,name=open,proto3" json:"open,omitempty"`
 	XXX_NoUnkeyedLiteral struct{}                       `json:"-"`
 	XXX_unrecognized     []byte                         `json:"-"`
 	XXX_sizecache        int32                          `json:"-"`
@@ -452,6 +453,147 @@ func (m *CaptureRequestExt_StartCommit) XXX_DiscardUnknown() {
 
 var xxx_messageInfo_CaptureRequestExt_StartCommit proto.InternalMessageInfo
 
+type CaptureRequestExt_Open struct {
+	// Should the runtime error if a binding's `state_key` is not populated,
+	// rather than computing it from the binding's resource path?
+	StrictStateKeys bool `protobuf:"varint,1,opt,name=strict_state_keys,json=strictStateKeys,proto3" json:"strict_state_keys,omitempty"`
+	// Should the runtime discard its persisted connector state, and open the
+	// connector with only the `state_json` of the Open request?
+	ResetState bool `protobuf:"varint,2,opt,name=reset_state,json=resetState,proto3" json:"reset_state,omitempty"`
+	// Number of committed checkpoints which are batched into a single
+	// Acknowledge of the connector. Zero is treated as one.
+	AckBatchSize uint32 `protobuf:"varint,3,opt,name=ack_batch_size,json=ackBatchSize,proto3" json:"ack_batch_size,omitempty"`
+	// Milliseconds spent blocked sending a transaction to the client, beyond
+	// which a back-pressure warning is logged. Zero disables the warning.
+	ClientBlockedThresholdMs uint64 `protobuf:"varint,4,opt,name=client_blocked_threshold_ms,json=clientBlockedThresholdMs,proto3" json:"client_blocked_threshold_ms,omitempty"`
+	// Should read checkpoints be committed before a connector error is surfaced?
+	DrainOnError bool `protobuf:"varint,5,opt,name=drain_on_error,json=drainOnError,proto3" json:"drain_on_error,omitempty"`
+	// Should commits be suppressed? For debugging only.
+	DryCommit bool `protobuf:"varint,6,opt,name=dry_commit,json=dryCommit,proto3" json:"dry_commit,omitempty"`
+	// Milliseconds without a commit, after which an idle connector commits
+	// an empty heartbeat transaction. Zero disables heartbeats.
+	IdleHeartbeatMs uint64 `protobuf:"varint,7,opt,name=idle_heartbeat_ms,json=idleHeartbeatMs,proto3" json:"idle_heartbeat_ms,omitempty"`
+	// Number of consecutive commits without a Shape update, upon which a
+	// binding's inferred Shape is stable. Zero uses the runtime default.
+	InferenceStableAfter uint64 `protobuf:"varint,8,opt,name=inference_stable_after,json=inferenceStableAfter,proto3" json:"inference_stable_after,omitempty"`
+	// Maximum milliseconds of a capture session, after which it drains,
+	// commits, and restarts. Zero is unlimited.
+	MaxRunDurationMs uint64 `protobuf:"varint,9,opt,name=max_run_duration_ms,json=maxRunDurationMs,proto3" json:"max_run_duration_ms,omitempty"`
+	// Maximum bytes of a connector state update. Zero is unlimited.
+	MaxStateBytes uint64 `protobuf:"varint,10,opt,name=max_state_bytes,json=maxStateBytes,proto3" json:"max_state_bytes,omitempty"`
+	// Milliseconds for which the connector may produce no checkpoints
+	// before the session restarts. Zero is unlimited.
+	PollTimeoutMs uint64 `protobuf:"varint,11,opt,name=poll_timeout_ms,json=pollTimeoutMs,proto3" json:"poll_timeout_ms,omitempty"`
+	// Milliseconds over which the restarts of the task's shards are spread.
+	RestartJitterMs uint64 `protobuf:"varint,12,opt,name=restart_jitter_ms,json=restartJitterMs,proto3" json:"restart_jitter_ms,omitempty"`
+	// Fraction of captured documents, in [0, 1], which are sampled for debugging.
+	SampleRate float64 `protobuf:"fixed64,13,opt,name=sample_rate,json=sampleRate,proto3" json:"sample_rate,omitempty"`
+	// Should invalid captured documents be skipped rather than failing?
+	SkipInvalid bool `protobuf:"varint,14,opt,name=skip_invalid,json=skipInvalid,proto3" json:"skip_invalid,omitempty"`
+	// Bytes of memory which a combiner MemTable may use before it's spilled.
+	// Zero uses the combiner's default.
+	SpillThresholdBytes uint64 `protobuf:"varint,15,opt,name=spill_threshold_bytes,json=spillThresholdBytes,proto3" json:"spill_threshold_bytes,omitempty"`
+	// Serialization policy of connector state updates. If unset, state
+	// updates are not truncated.
+	StateSerPolicy *flow.SerPolicy `protobuf:"bytes,16,opt,name=state_ser_policy,json=stateSerPolicy,proto3" json:"state_ser_policy,omitempty"`
+	// Should captured documents be validated as they're read?
+	ValidateCaptured bool `protobuf:"varint,17,opt,name=validate_captured,json=validateCaptured,proto3" json:"validate_captured,omitempty"`
+	// Options of the capture's bindings, index-aligned with the bindings
+	// of its CaptureSpec. Bindings beyond its length use default options.
+	Bindings             []*CaptureRequestExt_Open_Binding `protobuf:"bytes,18,rep,name=bindings,proto3" json:"bindings,omitempty"`
+	XXX_NoUnkeyedLiteral struct{}                          `json:"-"`
+	XXX_unrecognized     []byte                            `json:"-"`
+	XXX_sizecache        int32                             `json:"-"`
+}
+
+func (m *CaptureRequestExt_Open) Reset()         { *m = CaptureRequestExt_Open{} }
+func (m *CaptureRequestExt_Open) String() string { return proto.CompactTextString(m) }
+func (*CaptureRequestExt_Open) ProtoMessage()    {}
+func (*CaptureRequestExt_Open) Descriptor() ([]byte, []int) {
+	return fileDescriptor_73af6e0737ce390c, []int{5, 1}
+}
+func (m *CaptureRequestExt_Open) XXX_Unmarshal(b []byte) error {
+	return m.Unmarshal(b)
+}
+func (m *CaptureRequestExt_Open) XXX_Marshal(b []byte, deterministic bool) ([]byte, error) {
+	if deterministic {
+		return xxx_messageInfo_CaptureRequestExt_Open.Marshal(b, m, deterministic)
+	} else {
+		b = b[:cap(b)]
+		n, err := m.MarshalToSizedBuffer(b)
+		if err != nil {
+			return nil, err
+		}
+		return b[:n], nil
+	}
+}
+func (m *CaptureRequestExt_Open) XXX_Merge(src proto.Message) {
+	xxx_messageInfo_CaptureRequestExt_Open.Merge(m, src)
+}
+func (m *CaptureRequestExt_Open) XXX_Size() int {
+	return m.ProtoSize()
+}
+func (m *CaptureRequestExt_Open) XXX_DiscardUnknown() {
+	xxx_messageInfo_CaptureRequestExt_Open.DiscardUnknown(m)
+}
+
+var xxx_messageInfo_CaptureRequestExt_Open proto.InternalMessageInfo
+
+type CaptureRequestExt_Open_Binding struct {
+	// Serialization policy of the binding's captured documents.
+	// If unset, documents are not truncated.
+	SerPolicy *flow.SerPolicy `protobuf:"bytes,1,opt,name=ser_policy,json=serPolicy,proto3" json:"ser_policy,omitempty"`
+	// JSON pointers of captured document locations which are retained,
+	// in addition to key and partition locations. If empty, captured
+	// documents are retained in their entirety.
+	Projection []string `protobuf:"bytes,2,rep,name=projection,proto3" json:"projection,omitempty"`
+	// JSON pointer by which drained documents are sorted, and then by key.
+	// If empty, drained documents are in key order.
+	SortPtr string `protobuf:"bytes,3,opt,name=sort_ptr,json=sortPtr,proto3" json:"sort_ptr,omitempty"`
+	// Should captured documents bypass the combiner, being sent to the
+	// client in the order in which they were captured?
+	Passthrough bool `protobuf:"varint,4,opt,name=passthrough,proto3" json:"passthrough,omitempty"`
+	// May the connector supply the packed partition values of its
+	// captured documents?
+	ConnectorPartitions  bool     `protobuf:"varint,5,opt,name=connector_partitions,json=connectorPartitions,proto3" json:"connector_partitions,omitempty"`
+	XXX_NoUnkeyedLiteral struct{} `json:"-"`
+	XXX_unrecognized     []byte   `json:"-"`
+	XXX_sizecache        int32    `json:"-"`
+}
+
+func (m *CaptureRequestExt_Open_Binding) Reset()         { *m = CaptureRequestExt_Open_Binding{} }
+func (m *CaptureRequestExt_Open_Binding) String() string { return proto.CompactTextString(m) }
+func (*CaptureRequestExt_Open_Binding) ProtoMessage()    {}
+func (*CaptureRequestExt_Open_Binding) Descriptor() ([]byte, []int) {
+	return fileDescriptor_73af6e0737ce390c, []int{5, 1, 0}
+}
+func (m *CaptureRequestExt_Open_Binding) XXX_Unmarshal(b []byte) error {
+	return m.Unmarshal(b)
+}
+func (m *CaptureRequestExt_Open_Binding) XXX_Marshal(b []byte, deterministic bool) ([]byte, error) {
+	if deterministic {
+		return xxx_messageInfo_CaptureRequestExt_Open_Binding.Marshal(b, m, deterministic)
+	} else {
+		b = b[:cap(b)]
+		n, err := m.MarshalToSizedBuffer(b)
+		if err != nil {
+			return nil, err
+		}
+		return b[:n], nil
+	}
+}
+func (m *CaptureRequestExt_Open_Binding) XXX_Merge(src proto.Message) {
+	xxx_messageInfo_CaptureRequestExt_Open_Binding.Merge(m, src)
+}
+func (m *CaptureRequestExt_Open_Binding) XXX_Size() int {
+	return m.ProtoSize()
+}
+func (m *CaptureRequestExt_Open_Binding) XXX_DiscardUnknown() {
+	xxx_messageInfo_CaptureRequestExt_Open_Binding.DiscardUnknown(m)
+}
+
+var xxx_messageInfo_CaptureRequestExt_Open_Binding proto.InternalMessageInfo
+
 type CaptureResponseExt struct {
 	Container            *Container                     `protobuf:"bytes,1,opt,name=container,proto3" json:"container,omitempty"`
 	Opened               *CaptureResponseExt_Opened     `protobuf:"bytes,2,opt,name=opened,proto3" json:"opened,omitempty"`
@@ -1238,6 +1380,8 @@ func init() {
 	proto.RegisterMapType((map[uint32]string)(nil), "runtime.Container.MappedHostPortsEntry")
 	proto.RegisterType((*CaptureRequestExt)(nil), "runtime.CaptureRequestExt")
 	proto.RegisterType((*CaptureRequestExt_StartCommit)(nil), "runtime.CaptureRequestExt.StartCommit")
+	proto.RegisterType((*CaptureRequestExt_Open)(nil), "runtime.CaptureRequestExt.Open")
+	proto.RegisterType((*CaptureRequestExt_Open_Binding)(nil), "runtime.CaptureRequestExt.Open.Binding")
 	proto.RegisterType((*CaptureResponseExt)(nil), "runtime.CaptureResponseExt")
 	proto.RegisterType((*CaptureResponseExt_Opened)(nil), "runtime.CaptureResponseExt.Opened")
 	proto.RegisterType((*CaptureResponseExt_Captured)(nil), "runtime.CaptureResponseExt.Captured")
@@ -1263,128 +1407,155 @@ func init() {
 }
 
 var fileDescriptor_73af6e0737ce390c = []byte{
-	// 1927 bytes of a gzipped FileDescriptorProto
-	0x1f, 0x8b, 0x08, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0xff, 0xcc, 0x58, 0xcd, 0x72, 0x1b, 0xc7,
-	0x11, 0xd6, 0x02, 0x20, 0x81, 0x6d, 0x90, 0x14, 0x38, 0xa5, 0xd8, 0x30, 0xac, 0x90, 0x34, 0x6c,
-	0x27, 0xac, 0x48, 0x5e, 0x2a, 0x74, 0x2a, 0x71, 0x5c, 0x89, 0x4b, 0x24, 0x48, 0x46, 0x54, 0x48,
-	0x91, 0x19, 0x48, 0xaa, 0x4a, 0x2e, 0x5b, 0xc3, 0xdd, 0x01, 0xb0, 0xc2, 0x62, 0x67, 0x3d, 0x33,
-	0x4b, 0x8a, 0x7e, 0x85, 0x1c, 0x72, 0xc9, 0x03, 0xe4, 0x9c, 0x37, 0x88, 0x9f, 0x40, 0xc7, 0x54,
-	0x0e, 0xa9, 0x9c, 0x58, 0x15, 0xe7, 0x9a, 0x63, 0x2e, 0x51, 0xe5, 0x90, 0x9a, 0x9f, 0x5d, 0x80,
-	0x80, 0xa8, 0x28, 0xb4, 0x0f, 0x3e, 0x48, 0x9c, 0xe9, 0xee, 0xaf, 0xb7, 0xbb, 0xa7, 0xbb, 0xa7,
-	0x07, 0xd0, 0xee, 0xb3, 0x8d, 0x94, 0x33, 0xc9, 0x02, 0x16, 0x8b, 0x0d, 0x9e, 0x25, 0x32, 0x1a,
-	0xd1, 0xfc, 0xaf, 0xa7, 0x39, 0xa8, 0x6a, 0xb7, 0xad, 0x95, 0x13, 0xce, 0x86, 0x94, 0x17, 0x80,
-	0x62, 0x61, 0x04, 0x5b, 0x6b, 0x01, 0x4b, 0x44, 0x36, 0x7a, 0x8d, 0xc4, 0xed, 0x4b, 0x9f, 0xeb,
-	0xc5, 0xec, 0x4c, 0xff, 0x67, 0xb9, 0xad, 0x4b, 0x5c, 0x96, 0xea, 0x7f, 0x96, 0x77, 0xab, 0xcf,
-	0xfa, 0x4c, 0x2f, 0x37, 0xd4, 0xca, 0x50, 0xdb, 0x7f, 0x72, 0x60, 0xf9, 0x31, 0x11, 0xc3, 0x2e,
-	0xe5, 0xa7, 0x51, 0x40, 0x3b, 0x2c, 0xe9, 0x45, 0x7d, 0xb4, 0x02, 0xf5, 0x98, 0xf5, 0xfd, 0x5e,
-	0x14, 0x53, 0xbf, 0x17, 0x36, 0x9d, 0x35, 0x67, 0x7d, 0x0e, 0xbb, 0x31, 0xeb, 0xef, 0x45, 0x31,
-	0xdd, 0x0b, 0xd1, 0xbb, 0xe0, 0x4a, 0x22, 0x86, 0x7e, 0x42, 0x46, 0xb4, 0x59, 0x5a, 0x73, 0xd6,
-	0x5d, 0x5c, 0x53, 0x84, 0x47, 0x64, 0x44, 0xd1, 0x3b, 0x50, 0xcb, 0x42, 0xe1, 0xa7, 0x44, 0x0e,
-	0x9a, 0x65, 0xcd, 0xab, 0x66, 0xa1, 0x38, 0x26, 0x72, 0x80, 0xee, 0xc0, 0x72, 0xc0, 0x12, 0x49,
-	0xa2, 0x84, 0x72, 0x3f, 0xa1, 0xf2, 0x8c, 0xf1, 0x61, 0xb3, 0xa2, 0x65, 0x1a, 0x05, 0xe3, 0x91,
-	0xa1, 0xa3, 0x55, 0xa8, 0x93, 0x38, 0x66, 0x67, 0x7e, 0xcc, 0x02, 0x12, 0x37, 0xe7, 0xd6, 0x9c,
-	0xf5, 0x1a, 0x06, 0x4d, 0x3a, 0x50, 0x94, 0xf6, 0xbf, 0x2b, 0xb0, 0xd4, 0x1d, 0x64, 0xbd, 0x5e,
-	0x4c, 0x31, 0xfd, 0x3c, 0xa3, 0x42, 0xa2, 0x7d, 0xa8, 0x3e, 0x63, 0x19, 0x4f, 0x48, 0xac, 0x8d,
-	0x76, 0xb7, 0x37, 0x5e, 0x5e, 0xac, 0xde, 0xe9, 0x33, 0xaf, 0x4f, 0xbe, 0xa0, 0x52, 0x52, 0x2f,
-	0xa4, 0xa7, 0x1b, 0x01, 0xe3, 0x74, 0x63, 0xea, 0x24, 0xbc, 0x87, 0x06, 0x86, 0x73, 0x3c, 0x7a,
-	0x0b, 0xe6, 0x39, 0x4d, 0x63, 0x72, 0xae, 0x1d, 0xac, 0x61, 0xbb, 0x53, 0xee, 0x9d, 0x64, 0x51,
-	0x1c, 0xfa, 0x51, 0x98, 0xbb, 0xa7, 0xf7, 0xfb, 0x21, 0xda, 0x83, 0x79, 0xd6, 0xeb, 0x09, 0x2a,
-	0xb5, 0x4f, 0xe5, 0x6d, 0xef, 0xe5, 0xc5, 0xea, 0x0f, 0xde, 0xe4, 0xe3, 0x47, 0x1a, 0x85, 0x2d,
-	0x1a, 0x1d, 0x02, 0xd0, 0x24, 0xf4, 0xad, 0xae, 0xb9, 0x6b, 0xe9, 0x72, 0x69, 0x12, 0x9a, 0x25,
-	0xba, 0x03, 0x73, 0x9c, 0x24, 0x7d, 0xda, 0x9c, 0x5f, 0x73, 0xd6, 0xeb, 0x9b, 0x37, 0x3d, 0x9d,
-	0x31, 0x58, 0x91, 0xba, 0x29, 0x0d, 0xb6, 0x2b, 0x2f, 0x2e, 0x56, 0x6f, 0x60, 0x23, 0x83, 0xba,
-	0x50, 0x0f, 0x18, 0xe3, 0x61, 0x94, 0x10, 0xc9, 0x78, 0xb3, 0xaa, 0xa3, 0xf8, 0xc3, 0x97, 0x17,
-	0xab, 0x1f, 0xbd, 0xea, 0xe3, 0x33, 0xf9, 0xea, 0x75, 0x07, 0x84, 0x87, 0xfb, 0x3b, 0x78, 0x52,
-	0x0b, 0xba, 0x07, 0xc0, 0xa9, 0x60, 0x71, 0x26, 0x23, 0x96, 0x34, 0x6b, 0xda, 0x8c, 0x86, 0x57,
-	0x60, 0x1e, 0x50, 0x12, 0x52, 0x8e, 0x27, 0x64, 0xd0, 0xfb, 0xb0, 0x28, 0xcc, 0xd1, 0xfa, 0x51,
-	0x12, 0xd2, 0xe7, 0x4d, 0x77, 0xcd, 0x59, 0x5f, 0xc4, 0x0b, 0x96, 0xb8, 0xaf, 0x68, 0xe8, 0x47,
-	0x00, 0x21, 0xe5, 0xd1, 0x29, 0xd1, 0x6a, 0x41, 0xab, 0xbd, 0x65, 0xbc, 0xeb, 0xb0, 0x38, 0xa6,
-	0x81, 0xa2, 0x2b, 0x17, 0xf1, 0x84, 0x1c, 0xea, 0xc0, 0xcd, 0x11, 0x91, 0x94, 0x47, 0x24, 0x8e,
-	0xbe, 0x30, 0xd0, 0xba, 0x86, 0xbe, 0x63, 0xa0, 0x87, 0x97, 0x99, 0x1a, 0x3f, 0x8d, 0x68, 0xff,
-	0xa5, 0x02, 0x37, 0x8b, 0xdc, 0x13, 0x29, 0x4b, 0x04, 0x45, 0xeb, 0x30, 0x2f, 0x24, 0x91, 0x99,
-	0xd0, 0xb9, 0xb7, 0xb4, 0xd9, 0xf0, 0xf2, 0xf0, 0x78, 0x5d, 0x4d, 0xc7, 0x96, 0xaf, 0x24, 0x07,
-	0xda, 0x67, 0x9d, 0x5b, 0xaf, 0x8a, 0x85, 0xe5, 0xa3, 0x0f, 0x61, 0x49, 0x52, 0x3e, 0x8a, 0x12,
-	0x12, 0xfb, 0x94, 0x73, 0xc6, 0x6d, 0xce, 0x2d, 0xe6, 0xd4, 0x5d, 0x45, 0x44, 0xbf, 0x82, 0x05,
-	0x4e, 0x49, 0xe8, 0xcb, 0x01, 0x67, 0x59, 0x7f, 0x70, 0xcd, 0xfc, 0xab, 0x2b, 0x1d, 0x8f, 0x8d,
-	0x0a, 0x95, 0x84, 0x67, 0x3c, 0x92, 0xd4, 0x57, 0x96, 0x5c, 0x37, 0x09, 0xb5, 0x06, 0xe5, 0x12,
-	0xda, 0x87, 0x39, 0xc2, 0x69, 0x42, 0x74, 0x12, 0x2e, 0x6c, 0x7f, 0xfc, 0xf2, 0x62, 0x75, 0xa3,
-	0x1f, 0xc9, 0x41, 0x76, 0xe2, 0x05, 0x6c, 0xb4, 0x41, 0x85, 0xcc, 0x08, 0x3f, 0x37, 0x1d, 0x6d,
-	0xa6, 0xc7, 0x79, 0x5b, 0x0a, 0x8a, 0x8d, 0x06, 0xf4, 0x21, 0x54, 0x42, 0x16, 0x88, 0x66, 0x75,
-	0xad, 0xbc, 0x5e, 0xdf, 0xac, 0x9b, 0x53, 0xeb, 0xc6, 0x51, 0x40, 0x6d, 0x2a, 0x6b, 0x36, 0x7a,
-	0x00, 0x55, 0x53, 0x41, 0xa2, 0x59, 0x5b, 0x2b, 0x5f, 0xc3, 0xfa, 0x1c, 0xae, 0xf2, 0x2c, 0xcb,
-	0xa2, 0xd0, 0x4f, 0x09, 0x97, 0xa2, 0xe9, 0xea, 0xcf, 0xda, 0x2a, 0x7a, 0xf2, 0x64, 0x7f, 0xe7,
-	0x58, 0x91, 0xed, 0xa7, 0x5d, 0x25, 0xa8, 0x09, 0x2a, 0xe9, 0x53, 0x12, 0x0c, 0x69, 0xe8, 0x0f,
-	0xe9, 0x79, 0x13, 0xae, 0x32, 0xd6, 0x35, 0x42, 0xbf, 0xa4, 0xe7, 0xed, 0x10, 0x96, 0x31, 0x0b,
-	0x86, 0x62, 0x67, 0x7b, 0x87, 0x8a, 0x80, 0x47, 0xa9, 0xaa, 0x9d, 0xbb, 0x80, 0xb8, 0x22, 0x86,
-	0x27, 0x3e, 0x4d, 0x4e, 0xfd, 0x11, 0x1d, 0xa5, 0x92, 0xeb, 0x0c, 0x9b, 0xc7, 0x0d, 0xcb, 0xd9,
-	0x4d, 0x4e, 0x0f, 0x35, 0x1d, 0xbd, 0x07, 0x0b, 0xb9, 0xb4, 0x6e, 0xc0, 0xa6, 0x39, 0xd7, 0x2d,
-	0x4d, 0x35, 0xe1, 0xf6, 0xef, 0x4b, 0xe0, 0x76, 0xf2, 0x66, 0x8b, 0xde, 0x86, 0x6a, 0x94, 0xfa,
-	0x24, 0x0c, 0x8d, 0x4e, 0x17, 0xcf, 0x47, 0xe9, 0x56, 0x18, 0x72, 0xf4, 0x63, 0x58, 0xb4, 0x1d,
-	0xda, 0x4f, 0x99, 0xf2, 0xbb, 0xa4, 0x3d, 0x58, 0x36, 0x1e, 0xd8, 0x26, 0x7d, 0xcc, 0xb8, 0xc4,
-	0x0b, 0xc9, 0x78, 0x23, 0x50, 0x17, 0x96, 0x47, 0x24, 0x4d, 0x69, 0xe8, 0x0f, 0x98, 0x90, 0x16,
-	0x5b, 0xd6, 0xd8, 0xef, 0x7b, 0xf9, 0xbd, 0x58, 0x7c, 0xdf, 0x3b, 0xd4, 0xb2, 0x0f, 0x98, 0x90,
-	0x1a, 0xbe, 0x9b, 0x48, 0x7e, 0xae, 0xca, 0xed, 0x12, 0x15, 0x7d, 0x17, 0x20, 0x13, 0xa4, 0x4f,
-	0x7d, 0x4e, 0x24, 0xd5, 0xd9, 0x5d, 0xc2, 0xae, 0xa6, 0x60, 0x22, 0x69, 0x6b, 0x1b, 0x6e, 0xbd,
-	0x4a, 0x0f, 0x6a, 0x40, 0x59, 0xc5, 0xde, 0xd1, 0xbd, 0x43, 0x2d, 0xd1, 0x2d, 0x98, 0x3b, 0x25,
-	0x71, 0x96, 0xdf, 0x5a, 0x66, 0xf3, 0x69, 0xe9, 0x13, 0xa7, 0xfd, 0xc7, 0x12, 0x2c, 0x77, 0x48,
-	0x2a, 0x33, 0x9e, 0xdf, 0x26, 0xbb, 0xcf, 0x55, 0xef, 0x54, 0xd7, 0x9e, 0x1f, 0xd3, 0x53, 0x1a,
-	0xdb, 0xb2, 0x5e, 0xf2, 0xd4, 0xa5, 0x7a, 0xc0, 0xfa, 0xde, 0x81, 0xa2, 0xe2, 0x5a, 0xcc, 0xfa,
-	0x7a, 0x85, 0xf6, 0xc7, 0x47, 0x15, 0x16, 0x07, 0x68, 0x4b, 0xbc, 0x55, 0xf8, 0x3e, 0x73, 0xc4,
-	0x78, 0xd9, 0xa2, 0x26, 0x4e, 0x7d, 0x1f, 0x16, 0x84, 0x24, 0x5c, 0xfa, 0x01, 0x1b, 0x8d, 0x22,
-	0xa9, 0xab, 0xbe, 0xbe, 0xf9, 0xbd, 0x71, 0x00, 0xa7, 0x2d, 0x55, 0x2d, 0x86, 0xcb, 0x8e, 0x96,
-	0xc6, 0x75, 0x31, 0xde, 0xb4, 0x30, 0xd4, 0x27, 0x78, 0xa8, 0x03, 0xc8, 0x2a, 0xf1, 0x83, 0x01,
-	0x0d, 0x86, 0x29, 0x8b, 0x12, 0xa9, 0x5d, 0x53, 0xcd, 0xb3, 0xe8, 0x58, 0x9d, 0x82, 0x87, 0x97,
-	0xad, 0xfc, 0x98, 0xd4, 0xfe, 0x4f, 0x05, 0x50, 0x61, 0x82, 0x69, 0x7f, 0x2a, 0x5a, 0xf7, 0xc0,
-	0x2d, 0xae, 0x71, 0xab, 0x12, 0xcd, 0x9e, 0x39, 0x1e, 0x0b, 0xa1, 0x4f, 0x61, 0x9e, 0xa5, 0x34,
-	0xa1, 0xa1, 0x0d, 0x53, 0x7b, 0xd6, 0xc3, 0x42, 0xbd, 0x77, 0xa4, 0x25, 0xb1, 0x45, 0xa0, 0xfb,
-	0x50, 0x0b, 0x8c, 0x50, 0x68, 0xe3, 0xf3, 0xc1, 0xeb, 0xd0, 0x96, 0x14, 0xe2, 0x02, 0x85, 0xf6,
-	0x00, 0x26, 0x62, 0x50, 0xb9, 0x2a, 0xc6, 0x13, 0x3a, 0xc6, 0x51, 0x99, 0x40, 0xb6, 0x0e, 0x61,
-	0xde, 0xd8, 0xf6, 0x8d, 0x44, 0xb7, 0xf5, 0x14, 0x6a, 0xb9, 0xb1, 0x2a, 0xf3, 0x87, 0xf4, 0xdc,
-	0x37, 0x4d, 0x42, 0x2b, 0x5a, 0xc0, 0xee, 0x90, 0x9e, 0x1f, 0x6b, 0x82, 0x9a, 0xa8, 0x54, 0x57,
-	0x8a, 0xd4, 0xa5, 0x24, 0x72, 0xa9, 0x92, 0x96, 0x6a, 0x8c, 0x19, 0x46, 0xb8, 0x75, 0x06, 0x30,
-	0xfe, 0x0a, 0x5a, 0x83, 0x39, 0x75, 0x1d, 0x09, 0x6b, 0x1d, 0xe8, 0xb4, 0x56, 0x17, 0x95, 0xc0,
-	0x86, 0x81, 0x7e, 0x01, 0xf5, 0x94, 0xc5, 0xb1, 0xcf, 0xa9, 0xc8, 0x62, 0xa9, 0xd5, 0x2e, 0xbd,
-	0x3e, 0x3e, 0xc7, 0x2c, 0x8e, 0xb1, 0x96, 0xc6, 0x90, 0x16, 0xeb, 0xf6, 0x23, 0x80, 0x31, 0x07,
-	0xd5, 0xa1, 0xba, 0xff, 0xe8, 0xe9, 0xd6, 0xc1, 0xfe, 0x4e, 0xe3, 0x06, 0x72, 0x61, 0x0e, 0xef,
-	0x6e, 0xed, 0xfc, 0xba, 0xe1, 0xa0, 0x45, 0x70, 0x1f, 0x1d, 0x3d, 0xf6, 0xcd, 0xb6, 0x84, 0x16,
-	0xa0, 0xd6, 0x39, 0x3a, 0x3a, 0xf0, 0x8f, 0xf6, 0xf6, 0x1a, 0x65, 0x05, 0xc2, 0xbb, 0xdd, 0xc7,
-	0x5b, 0xf8, 0x71, 0xa3, 0xd2, 0xfe, 0xa7, 0x03, 0x8d, 0x1d, 0x75, 0xa3, 0x7f, 0x1b, 0x4a, 0x75,
-	0x13, 0x2a, 0x2a, 0x21, 0x6d, 0x0a, 0xae, 0x14, 0xe0, 0x69, 0x03, 0x75, 0xfa, 0x62, 0x2d, 0xdb,
-	0xba, 0x0b, 0x15, 0xb5, 0x43, 0x1f, 0xc0, 0x92, 0xf8, 0x3c, 0x56, 0xb7, 0xec, 0x69, 0x4f, 0xf8,
-	0x19, 0x8f, 0x6c, 0x13, 0x5e, 0x30, 0xd4, 0xa7, 0x3d, 0xf1, 0x84, 0x47, 0xed, 0x7f, 0x95, 0x61,
-	0x39, 0xd7, 0xf6, 0x75, 0x8a, 0xed, 0xa7, 0x53, 0xc5, 0xf6, 0xde, 0x8c, 0xad, 0x57, 0xd6, 0xda,
-	0x36, 0xb8, 0x69, 0x76, 0x12, 0x47, 0x62, 0xf0, 0x8a, 0x62, 0x9b, 0x45, 0x1f, 0xe7, 0xb2, 0x78,
-	0x0c, 0x43, 0x3f, 0x83, 0x6a, 0x2f, 0xce, 0xb4, 0x86, 0xca, 0x54, 0xb1, 0xcf, 0x6a, 0xd8, 0x33,
-	0x92, 0x38, 0x87, 0x7c, 0xd3, 0x35, 0x26, 0xc1, 0x2d, 0x8c, 0x54, 0xef, 0x99, 0x11, 0x79, 0xee,
-	0x07, 0x31, 0x0b, 0x86, 0xf6, 0x6a, 0xad, 0x8d, 0xc8, 0xf3, 0x8e, 0xda, 0x4f, 0x55, 0x60, 0xe9,
-	0x8d, 0x2a, 0xb0, 0x7c, 0x45, 0x05, 0xde, 0x81, 0xaa, 0x75, 0xec, 0x7f, 0x97, 0x5f, 0xfb, 0x77,
-	0x0e, 0x7c, 0x67, 0x3c, 0x8c, 0x7e, 0x0b, 0x52, 0xbd, 0xfd, 0xa5, 0x03, 0x6f, 0x5d, 0xb2, 0xe8,
-	0xeb, 0x64, 0xe3, 0xd6, 0x38, 0x1d, 0x8c, 0x31, 0xe3, 0xf1, 0xe0, 0xd5, 0xdf, 0x98, 0xcd, 0x89,
-	0xff, 0x2b, 0x9c, 0x5f, 0x56, 0x60, 0xa9, 0xc3, 0x46, 0x27, 0x51, 0x52, 0x3c, 0x17, 0xef, 0xd9,
-	0xd2, 0x35, 0x98, 0xdb, 0x13, 0xf6, 0x4e, 0x8a, 0x4d, 0x14, 0x2e, 0xfa, 0x08, 0xca, 0x24, 0xcc,
-	0x0d, 0x7e, 0xf7, 0x2a, 0xc0, 0x56, 0x18, 0x62, 0x25, 0xd7, 0xfa, 0x6b, 0xc9, 0x16, 0xfa, 0x7d,
-	0xa8, 0x9d, 0x44, 0x49, 0x18, 0x25, 0x7d, 0x65, 0x61, 0xf9, 0xf2, 0x5d, 0x35, 0xfb, 0x35, 0x6f,
-	0xdb, 0x08, 0xe3, 0x02, 0xd5, 0xfa, 0x6d, 0x09, 0xaa, 0x96, 0x8a, 0x10, 0x54, 0x7a, 0x59, 0x6c,
-	0x8e, 0xbe, 0x86, 0xf5, 0x3a, 0x9f, 0x75, 0xd4, 0x94, 0xe6, 0x9a, 0x59, 0xe7, 0x13, 0xa8, 0xa7,
-	0x9c, 0x3d, 0x33, 0xcf, 0xa0, 0x7c, 0x06, 0x6b, 0x98, 0xf9, 0xed, 0xb8, 0x60, 0xd8, 0x31, 0x74,
-	0x52, 0x14, 0xfd, 0x1c, 0xea, 0x22, 0x18, 0xd0, 0x11, 0xf1, 0x9f, 0x09, 0x96, 0x98, 0x17, 0xfa,
-	0xf6, 0xed, 0x97, 0x17, 0xab, 0x4d, 0x9a, 0x04, 0x4c, 0x99, 0xb0, 0xa1, 0x18, 0x1e, 0x26, 0x67,
-	0x87, 0x54, 0xe8, 0x31, 0x0c, 0x0c, 0xe0, 0xa1, 0x60, 0x09, 0xf2, 0x00, 0x04, 0xe5, 0x7e, 0xca,
-	0xe2, 0x28, 0x38, 0xd7, 0x4f, 0x87, 0x62, 0x5e, 0xee, 0x52, 0x7e, 0xac, 0xc9, 0xd8, 0x15, 0xf9,
-	0x52, 0xff, 0x62, 0xa0, 0xe7, 0x6b, 0xc9, 0xf5, 0xf3, 0xc0, 0xc5, 0x55, 0x3d, 0x46, 0x4b, 0xae,
-	0x5e, 0xe1, 0x7a, 0x44, 0x33, 0xd3, 0xbe, 0x8b, 0xed, 0xae, 0x95, 0x40, 0x79, 0x2b, 0x0c, 0x51,
-	0x13, 0xaa, 0x36, 0x40, 0x76, 0xc8, 0xcb, 0xb7, 0xe8, 0x27, 0x50, 0x0b, 0x59, 0x60, 0xec, 0x2f,
-	0xbd, 0x81, 0xfd, 0xd5, 0x90, 0x05, 0xda, 0xf8, 0x5b, 0x30, 0xd7, 0xe3, 0x2c, 0x31, 0x23, 0x57,
-	0x0d, 0x9b, 0x4d, 0xfb, 0x6f, 0x0e, 0xdc, 0x2c, 0xce, 0xc9, 0xbe, 0xf7, 0xae, 0xfe, 0x78, 0x13,
-	0xaa, 0x21, 0x8d, 0xa9, 0xb4, 0xa9, 0x5d, 0xc3, 0xf9, 0xf6, 0x92, 0x59, 0xe5, 0x6b, 0x99, 0x55,
-	0x99, 0x30, 0x6b, 0xaa, 0x37, 0xcd, 0x4d, 0xf7, 0xa6, 0xf7, 0x61, 0xd1, 0xc4, 0x2b, 0x97, 0xd0,
-	0x8f, 0x2f, 0xbc, 0x60, 0x88, 0x46, 0x68, 0xf3, 0x21, 0xd4, 0xec, 0x4b, 0x96, 0xa3, 0xcf, 0xa0,
-	0x6a, 0xd7, 0xe8, 0xed, 0x22, 0x3f, 0x2f, 0xff, 0xc6, 0xd2, 0x6a, 0xce, 0x32, 0x4c, 0x40, 0xee,
-	0x39, 0x9b, 0x07, 0x50, 0xb3, 0x51, 0xe2, 0xe8, 0x3e, 0x54, 0xed, 0x7a, 0x42, 0xd7, 0xe5, 0x5c,
-	0x9f, 0xd0, 0x35, 0x15, 0xdc, 0x75, 0xe7, 0x9e, 0xb3, 0xfd, 0xd9, 0x8b, 0xbf, 0xaf, 0xdc, 0x78,
-	0xf1, 0xd5, 0x8a, 0xf3, 0xe7, 0xaf, 0x56, 0x9c, 0x3f, 0xfc, 0x63, 0xc5, 0xf9, 0xcd, 0xdd, 0x37,
-	0x7a, 0x32, 0x5a, 0x9d, 0x27, 0xf3, 0x9a, 0xf4, 0xf1, 0x7f, 0x03, 0x00, 0x00, 0xff, 0xff, 0x34,
-	0xcc, 0xb6, 0x16, 0xa4, 0x13, 0x00, 0x00,
+	// 2362 bytes of a gzipped FileDescriptorProto
+	0x1f, 0x8b, 0x08, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0xff, 0xcd, 0x18, 0xc9, 0x72, 0x1b, 0xc7,
+	0x55, 0x58, 0x88, 0xe5, 0x81, 0x0b, 0xd8, 0xa2, 0x65, 0x18, 0x52, 0x28, 0x19, 0x56, 0x1c, 0x95,
+	0x25, 0x83, 0x32, 0x95, 0x4a, 0x6c, 0x57, 0xe2, 0x32, 0xc1, 0x25, 0xa2, 0x42, 0x8a, 0x4c, 0x93,
+	0x52, 0x55, 0x72, 0x99, 0x1a, 0xcc, 0x34, 0x80, 0x11, 0x07, 0x33, 0xe3, 0xee, 0x19, 0x4a, 0xd4,
+	0x2f, 0xe4, 0x90, 0x4b, 0x3e, 0x20, 0xbf, 0x11, 0x7f, 0x81, 0x72, 0x4b, 0xe5, 0x90, 0xca, 0x25,
+	0xae, 0x8a, 0x73, 0xcd, 0x31, 0x97, 0x24, 0x3e, 0xf8, 0xf5, 0x32, 0x83, 0x21, 0x48, 0xca, 0x8a,
+	0xec, 0x83, 0x0f, 0x20, 0xbb, 0xdf, 0xd6, 0xef, 0xbd, 0x7e, 0x5b, 0x0f, 0x74, 0x86, 0xe1, 0x4a,
+	0xc4, 0xc3, 0x38, 0x74, 0x42, 0x5f, 0xac, 0xf0, 0x24, 0x88, 0xbd, 0x31, 0x4b, 0xff, 0x77, 0x15,
+	0x86, 0x54, 0xcd, 0xb6, 0xbd, 0xdc, 0xe7, 0xe1, 0x11, 0xe3, 0x19, 0x43, 0xb6, 0xd0, 0x84, 0xed,
+	0x1b, 0x4e, 0x18, 0x88, 0x64, 0xfc, 0x12, 0x8a, 0x6b, 0xa7, 0x8e, 0x1b, 0xf8, 0xe1, 0x53, 0xf5,
+	0xc7, 0x60, 0xdb, 0xa7, 0xb0, 0x61, 0xa4, 0x7e, 0x06, 0xb7, 0x34, 0x0c, 0x87, 0xa1, 0x5a, 0xae,
+	0xc8, 0x95, 0x86, 0x76, 0xfe, 0x58, 0x80, 0xc5, 0x43, 0x5b, 0x1c, 0x1d, 0x30, 0x7e, 0xec, 0x39,
+	0x6c, 0x3d, 0x0c, 0x06, 0xde, 0x90, 0x2c, 0x43, 0xc3, 0x0f, 0x87, 0xd6, 0xc0, 0xf3, 0x99, 0x35,
+	0x70, 0x5b, 0x85, 0x1b, 0x85, 0x5b, 0x33, 0xb4, 0x8e, 0xa0, 0x2d, 0x84, 0x6c, 0xb9, 0xe4, 0x2a,
+	0xd4, 0x63, 0x64, 0xb2, 0x02, 0x7b, 0xcc, 0x5a, 0x45, 0xc4, 0xd6, 0x69, 0x4d, 0x02, 0x1e, 0xe2,
+	0x9e, 0xbc, 0x05, 0xb5, 0xc4, 0x15, 0x56, 0x64, 0xc7, 0xa3, 0x56, 0x49, 0xe1, 0xaa, 0xb8, 0xdf,
+	0xc7, 0x2d, 0xb9, 0x0d, 0x8b, 0x68, 0x61, 0x6c, 0x7b, 0x01, 0xe3, 0x56, 0xc0, 0xe2, 0xa7, 0x21,
+	0x3f, 0x6a, 0x95, 0x15, 0x4d, 0x33, 0x43, 0x3c, 0xd4, 0x70, 0x72, 0x1d, 0x1a, 0xb6, 0x8f, 0xb6,
+	0x59, 0x7e, 0xe8, 0xd8, 0x7e, 0x6b, 0x06, 0xc9, 0x6a, 0x14, 0x14, 0x68, 0x47, 0x42, 0x3a, 0xff,
+	0x29, 0xc3, 0xfc, 0xc1, 0x28, 0x19, 0x0c, 0x7c, 0x46, 0xd9, 0x67, 0x09, 0x13, 0x31, 0xd9, 0x86,
+	0xea, 0x93, 0x30, 0xe1, 0x01, 0xd2, 0x4b, 0xa5, 0xeb, 0xbd, 0x95, 0xff, 0x7e, 0x71, 0xfd, 0x36,
+	0x5a, 0x3b, 0xb4, 0x9f, 0xb3, 0x38, 0x66, 0x5d, 0x97, 0x1d, 0xaf, 0x38, 0x21, 0x67, 0x2b, 0x53,
+	0x37, 0xd1, 0x7d, 0xa0, 0xd9, 0x68, 0xca, 0x4f, 0xae, 0x40, 0x85, 0xb3, 0xc8, 0xb7, 0x4f, 0x94,
+	0x81, 0x35, 0x6a, 0x76, 0xd2, 0xbc, 0x7e, 0xe2, 0xf9, 0xae, 0xe5, 0xb9, 0xa9, 0x79, 0x6a, 0xbf,
+	0xed, 0x92, 0x2d, 0xa8, 0x84, 0x83, 0x81, 0x60, 0xb1, 0xb2, 0xa9, 0xd4, 0xeb, 0xe2, 0xe1, 0xef,
+	0xbd, 0xca, 0xe1, 0x7b, 0x8a, 0x8b, 0x1a, 0x6e, 0xb2, 0x0b, 0xc0, 0x02, 0xd7, 0x32, 0xb2, 0x66,
+	0x5e, 0x4b, 0x56, 0x1d, 0x25, 0xe8, 0x25, 0x7a, 0x7d, 0x86, 0xdb, 0xc1, 0x90, 0xb5, 0x2a, 0x28,
+	0xa9, 0xb1, 0xba, 0xd0, 0x55, 0x11, 0x43, 0x25, 0xe8, 0x20, 0x62, 0x4e, 0xaf, 0xfc, 0xe2, 0x8b,
+	0xeb, 0x97, 0xa8, 0xa6, 0x21, 0x07, 0xd0, 0x70, 0xc2, 0x90, 0xbb, 0x5e, 0x60, 0xc7, 0x21, 0x6f,
+	0x55, 0x95, 0x17, 0x3f, 0xc0, 0xc3, 0xdf, 0x3f, 0xef, 0xf0, 0x33, 0xf1, 0xda, 0x3d, 0x18, 0xd9,
+	0xdc, 0xdd, 0xde, 0xa0, 0x79, 0x29, 0xe4, 0x2e, 0x00, 0x67, 0x22, 0xf4, 0x93, 0xd8, 0x0b, 0x83,
+	0x56, 0x4d, 0xa9, 0xd1, 0xec, 0x66, 0x3c, 0xf7, 0x99, 0xed, 0x32, 0x4e, 0x73, 0x34, 0xe4, 0x1d,
+	0x98, 0x13, 0xfa, 0x6a, 0x2d, 0x2f, 0x70, 0xd9, 0xb3, 0x56, 0x1d, 0x99, 0xe6, 0xe8, 0xac, 0x01,
+	0x6e, 0x4b, 0x18, 0xf9, 0x31, 0x00, 0xf2, 0x79, 0xc7, 0xb6, 0x12, 0x0b, 0x4a, 0xec, 0x92, 0xb6,
+	0x6e, 0x3d, 0xf4, 0x7d, 0xe6, 0x48, 0xb8, 0x34, 0x91, 0xe6, 0xe8, 0xc8, 0x3a, 0x2c, 0x8c, 0xed,
+	0x18, 0xf7, 0xb6, 0xef, 0x3d, 0xd7, 0xac, 0x0d, 0xc5, 0xfa, 0x96, 0x66, 0xdd, 0x3d, 0x8d, 0x54,
+	0xfc, 0xd3, 0x1c, 0x9d, 0xbf, 0x94, 0x61, 0x21, 0x8b, 0x3d, 0x11, 0xa1, 0x1b, 0x18, 0xb9, 0x05,
+	0x15, 0x11, 0xdb, 0x71, 0x22, 0x54, 0xec, 0xcd, 0xa3, 0x85, 0xa9, 0x7b, 0xba, 0x07, 0x0a, 0x4e,
+	0x0d, 0x5e, 0x52, 0x8e, 0x94, 0xcd, 0x2a, 0xb6, 0xce, 0xf3, 0x85, 0xc1, 0x93, 0x1f, 0xc2, 0x3c,
+	0x1e, 0x3c, 0x46, 0x3f, 0xfa, 0x16, 0xe3, 0x1c, 0x6f, 0x44, 0xc7, 0xdc, 0x5c, 0x0a, 0xdd, 0x94,
+	0x40, 0xf2, 0x2b, 0x98, 0xe5, 0xc8, 0x60, 0xc5, 0x23, 0x1e, 0x26, 0xc3, 0xd1, 0x6b, 0xc6, 0x5f,
+	0x43, 0xca, 0x38, 0xd4, 0x22, 0x64, 0x10, 0x3e, 0xe5, 0x5e, 0xcc, 0x2c, 0xa9, 0xc9, 0xeb, 0x06,
+	0xa1, 0x92, 0x20, 0x4d, 0xc2, 0xcc, 0x9c, 0xb1, 0x39, 0x0b, 0x6c, 0x15, 0x84, 0xb3, 0xbd, 0x7b,
+	0x28, 0x69, 0x65, 0xe8, 0xc5, 0xa3, 0xa4, 0x8f, 0x5e, 0x1a, 0xaf, 0x60, 0xf2, 0x26, 0x36, 0x3f,
+	0xd1, 0x15, 0xed, 0x4c, 0x8d, 0xeb, 0xae, 0x49, 0x56, 0xaa, 0x25, 0xa0, 0x4f, 0xca, 0x6e, 0xe8,
+	0x08, 0x8c, 0xcd, 0x12, 0xfa, 0xae, 0xa1, 0x6f, 0xed, 0xc0, 0xc7, 0xf2, 0x65, 0x42, 0x59, 0xa1,
+	0xc9, 0x7d, 0xa8, 0xea, 0x0c, 0x12, 0x18, 0x71, 0xa5, 0xd7, 0xd0, 0x3e, 0x65, 0x97, 0x71, 0x96,
+	0x24, 0x9e, 0x8b, 0x25, 0x8d, 0xa3, 0xb0, 0xba, 0x3a, 0xd6, 0x64, 0xd1, 0xa3, 0x47, 0xdb, 0x1b,
+	0xfb, 0x12, 0x6c, 0x8e, 0xae, 0x4b, 0x42, 0x05, 0x90, 0x41, 0x1f, 0xd9, 0xce, 0x11, 0x73, 0xad,
+	0x23, 0x76, 0x82, 0xd1, 0x79, 0x81, 0xb2, 0x75, 0x4d, 0xf4, 0x4b, 0x76, 0xd2, 0x71, 0x61, 0x91,
+	0x86, 0xce, 0x91, 0xd8, 0xe8, 0x6d, 0x30, 0xe1, 0x70, 0x2f, 0x92, 0xb9, 0x73, 0x07, 0x08, 0x97,
+	0x40, 0xb7, 0x6f, 0xb1, 0xe0, 0xd8, 0x1a, 0xb3, 0x71, 0x14, 0x73, 0x15, 0x61, 0x15, 0xda, 0x34,
+	0x98, 0xcd, 0xe0, 0x78, 0x57, 0xc1, 0xc9, 0xdb, 0x18, 0x08, 0x86, 0x5a, 0x15, 0x60, 0x5d, 0x9c,
+	0x1b, 0x06, 0x26, 0x8b, 0x70, 0xe7, 0xf7, 0x45, 0xa8, 0xaf, 0xa7, 0xc5, 0x96, 0xbc, 0x09, 0x55,
+	0x2f, 0xb2, 0x6c, 0xd7, 0xd5, 0x32, 0xeb, 0xb4, 0xe2, 0x45, 0x6b, 0xb8, 0x23, 0x3f, 0x81, 0x39,
+	0x53, 0xa1, 0xad, 0x28, 0x94, 0x76, 0x17, 0x95, 0x05, 0x8b, 0xda, 0x02, 0x53, 0xa4, 0xf7, 0x11,
+	0x43, 0x67, 0x83, 0xc9, 0x46, 0x60, 0x01, 0x59, 0x1c, 0xdb, 0x51, 0x84, 0x66, 0x8f, 0x42, 0x11,
+	0x1b, 0xde, 0x92, 0xe2, 0xfd, 0x51, 0x37, 0xed, 0x8b, 0xd9, 0xf9, 0x98, 0x6d, 0x92, 0xf6, 0x3e,
+	0x92, 0x2a, 0xf6, 0xcd, 0x20, 0xe6, 0x27, 0x32, 0xdd, 0x4e, 0x41, 0xc9, 0x0f, 0xf0, 0x06, 0x84,
+	0x3d, 0x64, 0x16, 0xc7, 0x3c, 0x54, 0xd1, 0x5d, 0x44, 0x57, 0x4b, 0x08, 0x45, 0x40, 0xbb, 0x07,
+	0x4b, 0xe7, 0xc9, 0x21, 0x4d, 0x28, 0x49, 0xdf, 0x17, 0x54, 0xed, 0x90, 0x4b, 0xb2, 0x04, 0x33,
+	0xc7, 0xb6, 0x9f, 0xa4, 0x5d, 0x4b, 0x6f, 0x3e, 0x2e, 0x7e, 0x58, 0xe8, 0xfc, 0x1d, 0x60, 0x71,
+	0xdd, 0x8e, 0xe2, 0x84, 0xa7, 0xdd, 0x64, 0xf3, 0x99, 0xac, 0x9d, 0xb2, 0xed, 0x59, 0x3e, 0x3b,
+	0x66, 0xbe, 0x49, 0xeb, 0xf9, 0xae, 0x6c, 0xaa, 0x3b, 0xe1, 0xb0, 0xbb, 0x23, 0xa1, 0xb4, 0x86,
+	0x04, 0x6a, 0x85, 0x31, 0x9e, 0x5d, 0x95, 0x9b, 0x5d, 0xa0, 0x49, 0xf1, 0x76, 0x66, 0xfb, 0x99,
+	0x2b, 0xa6, 0x8b, 0x86, 0x2b, 0x77, 0xeb, 0xdb, 0x30, 0x8b, 0xb5, 0x82, 0xc7, 0x16, 0x26, 0xc7,
+	0xd8, 0x8b, 0x55, 0xd6, 0x37, 0x56, 0xdf, 0x9d, 0x38, 0x70, 0x5a, 0x53, 0x59, 0x62, 0x78, 0xbc,
+	0xae, 0xa8, 0x69, 0x43, 0x4c, 0x36, 0xe4, 0x1e, 0x94, 0xc3, 0x88, 0x05, 0xca, 0x6b, 0x8d, 0xd5,
+	0xeb, 0x2f, 0x11, 0xb1, 0x87, 0x64, 0x54, 0x11, 0xb7, 0x29, 0x34, 0x72, 0x02, 0xb1, 0x66, 0x12,
+	0xc3, 0x66, 0x39, 0x23, 0xe6, 0x1c, 0x45, 0xa1, 0x17, 0xc4, 0xca, 0x1f, 0xb2, 0xe2, 0x66, 0x65,
+	0x6e, 0x3d, 0xc3, 0xa1, 0x4d, 0x9a, 0x7e, 0x02, 0x6a, 0xff, 0xaf, 0x0a, 0x65, 0x79, 0x04, 0x79,
+	0x0f, 0x16, 0x45, 0xcc, 0x3d, 0x27, 0xb6, 0x64, 0x3d, 0x64, 0x32, 0x3f, 0x74, 0xcd, 0xac, 0xd1,
+	0x05, 0x8d, 0x90, 0xf5, 0x92, 0x61, 0x4a, 0x08, 0x39, 0x05, 0x60, 0x5b, 0x60, 0x86, 0xd4, 0xf4,
+	0x62, 0x50, 0x20, 0x45, 0x44, 0x6e, 0xc2, 0x3c, 0x26, 0x90, 0xd5, 0xb7, 0x63, 0x67, 0x64, 0x09,
+	0xef, 0x39, 0x53, 0xbe, 0xc2, 0x56, 0x81, 0xd0, 0x9e, 0x04, 0x1e, 0x20, 0x8c, 0xfc, 0x1c, 0xae,
+	0x3a, 0xbe, 0xc7, 0x82, 0xd8, 0xea, 0xe3, 0x38, 0x21, 0x93, 0x12, 0x4b, 0x25, 0x13, 0xa3, 0x10,
+	0x1b, 0xf9, 0x58, 0x28, 0xdf, 0x94, 0x69, 0x4b, 0x93, 0xf4, 0x34, 0xc5, 0x61, 0x4a, 0xb0, 0x2b,
+	0xe4, 0x21, 0x2e, 0xc7, 0x80, 0xb5, 0xc2, 0xc0, 0x94, 0x61, 0x3d, 0x8e, 0xcc, 0x2a, 0xe8, 0x5e,
+	0xa0, 0xab, 0x30, 0x46, 0xa9, 0xcb, 0x4f, 0xd2, 0x2b, 0xab, 0x28, 0x8a, 0x3a, 0x42, 0x8c, 0x13,
+	0xd1, 0x6c, 0xcf, 0xf5, 0x55, 0x41, 0xe5, 0x71, 0x9f, 0xd9, 0xb1, 0x3c, 0xb9, 0xaa, 0x4e, 0x5e,
+	0x90, 0x88, 0xfb, 0x29, 0x7c, 0x57, 0x96, 0x9c, 0x2b, 0x5e, 0x30, 0x60, 0x58, 0xef, 0x1c, 0x26,
+	0x4d, 0xef, 0x23, 0x9f, 0x3d, 0xc0, 0xa2, 0xaf, 0xba, 0x67, 0x99, 0x2e, 0x65, 0xd8, 0x03, 0x85,
+	0x5c, 0x93, 0x38, 0xf2, 0x3e, 0x5c, 0x1e, 0xdb, 0xcf, 0x2c, 0x74, 0xbd, 0xe5, 0x26, 0x5c, 0x75,
+	0x2a, 0x79, 0x46, 0x5d, 0xb1, 0x34, 0x11, 0x45, 0x93, 0x60, 0xc3, 0x20, 0xf0, 0x90, 0x77, 0x65,
+	0x27, 0x7c, 0x66, 0x2e, 0xa1, 0x7f, 0x12, 0x33, 0xa1, 0x9a, 0x68, 0x99, 0xce, 0x21, 0x58, 0x79,
+	0xb7, 0x27, 0x81, 0x92, 0x2e, 0xc2, 0x7e, 0x6a, 0xc9, 0xfb, 0x0c, 0x13, 0xa5, 0x76, 0x43, 0xd3,
+	0x49, 0xf0, 0xa1, 0x86, 0xa2, 0x3c, 0x34, 0x10, 0x5d, 0xa6, 0xc2, 0xf6, 0x89, 0x87, 0x15, 0x96,
+	0x4b, 0xca, 0x59, 0x6d, 0xa0, 0x41, 0x3c, 0x50, 0xf0, 0x5d, 0x75, 0xaf, 0xc2, 0x1e, 0x47, 0xbe,
+	0x49, 0xe9, 0x39, 0xa4, 0x2a, 0x50, 0xd0, 0x20, 0x99, 0xd3, 0xb2, 0x92, 0x89, 0x23, 0x2c, 0x4d,
+	0x5e, 0x80, 0x39, 0x8a, 0xb3, 0xd6, 0xbc, 0x72, 0x67, 0x43, 0xc2, 0xb6, 0x35, 0x88, 0xac, 0xc2,
+	0x1b, 0x22, 0xf2, 0xa4, 0x62, 0xd9, 0x5d, 0x6a, 0x2b, 0x16, 0xd4, 0x99, 0x97, 0x15, 0x32, 0xbb,
+	0x46, 0x6d, 0xcb, 0x47, 0xd0, 0xd4, 0xf6, 0x0a, 0x54, 0x0f, 0xd5, 0xf7, 0x9c, 0x93, 0x56, 0x33,
+	0x3f, 0x17, 0xe1, 0x24, 0xbc, 0xaf, 0xc0, 0x74, 0x5e, 0x11, 0x66, 0x7b, 0x39, 0xbd, 0xaa, 0x73,
+	0x25, 0xb7, 0xa3, 0x93, 0xc7, 0x6d, 0x2d, 0x2a, 0xb5, 0x9a, 0x29, 0xc2, 0x24, 0x95, 0x8b, 0x19,
+	0x53, 0xeb, 0xe3, 0xe4, 0xe2, 0x05, 0x43, 0xd1, 0x22, 0xd3, 0xd5, 0xef, 0xdc, 0xcc, 0xeb, 0xf6,
+	0x34, 0x3d, 0xcd, 0x18, 0xdb, 0x7f, 0x2a, 0x40, 0xd5, 0x40, 0x49, 0x17, 0x20, 0xa7, 0x72, 0xe1,
+	0x7c, 0x95, 0xeb, 0x22, 0xd3, 0x76, 0x19, 0xdb, 0x0f, 0x0f, 0x9f, 0xe8, 0x21, 0x48, 0x15, 0xef,
+	0x3a, 0xcd, 0x41, 0xe4, 0x1c, 0x2b, 0xb0, 0x52, 0x5a, 0xb2, 0x9b, 0x98, 0x39, 0x56, 0xee, 0xf7,
+	0xb1, 0x89, 0xdc, 0x80, 0x46, 0x64, 0x0b, 0x91, 0x1f, 0x26, 0xd0, 0xf3, 0x39, 0x10, 0xf9, 0x00,
+	0x96, 0x30, 0xe9, 0x03, 0x14, 0x15, 0x72, 0xd5, 0x16, 0x3d, 0x29, 0x53, 0x98, 0xac, 0xb8, 0x9c,
+	0xe1, 0xf6, 0x33, 0x54, 0xe7, 0xab, 0x32, 0x90, 0xcc, 0x70, 0x3d, 0x31, 0xc9, 0x02, 0x7b, 0x17,
+	0xea, 0xd9, 0xe4, 0x6f, 0xac, 0x22, 0x67, 0xdb, 0x04, 0x9d, 0x10, 0x91, 0x8f, 0x71, 0xca, 0x46,
+	0x77, 0xa1, 0xef, 0x75, 0x65, 0xed, 0x9c, 0xf5, 0x6b, 0x26, 0x5e, 0x39, 0x96, 0xb9, 0xd4, 0x70,
+	0x90, 0x4f, 0xa1, 0x96, 0xdd, 0x9c, 0x2e, 0xa9, 0x37, 0x5f, 0xc6, 0x9d, 0xde, 0x26, 0xcd, 0xb8,
+	0x70, 0xc6, 0x87, 0x5c, 0x05, 0x2c, 0x5f, 0x54, 0x96, 0x73, 0x32, 0x26, 0x35, 0x31, 0xc7, 0xd9,
+	0xde, 0x85, 0x8a, 0xd6, 0xed, 0xbb, 0xa9, 0xad, 0x8f, 0xa1, 0x96, 0x85, 0x1e, 0x96, 0x21, 0xac,
+	0xa8, 0x96, 0x9e, 0x2b, 0x94, 0xa0, 0x59, 0x5a, 0x47, 0xc8, 0xbe, 0x02, 0xc8, 0x30, 0x9e, 0xdc,
+	0x58, 0x4a, 0x55, 0x54, 0x54, 0xcd, 0x09, 0x42, 0x13, 0xb7, 0x9f, 0x02, 0x4c, 0x4e, 0xc1, 0xc0,
+	0x98, 0x91, 0x39, 0x21, 0x8c, 0x76, 0xa0, 0x3a, 0xa1, 0x2c, 0x14, 0x82, 0x6a, 0x04, 0xf9, 0x05,
+	0x86, 0x8e, 0x2c, 0x15, 0x98, 0x73, 0x89, 0x1f, 0x2b, 0xb1, 0xf3, 0x2f, 0xf7, 0x0f, 0x86, 0xab,
+	0x4f, 0x15, 0x35, 0x86, 0x67, 0xb6, 0xee, 0x3c, 0x04, 0x98, 0x60, 0x48, 0x03, 0xaa, 0xdb, 0x0f,
+	0x1f, 0xaf, 0xed, 0x6c, 0x6f, 0x34, 0x2f, 0x91, 0x3a, 0xcc, 0xd0, 0xcd, 0xb5, 0x8d, 0x5f, 0x37,
+	0x0b, 0x64, 0x0e, 0xea, 0x0f, 0xf7, 0x0e, 0x2d, 0xbd, 0x2d, 0x92, 0x59, 0xf4, 0xc2, 0xde, 0xde,
+	0x8e, 0xb5, 0xb7, 0xb5, 0xd5, 0x2c, 0x49, 0x26, 0xba, 0x79, 0x70, 0xb8, 0x46, 0x0f, 0x9b, 0xe5,
+	0xce, 0xbf, 0x0a, 0xd0, 0xdc, 0x90, 0x8f, 0x80, 0xef, 0x43, 0x77, 0x5f, 0x35, 0x2d, 0x59, 0x87,
+	0xe0, 0x72, 0xc6, 0x3c, 0xad, 0x60, 0xbe, 0x23, 0xdf, 0x31, 0xcd, 0x13, 0x5b, 0x91, 0xf8, 0xcc,
+	0x97, 0x83, 0xf9, 0xf1, 0x40, 0x58, 0x09, 0xf7, 0xcc, 0xdc, 0x36, 0xab, 0xa1, 0x8f, 0x07, 0xe2,
+	0x11, 0xf7, 0x3a, 0xff, 0x2e, 0xc1, 0x62, 0x2a, 0xed, 0xdb, 0x24, 0xdb, 0x47, 0x53, 0xc9, 0xf6,
+	0xf6, 0x19, 0x5d, 0x2f, 0xcc, 0xb5, 0x1e, 0xd4, 0xa3, 0xa4, 0xef, 0x7b, 0x62, 0x74, 0x4e, 0xb2,
+	0x9d, 0xe5, 0xde, 0x4f, 0x69, 0xe9, 0x84, 0x8d, 0xfc, 0x0c, 0xaa, 0x03, 0x3f, 0x51, 0x12, 0xca,
+	0x53, 0xc9, 0x7e, 0x56, 0xc2, 0x96, 0xa6, 0xa4, 0x29, 0xcb, 0x77, 0x9d, 0x63, 0x31, 0xd4, 0x33,
+	0x25, 0xe5, 0x27, 0x10, 0xd9, 0x3b, 0x1d, 0x39, 0x2b, 0x98, 0x69, 0xbc, 0x86, 0x80, 0x75, 0xb9,
+	0x9f, 0xca, 0xc0, 0xe2, 0x2b, 0x65, 0x60, 0xe9, 0x82, 0x0c, 0xbc, 0x0d, 0x55, 0x63, 0xd8, 0x37,
+	0xa7, 0x5f, 0xe7, 0x77, 0x05, 0x78, 0x63, 0xf2, 0x7e, 0xfd, 0x1e, 0x84, 0x7a, 0xe7, 0xf3, 0x02,
+	0x5c, 0x39, 0xa5, 0xd1, 0xb7, 0x89, 0xc6, 0xb5, 0x49, 0x38, 0x68, 0x65, 0x26, 0x3d, 0xf5, 0xfc,
+	0x33, 0xce, 0xc6, 0xc4, 0xff, 0xe5, 0xce, 0xcf, 0xcb, 0x30, 0x8f, 0xc3, 0x1b, 0xf6, 0xe3, 0xec,
+	0x0b, 0xd3, 0x5d, 0x93, 0xba, 0x9a, 0xe7, 0x5a, 0x4e, 0xdf, 0x3c, 0x59, 0x2e, 0x71, 0x71, 0x28,
+	0x2b, 0xe1, 0xf3, 0xca, 0x28, 0x7c, 0xf5, 0x22, 0x06, 0x7c, 0x73, 0x51, 0x49, 0xd7, 0xfe, 0x6b,
+	0xd1, 0x24, 0xfa, 0xa7, 0xb9, 0x09, 0xa2, 0xa0, 0x26, 0x88, 0x9b, 0x2f, 0x3b, 0xed, 0x9c, 0xf1,
+	0xe1, 0xb7, 0xc5, 0xc9, 0xf8, 0x40, 0xa0, 0x3c, 0x48, 0x7c, 0xdf, 0x8c, 0xd9, 0x6a, 0x9d, 0x3e,
+	0x8f, 0xf4, 0x6c, 0xa0, 0x9e, 0x47, 0x1f, 0x62, 0xf9, 0xce, 0x46, 0x84, 0xf4, 0xd9, 0xd6, 0xd4,
+	0x53, 0xc6, 0x7e, 0x86, 0x30, 0x2f, 0xd7, 0x3c, 0x29, 0x0e, 0xd8, 0x0d, 0x81, 0xa9, 0x35, 0xb6,
+	0xad, 0x27, 0x22, 0xd4, 0x8f, 0x8d, 0x7a, 0xef, 0x1a, 0xbe, 0xb8, 0x5b, 0x38, 0xa4, 0x86, 0x52,
+	0x85, 0x15, 0x89, 0xe8, 0x52, 0xfb, 0xe9, 0x2e, 0x13, 0xea, 0xe5, 0x06, 0x9a, 0xe1, 0x01, 0x82,
+	0xa7, 0xa6, 0x9b, 0x99, 0x6f, 0x9c, 0x6e, 0xe4, 0x47, 0x46, 0xf5, 0x24, 0xc7, 0xe9, 0xa5, 0x62,
+	0x3e, 0x32, 0xca, 0x97, 0x37, 0x4e, 0x2f, 0x57, 0xa0, 0xa2, 0x5e, 0x75, 0xfa, 0x03, 0x01, 0x3e,
+	0x68, 0xf5, 0xae, 0x1d, 0x40, 0x09, 0x9d, 0x4c, 0x5a, 0x50, 0x35, 0x0e, 0x32, 0xef, 0xc2, 0x74,
+	0x4b, 0x7e, 0x0a, 0x35, 0x37, 0x74, 0xb4, 0xfe, 0xc5, 0x57, 0xd0, 0xbf, 0x8a, 0xd4, 0x4a, 0x79,
+	0x7c, 0x54, 0x0e, 0x38, 0x06, 0xa9, 0xca, 0xe1, 0x1a, 0xd5, 0x9b, 0xce, 0xdf, 0x0a, 0xb0, 0x90,
+	0xdd, 0x93, 0xf9, 0x44, 0x74, 0xf1, 0xe1, 0x88, 0x71, 0x99, 0xcf, 0x62, 0x13, 0xda, 0x35, 0x9a,
+	0x6e, 0x4f, 0xa9, 0x55, 0x7a, 0x2d, 0xb5, 0xca, 0x39, 0xb5, 0xa6, 0x6a, 0xd3, 0xcc, 0x74, 0x6d,
+	0x7a, 0x07, 0xe6, 0xb4, 0xbf, 0x52, 0x0a, 0xf5, 0xbd, 0x86, 0xce, 0x6a, 0xa0, 0x26, 0x5a, 0x7d,
+	0x00, 0x35, 0xf3, 0xf1, 0x8b, 0x93, 0x4f, 0xa0, 0x6a, 0xd6, 0xe4, 0xcd, 0x2c, 0x3e, 0x4f, 0x7f,
+	0x96, 0x6d, 0xb7, 0xce, 0x22, 0xb4, 0x43, 0xee, 0x16, 0x56, 0x77, 0xb0, 0x67, 0x6b, 0x2f, 0x71,
+	0x0c, 0xf9, 0xaa, 0x59, 0xe7, 0x64, 0x9d, 0x8e, 0xf5, 0x9c, 0xac, 0x29, 0xe7, 0xde, 0x2a, 0xdc,
+	0x2d, 0xf4, 0x3e, 0x79, 0xf1, 0x8f, 0xe5, 0x4b, 0x2f, 0xbe, 0x5c, 0x2e, 0xfc, 0x19, 0x7f, 0x7f,
+	0xf8, 0xe7, 0x72, 0xe1, 0x37, 0x77, 0x5e, 0xe9, 0x2b, 0x93, 0x91, 0xd9, 0xaf, 0x28, 0xd0, 0xbd,
+	0xaf, 0x01, 0x93, 0x20, 0xa4, 0xf3, 0xd7, 0x17, 0x00, 0x00,
 }
 
 // Reference imports to suppress errors if they are not otherwise used.
@@ -2044,6 +2215,18 @@ func (m *CaptureRequestExt) MarshalToSizedBuffer(dAtA []byte) (int, error) {
 		i -= len(m.XXX_unrecognized)
 		copy(dAtA[i:], m.XXX_unrecognized)
 	}
+	if m.Open != nil {
+		{
+			size, err := m.Open.MarshalToSizedBuffer(dAtA[:i])
+			if err != nil {
+				return 0, err
+			}
+			i -= size
+			i = encodeVarintRuntime(dAtA, i, uint64(size))
+		}
+		i--
+		dAtA[i] = 0x22
+	}
 	if m.StartCommit != nil {
 		{
 			size, err := m.StartCommit.MarshalToSizedBuffer(dAtA[:i])
@@ -2115,6 +2298,251 @@ func (m *CaptureRequestExt_StartCommit) MarshalToSizedBuffer(dAtA []byte) (int,
 	return len(dAtA) - i, nil
 }
 
+func (m *CaptureRequestExt_Open) Marshal() (dAtA []byte, err error) {
+	size := m.ProtoSize()
+	dAtA = make([]byte, size)
+	n, err := m.MarshalToSizedBuffer(dAtA[:size])
+	if err != nil {
+		return nil, err
+	}
+	return dAtA[:n], nil
+}
+
+func (m *CaptureRequestExt_Open) MarshalTo(dAtA []byte) (int, error) {
+	size := m.ProtoSize()
+	return m.MarshalToSizedBuffer(dAtA[:size])
+}
+
+func (m *CaptureRequestExt_Open) MarshalToSizedBuffer(dAtA []byte) (int, error) {
+	i := len(dAtA)
+	_ = i
+	var l int
+	_ = l
+	if m.XXX_unrecognized != nil {
+		i -= len(m.XXX_unrecognized)
+		copy(dAtA[i:], m.XXX_unrecognized)
+	}
+	if len(m.Bindings) > 0 {
+		for iNdEx := len(m.Bindings) - 1; iNdEx >= 0; iNdEx-- {
+			{
+				size, err := m.Bindings[iNdEx].MarshalToSizedBuffer(dAtA[:i])
+				if err != nil {
+					return 0, err
+				}
+				i -= size
+				i = encodeVarintRuntime(dAtA, i, uint64(size))
+			}
+			i--
+			dAtA[i] = 0x1
+			i--
+			dAtA[i] = 0x92
+		}
+	}
+	if m.ValidateCaptured {
+		i--
+		if m.ValidateCaptured {
+			dAtA[i] = 1
+		} else {
+			dAtA[i] = 0
+		}
+		i--
+		dAtA[i] = 0x1
+		i--
+		dAtA[i] = 0x88
+	}
+	if m.StateSerPolicy != nil {
+		{
+			size, err := m.StateSerPolicy.MarshalToSizedBuffer(dAtA[:i])
+			if err != nil {
+				return 0, err
+			}
+			i -= size
+			i = encodeVarintRuntime(dAtA, i, uint64(size))
+		}
+		i--
+		dAtA[i] = 0x1
+		i--
+		dAtA[i] = 0x82
+	}
+	if m.SpillThresholdBytes != 0 {
+		i = encodeVarintRuntime(dAtA, i, uint64(m.SpillThresholdBytes))
+		i--
+		dAtA[i] = 0x78
+	}
+	if m.SkipInvalid {
+		i--
+		if m.SkipInvalid {
+			dAtA[i] = 1
+		} else {
+			dAtA[i] = 0
+		}
+		i--
+		dAtA[i] = 0x70
+	}
+	if m.SampleRate != 0 {
+		i -= 8
+		encoding_binary.LittleEndian.PutUint64(dAtA[i:], uint64(math.Float64bits(float64(m.SampleRate))))
+		i--
+		dAtA[i] = 0x69
+	}
+	if m.RestartJitterMs != 0 {
+		i = encodeVarintRuntime(dAtA, i, uint64(m.RestartJitterMs))
+		i--
+		dAtA[i] = 0x60
+	}
+	if m.PollTimeoutMs != 0 {
+		i = encodeVarintRuntime(dAtA, i, uint64(m.PollTimeoutMs))
+		i--
+		dAtA[i] = 0x58
+	}
+	if m.MaxStateBytes != 0 {
+		i = encodeVarintRuntime(dAtA, i, uint64(m.MaxStateBytes))
+		i--
+		dAtA[i] = 0x50
+	}
+	if m.MaxRunDurationMs != 0 {
+		i = encodeVarintRuntime(dAtA, i, uint64(m.MaxRunDurationMs))
+		i--
+		dAtA[i] = 0x48
+	}
+	if m.InferenceStableAfter != 0 {
+		i = encodeVarintRuntime(dAtA, i, uint64(m.InferenceStableAfter))
+		i--
+		dAtA[i] = 0x40
+	}
+	if m.IdleHeartbeatMs != 0 {
+		i = encodeVarintRuntime(dAtA, i, uint64(m.IdleHeartbeatMs))
+		i--
+		dAtA[i] = 0x38
+	}
+	if m.DryCommit {
+		i--
+		if m.DryCommit {
+			dAtA[i] = 1
+		} else {
+			dAtA[i] = 0
+		}
+		i--
+		dAtA[i] = 0x30
+	}
+	if m.DrainOnError {
+		i--
+		if m.DrainOnError {
+			dAtA[i] = 1
+		} else {
+			dAtA[i] = 0
+		}
+		i--
+		dAtA[i] = 0x28
+	}
+	if m.ClientBlockedThresholdMs != 0 {
+		i = encodeVarintRuntime(dAtA, i, uint64(m.ClientBlockedThresholdMs))
+		i--
+		dAtA[i] = 0x20
+	}
+	if m.AckBatchSize != 0 {
+		i = encodeVarintRuntime(dAtA, i, uint64(m.AckBatchSize))
+		i--
+		dAtA[i] = 0x18
+	}
+	if m.ResetState {
+		i--
+		if m.ResetState {
+			dAtA[i] = 1
+		} else {
+			dAtA[i] = 0
+		}
+		i--
+		dAtA[i] = 0x10
+	}
+	if m.StrictStateKeys {
+		i--
+		if m.StrictStateKeys {
+			dAtA[i] = 1
+		} else {
+			dAtA[i] = 0
+		}
+		i--
+		dAtA[i] = 0x8
+	}
+	return len(dAtA) - i, nil
+}
+
+func (m *CaptureRequestExt_Open_Binding) Marshal() (dAtA []byte, err error) {
+	size := m.ProtoSize()
+	dAtA = make([]byte, size)
+	n, err := m.MarshalToSizedBuffer(dAtA[:size])
+	if err != nil {
+		return nil, err
+	}
+	return dAtA[:n], nil
+}
+
+func (m *CaptureRequestExt_Open_Binding) MarshalTo(dAtA []byte) (int, error) {
+	size := m.ProtoSize()
+	return m.MarshalToSizedBuffer(dAtA[:size])
+}
+
+func (m *CaptureRequestExt_Open_Binding) MarshalToSizedBuffer(dAtA []byte) (int, error) {
+	i := len(dAtA)
+	_ = i
+	var l int
+	_ = l
+	if m.XXX_unrecognized != nil {
+		i -= len(m.XXX_unrecognized)
+		copy(dAtA[i:], m.XXX_unrecognized)
+	}
+	if m.ConnectorPartitions {
+		i--
+		if m.ConnectorPartitions {
+			dAtA[i] = 1
+		} else {
+			dAtA[i] = 0
+		}
+		i--
+		dAtA[i] = 0x28
+	}
+	if m.Passthrough {
+		i--
+		if m.Passthrough {
+			dAtA[i] = 1
+		} else {
+			dAtA[i] = 0
+		}
+		i--
+		dAtA[i] = 0x20
+	}
+	if len(m.SortPtr) > 0 {
+		i -= len(m.SortPtr)
+		copy(dAtA[i:], m.SortPtr)
+		i = encodeVarintRuntime(dAtA, i, uint64(len(m.SortPtr)))
+		i--
+		dAtA[i] = 0x1a
+	}
+	if len(m.Projection) > 0 {
+		for iNdEx := len(m.Projection) - 1; iNdEx >= 0; iNdEx-- {
+			i -= len(m.Projection[iNdEx])
+			copy(dAtA[i:], m.Projection[iNdEx])
+			i = encodeVarintRuntime(dAtA, i, uint64(len(m.Projection[iNdEx])))
+			i--
+			dAtA[i] = 0x12
+		}
+	}
+	if m.SerPolicy != nil {
+		{
+			size, err := m.SerPolicy.MarshalToSizedBuffer(dAtA[:i])
+			if err != nil {
+				return 0, err
+			}
+			i -= size
+			i = encodeVarintRuntime(dAtA, i, uint64(size))
+		}
+		i--
+		dAtA[i] = 0xa
+	}
+	return len(dAtA) - i, nil
+}
+
 func (m *CaptureResponseExt) Marshal() (dAtA []byte, err error) {
 	size := m.ProtoSize()
 	dAtA = make([]byte, size)
@@ -3265,6 +3693,10 @@ func (m *CaptureRequestExt) ProtoSize() (n int) {
 		l = m.StartCommit.ProtoSize()
 		n += 1 + l + sovRuntime(uint64(l))
 	}
+	if m.Open != nil {
+		l = m.Open.ProtoSize()
+		n += 1 + l + sovRuntime(uint64(l))
+	}
 	if m.XXX_unrecognized != nil {
 		n += len(m.XXX_unrecognized)
 	}
@@ -3287,37 +3719,139 @@ func (m *CaptureRequestExt_StartCommit) ProtoSize() (n int) {
 	return n
 }
 
-func (m *CaptureResponseExt) ProtoSize() (n int) {
+func (m *CaptureRequestExt_Open) ProtoSize() (n int) {
 	if m == nil {
 		return 0
 	}
 	var l int
 	_ = l
-	if m.Container != nil {
-		l = m.Container.ProtoSize()
-		n += 1 + l + sovRuntime(uint64(l))
+	if m.StrictStateKeys {
+		n += 2
 	}
-	if m.Opened != nil {
-		l = m.Opened.ProtoSize()
-		n += 1 + l + sovRuntime(uint64(l))
+	if m.ResetState {
+		n += 2
 	}
-	if m.Captured != nil {
-		l = m.Captured.ProtoSize()
-		n += 1 + l + sovRuntime(uint64(l))
+	if m.AckBatchSize != 0 {
+		n += 1 + sovRuntime(uint64(m.AckBatchSize))
 	}
-	if m.Checkpoint != nil {
-		l = m.Checkpoint.ProtoSize()
-		n += 1 + l + sovRuntime(uint64(l))
+	if m.ClientBlockedThresholdMs != 0 {
+		n += 1 + sovRuntime(uint64(m.ClientBlockedThresholdMs))
 	}
-	if m.XXX_unrecognized != nil {
-		n += len(m.XXX_unrecognized)
+	if m.DrainOnError {
+		n += 2
 	}
-	return n
-}
-
-func (m *CaptureResponseExt_Opened) ProtoSize() (n int) {
-	if m == nil {
-		return 0
+	if m.DryCommit {
+		n += 2
+	}
+	if m.IdleHeartbeatMs != 0 {
+		n += 1 + sovRuntime(uint64(m.IdleHeartbeatMs))
+	}
+	if m.InferenceStableAfter != 0 {
+		n += 1 + sovRuntime(uint64(m.InferenceStableAfter))
+	}
+	if m.MaxRunDurationMs != 0 {
+		n += 1 + sovRuntime(uint64(m.MaxRunDurationMs))
+	}
+	if m.MaxStateBytes != 0 {
+		n += 1 + sovRuntime(uint64(m.MaxStateBytes))
+	}
+	if m.PollTimeoutMs != 0 {
+		n += 1 + sovRuntime(uint64(m.PollTimeoutMs))
+	}
+	if m.RestartJitterMs != 0 {
+		n += 1 + sovRuntime(uint64(m.RestartJitterMs))
+	}
+	if m.SampleRate != 0 {
+		n += 9
+	}
+	if m.SkipInvalid {
+		n += 2
+	}
+	if m.SpillThresholdBytes != 0 {
+		n += 1 + sovRuntime(uint64(m.SpillThresholdBytes))
+	}
+	if m.StateSerPolicy != nil {
+		l = m.StateSerPolicy.ProtoSize()
+		n += 2 + l + sovRuntime(uint64(l))
+	}
+	if m.ValidateCaptured {
+		n += 3
+	}
+	if len(m.Bindings) > 0 {
+		for _, e := range m.Bindings {
+			l = e.ProtoSize()
+			n += 2 + l + sovRuntime(uint64(l))
+		}
+	}
+	if m.XXX_unrecognized != nil {
+		n += len(m.XXX_unrecognized)
+	}
+	return n
+}
+
+func (m *CaptureRequestExt_Open_Binding) ProtoSize() (n int) {
+	if m == nil {
+		return 0
+	}
+	var l int
+	_ = l
+	if m.SerPolicy != nil {
+		l = m.SerPolicy.ProtoSize()
+		n += 1 + l + sovRuntime(uint64(l))
+	}
+	if len(m.Projection) > 0 {
+		for _, s := range m.Projection {
+			l = len(s)
+			n += 1 + l + sovRuntime(uint64(l))
+		}
+	}
+	l = len(m.SortPtr)
+	if l > 0 {
+		n += 1 + l + sovRuntime(uint64(l))
+	}
+	if m.Passthrough {
+		n += 2
+	}
+	if m.ConnectorPartitions {
+		n += 2
+	}
+	if m.XXX_unrecognized != nil {
+		n += len(m.XXX_unrecognized)
+	}
+	return n
+}
+
+func (m *CaptureResponseExt) ProtoSize() (n int) {
+	if m == nil {
+		return 0
+	}
+	var l int
+	_ = l
+	if m.Container != nil {
+		l = m.Container.ProtoSize()
+		n += 1 + l + sovRuntime(uint64(l))
+	}
+	if m.Opened != nil {
+		l = m.Opened.ProtoSize()
+		n += 1 + l + sovRuntime(uint64(l))
+	}
+	if m.Captured != nil {
+		l = m.Captured.ProtoSize()
+		n += 1 + l + sovRuntime(uint64(l))
+	}
+	if m.Checkpoint != nil {
+		l = m.Checkpoint.ProtoSize()
+		n += 1 + l + sovRuntime(uint64(l))
+	}
+	if m.XXX_unrecognized != nil {
+		n += len(m.XXX_unrecognized)
+	}
+	return n
+}
+
+func (m *CaptureResponseExt_Opened) ProtoSize() (n int) {
+	if m == nil {
+		return 0
 	}
 	var l int
 	_ = l
@@ -5084,6 +5618,42 @@ func (m *CaptureRequestExt) Unmarshal(dAtA []byte) error {
 				return err
 			}
 			iNdEx = postIndex
+		case 4:
+			if wireType != 2 {
+				return fmt.Errorf("proto: wrong wireType = %d for field Open", wireType)
+			}
+			var msglen int
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowRuntime
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				msglen |= int(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
+			if msglen < 0 {
+				return ErrInvalidLengthRuntime
+			}
+			postIndex := iNdEx + msglen
+			if postIndex < 0 {
+				return ErrInvalidLengthRuntime
+			}
+			if postIndex > l {
+				return io.ErrUnexpectedEOF
+			}
+			if m.Open == nil {
+				m.Open = &CaptureRequestExt_Open{}
+			}
+			if err := m.Open.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
+				return err
+			}
+			iNdEx = postIndex
 		default:
 			iNdEx = preIndex
 			skippy, err := skipRuntime(dAtA[iNdEx:])
@@ -5193,6 +5763,620 @@ func (m *CaptureRequestExt_StartCommit) Unmarshal(dAtA []byte) error {
 	}
 	return nil
 }
+func (m *CaptureRequestExt_Open) Unmarshal(dAtA []byte) error {
+	l := len(dAtA)
+	iNdEx := 0
+	for iNdEx < l {
+		preIndex := iNdEx
+		var wire uint64
+		for shift := uint(0); ; shift += 7 {
+			if shift >= 64 {
+				return ErrIntOverflowRuntime
+			}
+			if iNdEx >= l {
+				return io.ErrUnexpectedEOF
+			}
+			b := dAtA[iNdEx]
+			iNdEx++
+			wire |= uint64(b&0x7F) << shift
+			if b < 0x80 {
+				break
+			}
+		}
+		fieldNum := int32(wire >> 3)
+		wireType := int(wire & 0x7)
+		if wireType == 4 {
+			return fmt.Errorf("proto: Open: wiretype end group for non-group")
+		}
+		if fieldNum <= 0 {
+			return fmt.Errorf("proto: Open: illegal tag %d (wire type %d)", fieldNum, wire)
+		}
+		switch fieldNum {
+		case 1:
+			if wireType != 0 {
+				return fmt.Errorf("proto: wrong wireType = %d for field StrictStateKeys", wireType)
+			}
+			var v int
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowRuntime
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				v |= int(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
+			m.StrictStateKeys = bool(v != 0)
+		case 2:
+			if wireType != 0 {
+				return fmt.Errorf("proto: wrong wireType = %d for field ResetState", wireType)
+			}
+			var v int
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowRuntime
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				v |= int(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
+			m.ResetState = bool(v != 0)
+		case 3:
+			if wireType != 0 {
+				return fmt.Errorf("proto: wrong wireType = %d for field AckBatchSize", wireType)
+			}
+			m.AckBatchSize = 0
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowRuntime
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				m.AckBatchSize |= uint32(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
+		case 4:
+			if wireType != 0 {
+				return fmt.Errorf("proto: wrong wireType = %d for field ClientBlockedThresholdMs", wireType)
+			}
+			m.ClientBlockedThresholdMs = 0
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowRuntime
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				m.ClientBlockedThresholdMs |= uint64(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
+		case 5:
+			if wireType != 0 {
+				return fmt.Errorf("proto: wrong wireType = %d for field DrainOnError", wireType)
+			}
+			var v int
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowRuntime
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				v |= int(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
+			m.DrainOnError = bool(v != 0)
+		case 6:
+			if wireType != 0 {
+				return fmt.Errorf("proto: wrong wireType = %d for field DryCommit", wireType)
+			}
+			var v int
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowRuntime
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				v |= int(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
+			m.DryCommit = bool(v != 0)
+		case 7:
+			if wireType != 0 {
+				return fmt.Errorf("proto: wrong wireType = %d for field IdleHeartbeatMs", wireType)
+			}
+			m.IdleHeartbeatMs = 0
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowRuntime
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				m.IdleHeartbeatMs |= uint64(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
+		case 8:
+			if wireType != 0 {
+				return fmt.Errorf("proto: wrong wireType = %d for field InferenceStableAfter", wireType)
+			}
+			m.InferenceStableAfter = 0
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowRuntime
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				m.InferenceStableAfter |= uint64(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
+		case 9:
+			if wireType != 0 {
+				return fmt.Errorf("proto: wrong wireType = %d for field MaxRunDurationMs", wireType)
+			}
+			m.MaxRunDurationMs = 0
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowRuntime
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				m.MaxRunDurationMs |= uint64(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
+		case 10:
+			if wireType != 0 {
+				return fmt.Errorf("proto: wrong wireType = %d for field MaxStateBytes", wireType)
+			}
+			m.MaxStateBytes = 0
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowRuntime
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				m.MaxStateBytes |= uint64(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
+		case 11:
+			if wireType != 0 {
+				return fmt.Errorf("proto: wrong wireType = %d for field PollTimeoutMs", wireType)
+			}
+			m.PollTimeoutMs = 0
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowRuntime
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				m.PollTimeoutMs |= uint64(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
+		case 12:
+			if wireType != 0 {
+				return fmt.Errorf("proto: wrong wireType = %d for field RestartJitterMs", wireType)
+			}
+			m.RestartJitterMs = 0
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowRuntime
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				m.RestartJitterMs |= uint64(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
+		case 13:
+			if wireType != 1 {
+				return fmt.Errorf("proto: wrong wireType = %d for field SampleRate", wireType)
+			}
+			var v uint64
+			if (iNdEx + 8) > l {
+				return io.ErrUnexpectedEOF
+			}
+			v = uint64(encoding_binary.LittleEndian.Uint64(dAtA[iNdEx:]))
+			iNdEx += 8
+			m.SampleRate = float64(math.Float64frombits(v))
+		case 14:
+			if wireType != 0 {
+				return fmt.Errorf("proto: wrong wireType = %d for field SkipInvalid", wireType)
+			}
+			var v int
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowRuntime
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				v |= int(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
+			m.SkipInvalid = bool(v != 0)
+		case 15:
+			if wireType != 0 {
+				return fmt.Errorf("proto: wrong wireType = %d for field SpillThresholdBytes", wireType)
+			}
+			m.SpillThresholdBytes = 0
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowRuntime
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				m.SpillThresholdBytes |= uint64(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
+		case 16:
+			if wireType != 2 {
+				return fmt.Errorf("proto: wrong wireType = %d for field StateSerPolicy", wireType)
+			}
+			var msglen int
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowRuntime
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				msglen |= int(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
+			if msglen < 0 {
+				return ErrInvalidLengthRuntime
+			}
+			postIndex := iNdEx + msglen
+			if postIndex < 0 {
+				return ErrInvalidLengthRuntime
+			}
+			if postIndex > l {
+				return io.ErrUnexpectedEOF
+			}
+			if m.StateSerPolicy == nil {
+				m.StateSerPolicy = &flow.SerPolicy{}
+			}
+			if err := m.StateSerPolicy.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
+				return err
+			}
+			iNdEx = postIndex
+		case 17:
+			if wireType != 0 {
+				return fmt.Errorf("proto: wrong wireType = %d for field ValidateCaptured", wireType)
+			}
+			var v int
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowRuntime
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				v |= int(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
+			m.ValidateCaptured = bool(v != 0)
+		case 18:
+			if wireType != 2 {
+				return fmt.Errorf("proto: wrong wireType = %d for field Bindings", wireType)
+			}
+			var msglen int
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowRuntime
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				msglen |= int(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
+			if msglen < 0 {
+				return ErrInvalidLengthRuntime
+			}
+			postIndex := iNdEx + msglen
+			if postIndex < 0 {
+				return ErrInvalidLengthRuntime
+			}
+			if postIndex > l {
+				return io.ErrUnexpectedEOF
+			}
+			m.Bindings = append(m.Bindings, &CaptureRequestExt_Open_Binding{})
+			if err := m.Bindings[len(m.Bindings)-1].Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
+				return err
+			}
+			iNdEx = postIndex
+		default:
+			iNdEx = preIndex
+			skippy, err := skipRuntime(dAtA[iNdEx:])
+			if err != nil {
+				return err
+			}
+			if (skippy < 0) || (iNdEx+skippy) < 0 {
+				return ErrInvalidLengthRuntime
+			}
+			if (iNdEx + skippy) > l {
+				return io.ErrUnexpectedEOF
+			}
+			m.XXX_unrecognized = append(m.XXX_unrecognized, dAtA[iNdEx:iNdEx+skippy]...)
+			iNdEx += skippy
+		}
+	}
+
+	if iNdEx > l {
+		return io.ErrUnexpectedEOF
+	}
+	return nil
+}
+func (m *CaptureRequestExt_Open_Binding) Unmarshal(dAtA []byte) error {
+	l := len(dAtA)
+	iNdEx := 0
+	for iNdEx < l {
+		preIndex := iNdEx
+		var wire uint64
+		for shift := uint(0); ; shift += 7 {
+			if shift >= 64 {
+				return ErrIntOverflowRuntime
+			}
+			if iNdEx >= l {
+				return io.ErrUnexpectedEOF
+			}
+			b := dAtA[iNdEx]
+			iNdEx++
+			wire |= uint64(b&0x7F) << shift
+			if b < 0x80 {
+				break
+			}
+		}
+		fieldNum := int32(wire >> 3)
+		wireType := int(wire & 0x7)
+		if wireType == 4 {
+			return fmt.Errorf("proto: Binding: wiretype end group for non-group")
+		}
+		if fieldNum <= 0 {
+			return fmt.Errorf("proto: Binding: illegal tag %d (wire type %d)", fieldNum, wire)
+		}
+		switch fieldNum {
+		case 1:
+			if wireType != 2 {
+				return fmt.Errorf("proto: wrong wireType = %d for field SerPolicy", wireType)
+			}
+			var msglen int
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowRuntime
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				msglen |= int(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
+			if msglen < 0 {
+				return ErrInvalidLengthRuntime
+			}
+			postIndex := iNdEx + msglen
+			if postIndex < 0 {
+				return ErrInvalidLengthRuntime
+			}
+			if postIndex > l {
+				return io.ErrUnexpectedEOF
+			}
+			if m.SerPolicy == nil {
+				m.SerPolicy = &flow.SerPolicy{}
+			}
+			if err := m.SerPolicy.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
+				return err
+			}
+			iNdEx = postIndex
+		case 2:
+			if wireType != 2 {
+				return fmt.Errorf("proto: wrong wireType = %d for field Projection", wireType)
+			}
+			var stringLen uint64
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowRuntime
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				stringLen |= uint64(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
+			intStringLen := int(stringLen)
+			if intStringLen < 0 {
+				return ErrInvalidLengthRuntime
+			}
+			postIndex := iNdEx + intStringLen
+			if postIndex < 0 {
+				return ErrInvalidLengthRuntime
+			}
+			if postIndex > l {
+				return io.ErrUnexpectedEOF
+			}
+			m.Projection = append(m.Projection, string(dAtA[iNdEx:postIndex]))
+			iNdEx = postIndex
+		case 3:
+			if wireType != 2 {
+				return fmt.Errorf("proto: wrong wireType = %d for field SortPtr", wireType)
+			}
+			var stringLen uint64
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowRuntime
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				stringLen |= uint64(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
+			intStringLen := int(stringLen)
+			if intStringLen < 0 {
+				return ErrInvalidLengthRuntime
+			}
+			postIndex := iNdEx + intStringLen
+			if postIndex < 0 {
+				return ErrInvalidLengthRuntime
+			}
+			if postIndex > l {
+				return io.ErrUnexpectedEOF
+			}
+			m.SortPtr = string(dAtA[iNdEx:postIndex])
+			iNdEx = postIndex
+		case 4:
+			if wireType != 0 {
+				return fmt.Errorf("proto: wrong wireType = %d for field Passthrough", wireType)
+			}
+			var v int
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowRuntime
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				v |= int(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
+			m.Passthrough = bool(v != 0)
+		case 5:
+			if wireType != 0 {
+				return fmt.Errorf("proto: wrong wireType = %d for field ConnectorPartitions", wireType)
+			}
+			var v int
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowRuntime
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				v |= int(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
+			m.ConnectorPartitions = bool(v != 0)
+		default:
+			iNdEx = preIndex
+			skippy, err := skipRuntime(dAtA[iNdEx:])
+			if err != nil {
+				return err
+			}
+			if (skippy < 0) || (iNdEx+skippy) < 0 {
+				return ErrInvalidLengthRuntime
+			}
+			if (iNdEx + skippy) > l {
+				return io.ErrUnexpectedEOF
+			}
+			m.XXX_unrecognized = append(m.XXX_unrecognized, dAtA[iNdEx:iNdEx+skippy]...)
+			iNdEx += skippy
+		}
+	}
+
+	if iNdEx > l {
+		return io.ErrUnexpectedEOF
+	}
+	return nil
+}
 func (m *CaptureResponseExt) Unmarshal(dAtA []byte) error {
 	l := len(dAtA)
 	iNdEx := 0
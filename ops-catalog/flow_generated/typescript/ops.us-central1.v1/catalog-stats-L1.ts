@@ -36,6 +36,10 @@ export type Document = /* Flow catalog task stats Statistics related to the proc
                     bytesTotal: /* Total number of bytes representing the JSON encoded documents */ number;
                     docsTotal: /* Total number of documents */ number;
                 };
+                skipped?: /* Documents which were skipped because they were invalid */ {
+                    bytesTotal: /* Total number of bytes representing the JSON encoded documents */ number;
+                    docsTotal: /* Total number of documents */ number;
+                };
             };
         };
         derive?: {
@@ -109,6 +113,10 @@ export type SourceStats = /* Flow task stats Statistics related to the processin
                 bytesTotal: /* Total number of bytes representing the JSON encoded documents */ number;
                 docsTotal: /* Total number of documents */ number;
             };
+            skipped?: /* Documents which were skipped because they were invalid */ {
+                bytesTotal: /* Total number of bytes representing the JSON encoded documents */ number;
+                docsTotal: /* Total number of documents */ number;
+            };
         };
     };
     derive?: {
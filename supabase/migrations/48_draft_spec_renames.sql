@@ -0,0 +1,14 @@
+begin;
+
+alter table draft_specs add column rename_from catalog_name default null;
+comment on column draft_specs.rename_from is '
+When set, this draft collection specification is a rename of the live
+collection having this catalog name.
+
+The renamed collection must be newly created by the publication of this draft,
+and the draft must also delete the live collection of `rename_from`.
+The inferred schema of the prior collection is carried over to the renamed
+collection, and the rename is recorded within its publication_specs detail.
+';
+
+commit;
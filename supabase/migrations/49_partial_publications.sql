@@ -0,0 +1,12 @@
+begin;
+
+alter table publications add column only_catalog_names text[] default null;
+comment on column publications.only_catalog_names is '
+When set, only the draft specifications having these catalog names are
+published. Other specifications of the draft are left as-is, and remain
+in the draft after the publication completes.
+';
+
+grant insert (only_catalog_names) on publications to authenticated;
+
+commit;
@@ -0,0 +1,18 @@
+begin;
+
+alter table live_specs add column spec_hash text default null;
+comment on column live_specs.spec_hash is '
+Hex-encoded SHA-256 hash of the canonical JSON of the live specification,
+as of its last publication. Canonical JSON has sorted object properties,
+integral numbers written as integers, and no insignificant whitespace, so
+that specifications which differ only in their formatting have the same hash.
+A publication of a specification having an unchanged hash doesn''t update its
+live specification: only its last_build_id advances, and its last_pub_id
+remains that of the publication which last changed it. Drafts which set an
+expect_pub_id must therefore expect the last_pub_id of the last change, and
+not that of a later publication which left the specification unchanged.
+Null if the specification is deleted, or hasn''t been published since this
+column was added.
+';
+
+commit;
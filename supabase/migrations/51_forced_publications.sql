@@ -0,0 +1,13 @@
+begin;
+
+alter table publications add column force boolean not null default false;
+comment on column publications.force is '
+When true, errors of conservative validation checks, such as changes to the
+partitions or narrowings of the schema of an established collection, are
+downgraded to warnings and don''t fail the publication. Structural checks,
+such as agreement of catalog types, are never overridden.
+';
+
+grant insert (force) on publications to authenticated;
+
+commit;